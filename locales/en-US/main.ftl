@@ -0,0 +1,289 @@
+## General
+
+app-nome = Rust Items
+carregando = Loading...
+carregando-configuracao = Loading configuration...
+cancelar = Cancel
+confirmar = Confirm
+fechar = Close
+salvar = Save
+salvando = Saving...
+remover = Delete
+desfazer = Undo
+voltar-para-lista = Back to the list
+pagina-nao-encontrada = Page not found.
+sem-permissao = You are not allowed to do this.
+idioma = Language
+coluna-id = ID
+coluna-nome = Name
+coluna-preco = Price
+coluna-acoes = Actions
+coluna-descricao = Description
+coluna-categoria = Category
+coluna-sku = SKU
+coluna-estoque = Stock
+coluna-ativo = Active
+sim = Yes
+nao = No
+exemplo = E.g. { $valor }
+
+## Navbar
+
+nav-itens = Items
+nav-painel = Dashboard
+nav-entrar = Sign in
+nav-sair = Sign out
+sair-pendentes-titulo = Sign out with pending changes?
+sair-pendentes-pergunta = { $n ->
+    [one] { $n } change made while offline has not been sent yet.
+   *[other] { $n } changes made while offline have not been sent yet.
+} They stay saved in this browser and are sent after the next sign-in.
+
+## Login
+
+login-titulo = Sign in
+login-subtitulo = Use your items API account.
+login-usuario = Username
+login-senha = Password
+login-entrar = Sign in
+login-campos-vazios = Enter your username and password.
+login-invalido = Invalid username or password.
+login-falha = Could not sign in: { $erro }
+sessao-expirada = Your session has expired. Please sign in again.
+sessao-invalida = Your session is no longer valid. Please sign in again.
+login-exigido = The API requires you to sign in.
+token-nao-e-jwt = Invalid token: not a JWT
+token-sem-base64 = Invalid token: payload is not base64
+token-invalido = Invalid token: { $erro }
+
+## List
+
+lista-titulo = Items
+lista-subtitulo = Manage the items served by the Rust API.
+lista-importar-csv = Import CSV
+lista-incluir-novo = Add new
+lista-total = { $n ->
+    [0] No items
+    [one] { $n } item
+   *[other] { $n } items
+}
+lista-vazia = No items found.
+lista-sem-conexao = No connection to the API. Showing the last saved copy of this page.
+lista-pendentes = { $n ->
+    [one] { $n } pending change, sent when the connection is back.
+   *[other] { $n } pending changes, sent when the connection is back.
+}
+lista-sincronizar = Sync now
+lista-conflitos = Some changes made while offline were not applied:
+lista-erro-buscar = Could not load items: { $erro }
+lista-erro-remover = Could not delete: { $erro }
+lista-erro-desfazer = Could not undo the deletion: { $erro }
+lista-erro-selecionar-busca = Could not select the search results: { $erro }
+lista-erro-exportar = Could not export: { $erro }
+remover-item-titulo = Delete item
+remover-item-pergunta = Delete this item?
+remover-selecionados-titulo = Delete selected items
+remover-selecionados-pergunta = { $n ->
+    [one] Delete { $n } item? This cannot be undone.
+   *[other] Delete { $n } items? This cannot be undone.
+}
+item-removido = "{ $nome }" deleted.
+
+## Table
+
+tabela-selecionar-pagina = Select page
+tabela-selecionar-item = Select { $nome }
+tabela-editar-nome = Edit name
+tabela-editar-preco = Edit price
+tabela-dica-edicao = Double-click to edit
+tabela-colunas = Columns
+tabela-ver-detalhes = View details
+
+## Search and pagination
+
+busca-placeholder = Search by ID, name, description or SKU...
+busca-botao = Search
+por-pagina = { $n } per page
+categoria-todas = All categories
+
+## Filters
+
+filtros-botao = Filters
+filtros-preco-min = Minimum price
+filtros-preco-max = Maximum price
+filtros-id-min = First ID
+filtros-id-max = Last ID
+filtros-nome-da-busca = Name search
+filtros-modo-do-nome = Name mode
+filtros-aplicar = Apply filters
+filtros-limpar = Clear filters
+filtros-remover = Remove filter { $rotulo }
+filtros-invalido = Invalid { $campo }
+filtros-preco-invertido = The minimum price is greater than the maximum
+filtros-id-invertido = The first ID is greater than the last
+filtro-preco-min = Price ≥ { $valor }
+filtro-preco-max = Price ≤ { $valor }
+filtro-id-min = ID ≥ { $valor }
+filtro-id-max = ID ≤ { $valor }
+filtro-modo = Name { $modo }
+modo-contem = contains
+modo-comeca-com = starts with
+modo-exato = is exactly
+modo-regex = regular expression
+
+## Saved views
+
+visoes-botao = Views
+visoes-aplicada = View: { $nome }
+visoes-nenhuma = No saved views.
+visoes-remover = Remove view { $nome }
+visoes-nome = View name
+visoes-salvar = Save current view
+visoes-exportar = Export views (JSON)
+visoes-importar = Import views (JSON)
+visoes-importar-rotulo = Import views
+visoes-importadas = { $n ->
+    [0] No views imported.
+    [one] { $n } view imported.
+   *[other] { $n } views imported.
+}
+visoes-salva = View "{ $nome }" saved.
+visoes-arquivo-invalido = Invalid views file: { $erro }
+visoes-erro-exportar = Could not export the views: { $erro }
+
+## Export
+
+exportar-botao = Export
+exportar-itens = Items
+exportar-pagina-atual = Current page
+exportar-todas = All pages
+exportar-planilha = Items
+exportar-sem-documento = document unavailable
+
+## Selection and bulk actions
+
+lote-selecionados = { $n } selected
+lote-selecionar-todos = Select all { $n } search results
+lote-limpar-selecao = Clear selection
+lote-tipo-ajuste = Adjustment type
+lote-ajuste-placeholder = E.g. 5 or -10
+lote-ajuste-rotulo = Price adjustment
+lote-ajustar = Adjust price
+lote-remover = Delete selected
+lote-processando = Processing { $feitos } of { $total } items...
+lote-remocao = Deletion
+lote-ajuste = Price adjustment
+lote-resumo = { $descricao }: { $feitos } of { $total } items done.
+lote-resumo-falhas = { $descricao }: { $feitos } of { $total } items done, { $falhas } failed.
+lote-preco-negativo = the price would be negative ({ $valor })
+
+## Form
+
+form-editar = Edit item
+form-novo = New item
+form-subtitulo = Fill in the fields and save.
+form-nome-placeholder = Product name
+form-salvar-alteracoes = Save changes
+form-verifique = Check the highlighted fields.
+form-nome-obrigatorio = Enter the product name
+form-preco-obrigatorio = Enter the price
+form-preco-invalido = Invalid price
+form-estoque-invalido = Invalid stock
+form-descricao-placeholder = Product details (optional)
+form-categoria-exemplo = Beverages
+form-ativo = Active (available for sale)
+form-erro-carregar = Could not load item: { $erro }
+form-erro-criar = Could not create item: { $erro }
+form-erro-atualizar = Could not update item: { $erro }
+
+## Dashboard
+
+painel-titulo = Catalogue dashboard
+painel-erro = Error loading the catalogue: { $erro }
+painel-vazio = No items yet.
+painel-total = Items
+painel-media = Average price
+painel-mediana = Median
+painel-minimo = Lowest price
+painel-maximo = Highest price
+painel-distribuicao = Price distribution
+painel-histograma = Histogram of the number of items per price range
+painel-faixa = { $de } to { $ate }: { $n ->
+    [one] { $n } item
+   *[other] { $n } items
+}
+painel-recentes = Recently added
+
+## Item details
+
+detalhe-criado-em = Created at
+detalhe-atualizado-em = Updated at
+detalhe-historico = Price history
+detalhe-sem-historico = No price changes recorded.
+detalhe-historico-indisponivel = History unavailable: { $erro }
+detalhe-grafico = Chart of the price over time
+detalhe-ponto = { $data }: { $preco }
+detalhe-data = Date
+detalhe-variacao = Change
+
+## Import
+
+importar-titulo = Import items
+importar-subtitulo = Upload a CSV file with the name and price of each item.
+importar-erro-ler = Could not read the file.
+importar-vazio = The file is empty.
+importar-falha-linha = Line { $linha } ({ $nome }): { $motivo }
+importar-outro = Import another file
+importar-progresso = Importing { $feitos } of { $total } items...
+importar-escolher = Choose a file...
+importar-nenhum-arquivo = No file selected
+importar-ajuda = Accepts semicolon, comma or tab separators and prices like 1.234,56 or 1234.56. Excel files in Latin-1 are read too.
+importar-coluna-nome = Name column
+importar-coluna-preco = Price column
+importar-coluna = Column { $n }
+importar-cabecalho = First line is a header
+importar-separador = Detected separator: { $separador }
+importar-validas = { $n } valid
+importar-com-erro = { $n } with errors
+importar-linha = Line
+importar-situacao = Status
+importar-mais-linhas = { $n ->
+    [one] ... and { $n } more line.
+   *[other] ... and { $n } more lines.
+}
+importar-botao = { $n ->
+    [one] Import { $n } item
+   *[other] Import { $n } items
+}
+importar-ok = OK · { $valor }
+importar-resumo = { $criados } of { $total } items imported.
+importar-resumo-falhas = { $criados } of { $total } items imported, { $falhas } failed.
+importar-ignoradas = { $n ->
+    [one] { $n } line with errors was not sent.
+   *[other] { $n } lines with errors were not sent.
+}
+separador-ponto-e-virgula = semicolon
+separador-tabulacao = tab
+separador-barra = vertical bar
+separador-virgula = comma
+
+## Offline use
+
+offline-inclusao = Creation of "{ $nome }"
+offline-alteracao = Change to "{ $nome }"
+offline-remocao = Deletion of "{ $nome }"
+offline-alterado-por-outro = the item was changed by someone else (now "{ $nome }" · { $preco })
+offline-removido-por-outro = the item was deleted by someone else
+idb-falha = IndexedDB failure
+idb-indisponivel = IndexedDB unavailable
+idb-falha-loja = Could not access "{ $loja }" in IndexedDB
+
+## API errors
+
+erro-rede = could not connect to the API ({ $detalhe })
+erro-nao-encontrado = resource not found (status { $status })
+erro-acesso-negado = access denied (status { $status })
+erro-interno = internal API error (status { $status })
+erro-status = the API responded with status { $status }
+erro-validacao = invalid data, check the highlighted fields
+erro-resposta = unexpected API response ({ $detalhe })
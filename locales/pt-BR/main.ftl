@@ -0,0 +1,297 @@
+## Geral
+
+app-nome = Rust Items
+carregando = Carregando...
+carregando-configuracao = Carregando configuração...
+cancelar = Cancelar
+confirmar = Confirmar
+fechar = Fechar
+salvar = Salvar
+salvando = Salvando...
+remover = Remover
+desfazer = Desfazer
+voltar-para-lista = Voltar para a lista
+pagina-nao-encontrada = Página não encontrada.
+sem-permissao = Você não tem permissão para esta ação.
+idioma = Idioma
+coluna-id = ID
+coluna-nome = Nome
+coluna-preco = Preço
+coluna-acoes = Ações
+coluna-descricao = Descrição
+coluna-categoria = Categoria
+coluna-sku = SKU
+coluna-estoque = Estoque
+coluna-ativo = Ativo
+sim = Sim
+nao = Não
+exemplo = Ex: { $valor }
+
+## Barra de navegação
+
+nav-itens = Itens
+nav-painel = Painel
+nav-entrar = Entrar
+nav-sair = Sair
+sair-pendentes-titulo = Sair com alterações pendentes?
+sair-pendentes-pergunta = { $n ->
+    [one] { $n } alteração feita sem conexão ainda não foi enviada.
+   *[other] { $n } alterações feitas sem conexão ainda não foram enviadas.
+} Elas continuam guardadas neste navegador e são enviadas depois do próximo login.
+
+## Login
+
+login-titulo = Entrar
+login-subtitulo = Use sua conta da API de itens.
+login-usuario = Usuário
+login-senha = Senha
+login-entrar = Entrar
+login-campos-vazios = Informe o usuário e a senha.
+login-invalido = Usuário ou senha inválidos.
+login-falha = Falha ao entrar: { $erro }
+sessao-expirada = Sua sessão expirou. Entre novamente.
+sessao-invalida = Sua sessão não é mais válida. Entre novamente.
+login-exigido = A API exige login para continuar.
+token-nao-e-jwt = Token inválido: não é um JWT
+token-sem-base64 = Token inválido: payload não está em base64
+token-invalido = Token inválido: { $erro }
+
+## Lista
+
+lista-titulo = Itens
+lista-subtitulo = Gerencie os itens consumindo a API Rust.
+lista-importar-csv = Importar CSV
+lista-incluir-novo = Incluir novo
+lista-total = { $n ->
+    [0] Nenhum item
+    [one] { $n } item
+   *[other] { $n } itens
+}
+lista-vazia = Nenhum item encontrado.
+lista-sem-conexao = Sem conexão com a API. Mostrando a última cópia salva desta página.
+lista-pendentes = { $n ->
+    [one] { $n } alteração pendente, enviada quando a conexão voltar.
+   *[other] { $n } alterações pendentes, enviadas quando a conexão voltar.
+}
+lista-sincronizar = Sincronizar agora
+lista-conflitos = Algumas alterações feitas sem conexão não foram aplicadas:
+lista-erro-buscar = Erro ao buscar itens: { $erro }
+lista-erro-remover = Falha ao remover: { $erro }
+lista-erro-desfazer = Falha ao desfazer a remoção: { $erro }
+lista-erro-selecionar-busca = Falha ao selecionar os itens da busca: { $erro }
+lista-erro-exportar = Falha ao exportar: { $erro }
+remover-item-titulo = Remover item
+remover-item-pergunta = Deseja remover este item?
+remover-selecionados-titulo = Remover itens selecionados
+remover-selecionados-pergunta = { $n ->
+    [one] Deseja remover { $n } item? Esta ação não pode ser desfeita.
+   *[other] Deseja remover { $n } itens? Esta ação não pode ser desfeita.
+}
+item-removido = "{ $nome }" removido.
+
+## Tabela
+
+tabela-selecionar-pagina = Selecionar a página
+tabela-selecionar-item = Selecionar { $nome }
+tabela-editar-nome = Editar nome
+tabela-editar-preco = Editar preço
+tabela-dica-edicao = Clique duas vezes para editar
+tabela-colunas = Colunas
+tabela-ver-detalhes = Ver detalhes
+
+## Busca e paginação
+
+busca-placeholder = Buscar por ID, nome, descrição ou SKU...
+busca-botao = Buscar
+por-pagina = { $n } por página
+categoria-todas = Todas as categorias
+
+## Filtros
+
+filtros-botao = Filtros
+filtros-preco-min = Preço mínimo
+filtros-preco-max = Preço máximo
+filtros-id-min = ID inicial
+filtros-id-max = ID final
+filtros-nome-da-busca = Nome da busca
+filtros-modo-do-nome = Modo do nome
+filtros-aplicar = Aplicar filtros
+filtros-limpar = Limpar filtros
+filtros-remover = Remover filtro { $rotulo }
+filtros-invalido = { $campo } inválido
+filtros-preco-invertido = O preço mínimo é maior que o máximo
+filtros-id-invertido = O ID inicial é maior que o final
+filtro-preco-min = Preço ≥ { $valor }
+filtro-preco-max = Preço ≤ { $valor }
+filtro-id-min = ID ≥ { $valor }
+filtro-id-max = ID ≤ { $valor }
+filtro-modo = Nome { $modo }
+modo-contem = contém
+modo-comeca-com = começa com
+modo-exato = é exatamente
+modo-regex = expressão regular
+
+## Visões salvas
+
+visoes-botao = Visões
+visoes-aplicada = Visão: { $nome }
+visoes-nenhuma = Nenhuma visão salva.
+visoes-remover = Remover visão { $nome }
+visoes-nome = Nome da visão
+visoes-salvar = Salvar visão atual
+visoes-exportar = Exportar visões (JSON)
+visoes-importar = Importar visões (JSON)
+visoes-importar-rotulo = Importar visões
+visoes-importadas = { $n ->
+    [0] Nenhuma visão importada.
+    [one] { $n } visão importada.
+   *[other] { $n } visões importadas.
+}
+visoes-salva = Visão "{ $nome }" salva.
+visoes-arquivo-invalido = Arquivo de visões inválido: { $erro }
+visoes-erro-exportar = Falha ao exportar as visões: { $erro }
+
+## Exportação
+
+exportar-botao = Exportar
+exportar-itens = Itens
+exportar-pagina-atual = Página atual
+exportar-todas = Todas as páginas
+exportar-planilha = Itens
+exportar-sem-documento = documento indisponível
+
+## Seleção e operações em lote
+
+lote-selecionados = { $n ->
+    [one] { $n } selecionado
+   *[other] { $n } selecionados
+}
+lote-selecionar-todos = Selecionar todos os { $n } da busca
+lote-limpar-selecao = Limpar seleção
+lote-tipo-ajuste = Tipo de ajuste
+lote-ajuste-placeholder = Ex: 5 ou -10
+lote-ajuste-rotulo = Ajuste de preço
+lote-ajustar = Ajustar preço
+lote-remover = Remover selecionados
+lote-processando = Processando { $feitos } de { $total } itens...
+lote-remocao = Remoção
+lote-ajuste = Ajuste de preço
+lote-resumo = { $descricao }: { $feitos } de { $total } itens concluídos.
+lote-resumo-falhas = { $descricao }: { $feitos } de { $total } itens concluídos, { $falhas } com falha.
+lote-preco-negativo = o preço ficaria negativo ({ $valor })
+
+## Formulário
+
+form-editar = Editar item
+form-novo = Novo item
+form-subtitulo = Preencha os campos e salve.
+form-nome-placeholder = Nome do produto
+form-salvar-alteracoes = Salvar alterações
+form-verifique = Verifique os campos destacados.
+form-nome-obrigatorio = Informe o nome do produto
+form-preco-obrigatorio = Informe o preço
+form-preco-invalido = Preço inválido
+form-estoque-invalido = Estoque inválido
+form-descricao-placeholder = Detalhes do produto (opcional)
+form-categoria-exemplo = Bebidas
+form-ativo = Ativo (disponível para venda)
+form-erro-carregar = Erro ao carregar item: { $erro }
+form-erro-criar = Erro ao criar item: { $erro }
+form-erro-atualizar = Erro ao atualizar item: { $erro }
+
+## Painel
+
+painel-titulo = Painel do catálogo
+painel-erro = Erro ao carregar o catálogo: { $erro }
+painel-vazio = Nenhum item cadastrado.
+painel-total = Itens
+painel-media = Preço médio
+painel-mediana = Mediana
+painel-minimo = Menor preço
+painel-maximo = Maior preço
+painel-distribuicao = Distribuição de preços
+painel-histograma = Histograma da quantidade de itens por faixa de preço
+painel-faixa = { $de } a { $ate }: { $n ->
+    [one] { $n } item
+   *[other] { $n } itens
+}
+painel-recentes = Incluídos recentemente
+
+## Detalhes do item
+
+detalhe-criado-em = Criado em
+detalhe-atualizado-em = Atualizado em
+detalhe-historico = Histórico de preços
+detalhe-sem-historico = Nenhuma alteração de preço registrada.
+detalhe-historico-indisponivel = Histórico indisponível: { $erro }
+detalhe-grafico = Gráfico da evolução do preço
+detalhe-ponto = { $data }: { $preco }
+detalhe-data = Data
+detalhe-variacao = Variação
+
+## Importação
+
+importar-titulo = Importar itens
+importar-subtitulo = Envie um arquivo CSV com o nome e o preço de cada item.
+importar-erro-ler = Não foi possível ler o arquivo.
+importar-vazio = O arquivo está vazio.
+importar-falha-linha = Linha { $linha } ({ $nome }): { $motivo }
+importar-outro = Importar outro arquivo
+importar-progresso = Importando { $feitos } de { $total } itens...
+importar-escolher = Escolher arquivo...
+importar-nenhum-arquivo = Nenhum arquivo selecionado
+importar-ajuda = Aceita separador ponto e vírgula, vírgula ou tabulação e preços como 1.234,56 ou 1234.56. Arquivos do Excel em Latin-1 também são lidos.
+importar-coluna-nome = Coluna do nome
+importar-coluna-preco = Coluna do preço
+importar-coluna = Coluna { $n }
+importar-cabecalho = Primeira linha é cabeçalho
+importar-separador = Separador detectado: { $separador }
+importar-validas = { $n ->
+    [0] { $n } válidas
+    [one] { $n } válida
+   *[other] { $n } válidas
+}
+importar-com-erro = { $n } com erro
+importar-linha = Linha
+importar-situacao = Situação
+importar-mais-linhas = { $n ->
+    [one] ... e mais { $n } linha.
+   *[other] ... e mais { $n } linhas.
+}
+importar-botao = { $n ->
+    [0] Importar { $n } itens
+    [one] Importar { $n } item
+   *[other] Importar { $n } itens
+}
+importar-ok = OK · { $valor }
+importar-resumo = { $criados } de { $total } itens importados.
+importar-resumo-falhas = { $criados } de { $total } itens importados, { $falhas } com falha.
+importar-ignoradas = { $n ->
+    [one] { $n } linha com erro não foi enviada.
+   *[other] { $n } linhas com erro não foram enviadas.
+}
+separador-ponto-e-virgula = ponto e vírgula
+separador-tabulacao = tabulação
+separador-barra = barra vertical
+separador-virgula = vírgula
+
+## Uso sem conexão
+
+offline-inclusao = Inclusão de "{ $nome }"
+offline-alteracao = Alteração de "{ $nome }"
+offline-remocao = Remoção de "{ $nome }"
+offline-alterado-por-outro = o item foi alterado por outra pessoa (agora "{ $nome }" · { $preco })
+offline-removido-por-outro = o item foi removido por outra pessoa
+idb-falha = falha no IndexedDB
+idb-indisponivel = IndexedDB indisponível
+idb-falha-loja = Falha ao acessar "{ $loja }" no IndexedDB
+
+## Erros da API
+
+erro-rede = não foi possível conectar à API ({ $detalhe })
+erro-nao-encontrado = recurso não encontrado (status { $status })
+erro-acesso-negado = acesso negado (status { $status })
+erro-interno = erro interno na API (status { $status })
+erro-status = a API respondeu com status { $status }
+erro-validacao = dados inválidos, verifique os campos destacados
+erro-resposta = resposta inesperada da API ({ $detalhe })
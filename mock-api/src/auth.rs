@@ -0,0 +1,140 @@
+//! Login de mentira: dois usuários fixos e tokens JWT sem assinatura (`alg: none`).
+//!
+//! Só são aceitos tokens emitidos por este processo, então não dá para forjar um editando o payload.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde_json::json;
+
+pub const CRIAR: &str = "itens:criar";
+pub const EDITAR: &str = "itens:editar";
+pub const REMOVER: &str = "itens:remover";
+
+/// `(usuário, senha, nome, permissões)`.
+const USUARIOS: &[(&str, &str, &str, &[&str])] = &[
+    ("admin", "admin", "Administrador", &[CRIAR, EDITAR, REMOVER]),
+    ("leitor", "leitor", "Leitor", &[]),
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sessao {
+    pub usuario: String,
+    pub permissoes: Vec<String>,
+    /// Expiração, em segundos desde 1970.
+    pub exp: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Recusa {
+    /// Sem token, token desconhecido ou expirado: 401.
+    NaoAutenticado,
+    /// Token válido sem a permissão pedida: 403.
+    SemPermissao,
+}
+
+pub struct Autenticador {
+    validade_s: u64,
+    emitidos: HashMap<String, Sessao>,
+}
+
+impl Autenticador {
+    pub fn new(validade_s: u64) -> Self {
+        Self {
+            validade_s,
+            emitidos: HashMap::new(),
+        }
+    }
+
+    /// Token para o usuário, ou `None` se as credenciais não conferem.
+    pub fn entrar(&mut self, usuario: &str, senha: &str, agora: u64) -> Option<String> {
+        let (login, _, nome, permissoes) = USUARIOS.iter().find(|(u, s, _, _)| *u == usuario && *s == senha)?;
+        let exp = agora + self.validade_s;
+        let cabecalho = URL_SAFE_NO_PAD.encode(json!({ "alg": "none", "typ": "JWT" }).to_string());
+        let payload = json!({
+            "sub": login,
+            "name": nome,
+            "exp": exp,
+            "permissions": permissoes,
+            "jti": self.emitidos.len(),
+        });
+        let token = format!("{}.{}.", cabecalho, URL_SAFE_NO_PAD.encode(payload.to_string()));
+        self.emitidos.insert(
+            token.clone(),
+            Sessao {
+                usuario: login.to_string(),
+                permissoes: permissoes.iter().map(|p| p.to_string()).collect(),
+                exp,
+            },
+        );
+        Some(token)
+    }
+
+    /// Confere o valor do cabeçalho `Authorization` e, se informada, a permissão.
+    pub fn verificar(&self, authorization: Option<&str>, permissao: Option<&str>, agora: u64) -> Result<&Sessao, Recusa> {
+        let token = authorization
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .ok_or(Recusa::NaoAutenticado)?;
+        let sessao = self
+            .emitidos
+            .get(token.trim())
+            .filter(|s| s.exp > agora)
+            .ok_or(Recusa::NaoAutenticado)?;
+        match permissao {
+            Some(p) if !sessao.permissoes.iter().any(|q| q == p) => Err(Recusa::SemPermissao),
+            _ => Ok(sessao),
+        }
+    }
+}
+
+pub fn agora() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bearer(token: &str) -> String {
+        format!("Bearer {}", token)
+    }
+
+    #[test]
+    fn emite_token_com_as_claims_do_usuario() {
+        let mut auth = Autenticador::new(60);
+        assert_eq!(auth.entrar("admin", "errada", 1000), None);
+
+        let token = auth.entrar("admin", "admin", 1000).unwrap();
+        let payload = token.split('.').nth(1).unwrap();
+        let claims: serde_json::Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+
+        assert_eq!(claims["sub"], "admin");
+        assert_eq!(claims["exp"], 1060);
+        assert_eq!(claims["permissions"], json!([CRIAR, EDITAR, REMOVER]));
+    }
+
+    #[test]
+    fn recusa_token_ausente_desconhecido_ou_expirado() {
+        let mut auth = Autenticador::new(60);
+        let token = auth.entrar("admin", "admin", 1000).unwrap();
+
+        assert_eq!(auth.verificar(None, None, 1000), Err(Recusa::NaoAutenticado));
+        assert_eq!(auth.verificar(Some(&bearer("x.y.z")), None, 1000), Err(Recusa::NaoAutenticado));
+        assert_eq!(auth.verificar(Some(&token), None, 1000), Err(Recusa::NaoAutenticado));
+        assert_eq!(auth.verificar(Some(&bearer(&token)), None, 1059).unwrap().usuario, "admin");
+        assert_eq!(auth.verificar(Some(&bearer(&token)), None, 1060), Err(Recusa::NaoAutenticado));
+    }
+
+    #[test]
+    fn confere_a_permissao_pedida() {
+        let mut auth = Autenticador::new(60);
+        let leitor = bearer(&auth.entrar("leitor", "leitor", 0).unwrap());
+        let admin = bearer(&auth.entrar("admin", "admin", 0).unwrap());
+
+        assert!(auth.verificar(Some(&leitor), None, 0).is_ok());
+        assert_eq!(auth.verificar(Some(&leitor), Some(REMOVER), 0), Err(Recusa::SemPermissao));
+        assert!(auth.verificar(Some(&admin), Some(REMOVER), 0).is_ok());
+    }
+}
@@ -0,0 +1,165 @@
+//! API de itens em memória para desenvolver o frontend sem o `rust-api-sample`.
+//!
+//! `PORTA` (padrão 3000) define a porta e `ARQUIVO`, se informado, grava os itens em JSON.
+//! Com `EXIGIR_LOGIN`, as rotas de itens pedem o token obtido em `POST /login`, válido por
+//! `VALIDADE_TOKEN_S` segundos (padrão 3600).
+
+mod auth;
+mod store;
+
+use std::env;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Path, Query, Request, State};
+use axum::http::{Method, StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::json;
+use tower_http::cors::CorsLayer;
+
+use auth::{Autenticador, Recusa};
+use store::{ListarParams, NovoItem, Store};
+
+type Estado = Arc<Mutex<Store>>;
+type EstadoAuth = Arc<Mutex<Autenticador>>;
+
+#[tokio::main]
+async fn main() {
+    let store = match env::var("ARQUIVO") {
+        Ok(arquivo) => Store::abrir(arquivo.into()).expect("não foi possível abrir o arquivo de itens"),
+        Err(_) => Store::exemplo(),
+    };
+    let porta = env::var("PORTA").unwrap_or_else(|_| "3000".into());
+    let validade_s = env::var("VALIDADE_TOKEN_S").ok().and_then(|v| v.parse().ok()).unwrap_or(3600);
+    let autenticador: EstadoAuth = Arc::new(Mutex::new(Autenticador::new(validade_s)));
+
+    let mut itens = Router::new()
+        .route("/itens", get(listar).post(criar))
+        .route("/itens/{id}", get(obter).put(atualizar).delete(remover))
+        .route("/itens/{id}/historico", get(historico))
+        .route("/categorias", get(categorias))
+        .with_state(Arc::new(Mutex::new(store)));
+    if env::var("EXIGIR_LOGIN").is_ok() {
+        itens = itens.layer(middleware::from_fn_with_state(autenticador.clone(), exigir_login));
+    }
+
+    let app = Router::new()
+        .route("/login", post(login))
+        .with_state(autenticador)
+        .merge(itens)
+        .layer(CorsLayer::permissive());
+
+    let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", porta))
+        .await
+        .expect("não foi possível abrir a porta");
+    println!("mock-api ouvindo em http://localhost:{}", porta);
+    axum::serve(listener, app).await.unwrap();
+}
+
+#[derive(Deserialize)]
+struct Credenciais {
+    usuario: String,
+    senha: String,
+}
+
+async fn login(State(auth): State<EstadoAuth>, Json(cred): Json<Credenciais>) -> Response {
+    match auth.lock().unwrap().entrar(&cred.usuario, &cred.senha, auth::agora()) {
+        Some(token) => Json(json!({ "token": token })).into_response(),
+        None => (StatusCode::UNAUTHORIZED, Json(json!({ "erro": "Usuário ou senha inválidos" }))).into_response(),
+    }
+}
+
+async fn exigir_login(State(auth): State<EstadoAuth>, req: Request, next: Next) -> Response {
+    let permissao = match *req.method() {
+        Method::POST => Some(auth::CRIAR),
+        Method::PUT => Some(auth::EDITAR),
+        Method::DELETE => Some(auth::REMOVER),
+        _ => None,
+    };
+    let authorization = req.headers().get(header::AUTHORIZATION).and_then(|v| v.to_str().ok());
+    let recusa = auth.lock().unwrap().verificar(authorization, permissao, auth::agora()).err();
+    match recusa {
+        None => next.run(req).await,
+        Some(Recusa::NaoAutenticado) => {
+            (StatusCode::UNAUTHORIZED, Json(json!({ "erro": "Login necessário" }))).into_response()
+        }
+        Some(Recusa::SemPermissao) => {
+            (StatusCode::FORBIDDEN, Json(json!({ "erro": "Sem permissão para esta ação" }))).into_response()
+        }
+    }
+}
+
+async fn listar(State(estado): State<Estado>, Query(params): Query<ListarParams>) -> Response {
+    match estado.lock().unwrap().listar(&params) {
+        Ok(res) => Json(res).into_response(),
+        Err(erro) => (StatusCode::BAD_REQUEST, Json(json!({ "erro": erro }))).into_response(),
+    }
+}
+
+async fn categorias(State(estado): State<Estado>) -> Response {
+    Json(estado.lock().unwrap().categorias()).into_response()
+}
+
+async fn obter(State(estado): State<Estado>, Path(id): Path<i32>) -> Response {
+    match estado.lock().unwrap().obter(id) {
+        Some(item) => Json(item).into_response(),
+        None => nao_encontrado(),
+    }
+}
+
+async fn historico(State(estado): State<Estado>, Path(id): Path<i32>) -> Response {
+    match estado.lock().unwrap().historico(id) {
+        Some(historico) => Json(historico).into_response(),
+        None => nao_encontrado(),
+    }
+}
+
+async fn criar(State(estado): State<Estado>, Json(novo): Json<NovoItem>) -> Response {
+    if let Some(resp) = invalido(&novo) {
+        return resp;
+    }
+    match estado.lock().unwrap().criar(novo) {
+        Ok(item) => (StatusCode::CREATED, Json(item)).into_response(),
+        Err(e) => falha(e),
+    }
+}
+
+async fn atualizar(State(estado): State<Estado>, Path(id): Path<i32>, Json(novo): Json<NovoItem>) -> Response {
+    if let Some(resp) = invalido(&novo) {
+        return resp;
+    }
+    match estado.lock().unwrap().atualizar(id, novo) {
+        Ok(Some(item)) => Json(item).into_response(),
+        Ok(None) => nao_encontrado(),
+        Err(e) => falha(e),
+    }
+}
+
+async fn remover(State(estado): State<Estado>, Path(id): Path<i32>) -> Response {
+    match estado.lock().unwrap().remover(id) {
+        Ok(true) => StatusCode::NO_CONTENT.into_response(),
+        Ok(false) => nao_encontrado(),
+        Err(e) => falha(e),
+    }
+}
+
+fn invalido(novo: &NovoItem) -> Option<Response> {
+    let campos = store::validar(novo);
+    if campos.is_empty() {
+        return None;
+    }
+    let corpo = json!({ "erro": "Dados inválidos", "campos": campos });
+    Some((StatusCode::UNPROCESSABLE_ENTITY, Json(corpo)).into_response())
+}
+
+fn nao_encontrado() -> Response {
+    (StatusCode::NOT_FOUND, Json(json!({ "erro": "Item não encontrado" }))).into_response()
+}
+
+fn falha(e: std::io::Error) -> Response {
+    let corpo = json!({ "erro": format!("Falha ao gravar os itens: {}", e) });
+    (StatusCode::INTERNAL_SERVER_ERROR, Json(corpo)).into_response()
+}
@@ -0,0 +1,611 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::agora;
+
+fn ativo_padrao() -> bool {
+    true
+}
+
+/// Arquivos gravados por versões anteriores, só com `id`, `nome` e `preco`, continuam válidos.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    pub id: i32,
+    pub nome: String,
+    pub preco: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categoria: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estoque: Option<i32>,
+    #[serde(default = "ativo_padrao")]
+    pub ativo: bool,
+    /// Data e hora em ISO 8601 (UTC).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criado_em: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atualizado_em: Option<String>,
+}
+
+/// Entrada de `GET /itens/:id/historico`: o preço que passou a valer em `em`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AlteracaoPreco {
+    pub em: String,
+    pub preco: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct NovoItem {
+    pub nome: String,
+    pub preco: f64,
+    #[serde(default)]
+    pub descricao: Option<String>,
+    #[serde(default)]
+    pub categoria: Option<String>,
+    #[serde(default)]
+    pub sku: Option<String>,
+    #[serde(default)]
+    pub estoque: Option<i32>,
+    #[serde(default = "ativo_padrao")]
+    pub ativo: bool,
+}
+
+impl NovoItem {
+    fn em_item(self, id: i32) -> Item {
+        let texto = |t: Option<String>| t.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+        Item {
+            id,
+            nome: self.nome.trim().to_string(),
+            preco: self.preco,
+            descricao: texto(self.descricao),
+            categoria: texto(self.categoria),
+            sku: texto(self.sku),
+            estoque: self.estoque,
+            ativo: self.ativo,
+            criado_em: None,
+            atualizado_em: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ListarResponse {
+    pub itens: Vec<Item>,
+    pub total: i64,
+    pub pagina: i64,
+    pub por_pagina: i64,
+    pub total_paginas: i64,
+}
+
+/// Query string aceita por `GET /itens`, com os mesmos padrões do frontend.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ListarParams {
+    pub busca: String,
+    pub ordenar_por: String,
+    pub ordem: String,
+    pub pagina: i64,
+    pub por_pagina: i64,
+    /// Como `busca` é comparada com o nome e a descrição: `contem`, `comeca_com`, `exato` ou `regex`.
+    pub modo: String,
+    /// Categoria exata, sem diferenciar maiúsculas; vazia para todas.
+    pub categoria: String,
+    pub preco_min: Option<f64>,
+    pub preco_max: Option<f64>,
+    pub id_min: Option<i32>,
+    pub id_max: Option<i32>,
+}
+
+impl Default for ListarParams {
+    fn default() -> Self {
+        Self {
+            busca: String::new(),
+            ordenar_por: "id".into(),
+            ordem: "asc".into(),
+            pagina: 1,
+            por_pagina: 10,
+            modo: "contem".into(),
+            categoria: String::new(),
+            preco_min: None,
+            preco_max: None,
+            id_min: None,
+            id_max: None,
+        }
+    }
+}
+
+pub const POR_PAGINA_MAXIMO: i64 = 100;
+
+/// Itens em memória, opcionalmente gravados em um arquivo JSON a cada alteração.
+///
+/// O histórico de preços fica só em memória: ao reabrir o arquivo, cada item começa com o preço atual.
+#[derive(Debug)]
+pub struct Store {
+    itens: Vec<Item>,
+    historico: BTreeMap<i32, Vec<AlteracaoPreco>>,
+    proximo_id: i32,
+    arquivo: Option<PathBuf>,
+}
+
+impl Store {
+    pub fn new(itens: Vec<Item>) -> Self {
+        let proximo_id = itens.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+        let historico = itens
+            .iter()
+            .map(|i| {
+                let em = i.atualizado_em.clone().unwrap_or_else(|| data_hora(agora()));
+                (i.id, vec![AlteracaoPreco { em, preco: i.preco }])
+            })
+            .collect();
+        Self {
+            itens,
+            historico,
+            proximo_id,
+            arquivo: None,
+        }
+    }
+
+    /// Os itens de `dados_de_exemplo`, com alguns reajustes de preço no histórico.
+    pub fn exemplo() -> Self {
+        let itens = dados_de_exemplo();
+        let historico = itens.iter().map(|i| (i.id, historico_de_exemplo(i))).collect();
+        Self {
+            historico,
+            ..Self::new(itens)
+        }
+    }
+
+    /// Lê os itens de `arquivo` ou, se ele ainda não existir, começa com os dados de exemplo.
+    pub fn abrir(arquivo: PathBuf) -> io::Result<Self> {
+        let itens = match fs::read_to_string(&arquivo) {
+            Ok(conteudo) => serde_json::from_str(&conteudo).map_err(io::Error::other)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Self::exemplo().com_arquivo(arquivo),
+            Err(e) => return Err(e),
+        };
+        Self::new(itens).com_arquivo(arquivo)
+    }
+
+    fn com_arquivo(mut self, arquivo: PathBuf) -> io::Result<Self> {
+        self.arquivo = Some(arquivo);
+        self.gravar()?;
+        Ok(self)
+    }
+
+    /// Busca por id, SKU exato ou trecho do nome ou da descrição. Falha apenas com uma expressão
+    /// regular inválida em `busca`.
+    pub fn listar(&self, params: &ListarParams) -> Result<ListarResponse, String> {
+        let busca = params.busca.trim().to_lowercase();
+        let regex = match params.modo.as_str() {
+            "regex" if !busca.is_empty() => Some(
+                RegexBuilder::new(params.busca.trim())
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| format!("Expressão regular inválida: {}", e))?,
+            ),
+            _ => None,
+        };
+        let nome_confere = |nome: &str| {
+            let nome = nome.to_lowercase();
+            match (params.modo.as_str(), &regex) {
+                (_, Some(regex)) => regex.is_match(&nome),
+                ("comeca_com", _) => nome.starts_with(&busca),
+                ("exato", _) => nome == busca,
+                _ => nome.contains(&busca),
+            }
+        };
+        let mut itens: Vec<Item> = self
+            .itens
+            .iter()
+            .filter(|i| {
+                busca.is_empty()
+                    || i.id.to_string() == busca
+                    || i.sku.as_deref().is_some_and(|sku| sku.to_lowercase() == busca)
+                    || nome_confere(&i.nome)
+                    || i.descricao.as_deref().is_some_and(nome_confere)
+            })
+            .filter(|i| {
+                params.categoria.is_empty()
+                    || i.categoria.as_deref().is_some_and(|c| c.to_lowercase() == params.categoria.to_lowercase())
+            })
+            .filter(|i| params.preco_min.is_none_or(|min| i.preco >= min))
+            .filter(|i| params.preco_max.is_none_or(|max| i.preco <= max))
+            .filter(|i| params.id_min.is_none_or(|min| i.id >= min))
+            .filter(|i| params.id_max.is_none_or(|max| i.id <= max))
+            .cloned()
+            .collect();
+
+        itens.sort_by(|a, b| match params.ordenar_por.as_str() {
+            "nome" => a.nome.to_lowercase().cmp(&b.nome.to_lowercase()),
+            "preco" => a.preco.total_cmp(&b.preco),
+            _ => a.id.cmp(&b.id),
+        });
+        if params.ordem == "desc" {
+            itens.reverse();
+        }
+
+        let pagina = params.pagina.max(1);
+        let por_pagina = params.por_pagina.clamp(1, POR_PAGINA_MAXIMO);
+        let total = itens.len() as i64;
+        let total_paginas = (total + por_pagina - 1) / por_pagina;
+        let itens = itens
+            .into_iter()
+            // Saturada: uma página enorme na URL devolve a lista vazia em vez de estourar.
+            .skip(usize::try_from((pagina - 1).saturating_mul(por_pagina)).unwrap_or(usize::MAX))
+            .take(por_pagina as usize)
+            .collect();
+
+        Ok(ListarResponse {
+            itens,
+            total,
+            pagina,
+            por_pagina,
+            total_paginas,
+        })
+    }
+
+    /// Categorias em uso, sem repetição e em ordem alfabética.
+    pub fn categorias(&self) -> Vec<String> {
+        let mut categorias: Vec<String> = self.itens.iter().filter_map(|i| i.categoria.clone()).collect();
+        categorias.sort_by_key(|c| c.to_lowercase());
+        categorias.dedup_by_key(|c| c.to_lowercase());
+        categorias
+    }
+
+    pub fn obter(&self, id: i32) -> Option<Item> {
+        self.itens.iter().find(|i| i.id == id).cloned()
+    }
+
+    /// Preços do item, do mais antigo ao atual; `None` se o item não existe.
+    pub fn historico(&self, id: i32) -> Option<Vec<AlteracaoPreco>> {
+        self.obter(id)?;
+        Some(self.historico.get(&id).cloned().unwrap_or_default())
+    }
+
+    pub fn criar(&mut self, novo: NovoItem) -> io::Result<Item> {
+        let agora = data_hora(agora());
+        let item = Item {
+            criado_em: Some(agora.clone()),
+            atualizado_em: Some(agora.clone()),
+            ..novo.em_item(self.proximo_id)
+        };
+        self.proximo_id += 1;
+        self.historico.insert(item.id, vec![AlteracaoPreco { em: agora, preco: item.preco }]);
+        self.itens.push(item.clone());
+        self.gravar()?;
+        Ok(item)
+    }
+
+    pub fn atualizar(&mut self, id: i32, novo: NovoItem) -> io::Result<Option<Item>> {
+        let Some(item) = self.itens.iter_mut().find(|i| i.id == id) else {
+            return Ok(None);
+        };
+        let agora = data_hora(agora());
+        if novo.preco != item.preco {
+            let alteracao = AlteracaoPreco { em: agora.clone(), preco: novo.preco };
+            self.historico.entry(id).or_default().push(alteracao);
+        }
+        *item = Item {
+            criado_em: item.criado_em.take(),
+            atualizado_em: Some(agora),
+            ..novo.em_item(id)
+        };
+        let item = item.clone();
+        self.gravar()?;
+        Ok(Some(item))
+    }
+
+    pub fn remover(&mut self, id: i32) -> io::Result<bool> {
+        let antes = self.itens.len();
+        self.itens.retain(|i| i.id != id);
+        if self.itens.len() == antes {
+            return Ok(false);
+        }
+        self.historico.remove(&id);
+        self.gravar()?;
+        Ok(true)
+    }
+
+    fn gravar(&self) -> io::Result<()> {
+        match &self.arquivo {
+            Some(arquivo) => fs::write(arquivo, serde_json::to_string_pretty(&self.itens)?),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Mensagens de validação por campo, no formato que o frontend entende.
+pub fn validar(novo: &NovoItem) -> BTreeMap<&'static str, &'static str> {
+    let mut campos = BTreeMap::new();
+    if novo.nome.trim().is_empty() {
+        campos.insert("nome", "Informe o nome do produto");
+    }
+    if !novo.preco.is_finite() || novo.preco < 0.0 {
+        campos.insert("preco", "Preço inválido");
+    }
+    if novo.estoque.is_some_and(|n| n < 0) {
+        campos.insert("estoque", "Estoque inválido");
+    }
+    campos
+}
+
+/// Data e hora UTC em ISO 8601, como `2025-01-31T12:00:00Z`, a partir dos segundos desde 1970.
+pub fn data_hora(segundos: u64) -> String {
+    let (dias, resto) = ((segundos / 86_400) as i64, segundos % 86_400);
+    // Conversão de dias para data civil (algoritmo de Howard Hinnant).
+    let z = dias + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let dia = doy - (153 * mp + 2) / 5 + 1;
+    let mes = if mp < 10 { mp + 3 } else { mp - 9 };
+    let ano = yoe + era * 400 + i64::from(mes <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        ano,
+        mes,
+        dia,
+        resto / 3600,
+        resto / 60 % 60,
+        resto % 60
+    )
+}
+
+/// 2025-01-01T12:00:00Z: os itens de exemplo são cadastrados um por dia a partir daqui.
+const INICIO_EXEMPLO: u64 = 1_735_732_800;
+const DIA: u64 = 86_400;
+/// Fração do preço atual em cada reajuste simulado, com 30 dias entre eles.
+const REAJUSTES_EXEMPLO: [f64; 4] = [0.88, 0.94, 0.97, 1.0];
+
+fn historico_de_exemplo(item: &Item) -> Vec<AlteracaoPreco> {
+    let criado = INICIO_EXEMPLO + item.id as u64 * DIA;
+    REAJUSTES_EXEMPLO
+        .iter()
+        .zip(0..)
+        .map(|(fracao, i)| AlteracaoPreco {
+            em: data_hora(criado + i * 30 * DIA),
+            preco: (item.preco * fracao * 100.0).round() / 100.0,
+        })
+        .collect()
+}
+
+pub fn dados_de_exemplo() -> Vec<Item> {
+    [
+        ("Café em grãos 1kg", 54.90, "Mercearia"),
+        ("Chá verde 20 sachês", 9.50, "Bebidas"),
+        ("Açúcar mascavo 500g", 12.30, "Mercearia"),
+        ("Leite integral 1L", 5.49, "Laticínios"),
+        ("Pão de forma", 8.99, "Padaria"),
+        ("Manteiga 200g", 14.75, "Laticínios"),
+        ("Queijo minas 500g", 27.80, "Laticínios"),
+        ("Presunto fatiado 200g", 11.20, "Frios"),
+        ("Arroz branco 5kg", 29.90, "Mercearia"),
+        ("Feijão carioca 1kg", 8.45, "Mercearia"),
+        ("Macarrão espaguete 500g", 4.99, "Mercearia"),
+        ("Molho de tomate 340g", 3.79, "Mercearia"),
+        ("Azeite extra virgem 500ml", 39.90, "Mercearia"),
+        ("Sal refinado 1kg", 2.60, "Mercearia"),
+        ("Farinha de trigo 1kg", 5.20, "Mercearia"),
+        ("Ovos brancos (dúzia)", 13.50, "Hortifruti"),
+        ("Banana prata 1kg", 6.90, "Hortifruti"),
+        ("Maçã gala 1kg", 9.80, "Hortifruti"),
+        ("Detergente 500ml", 2.35, "Limpeza"),
+        ("Sabão em pó 1kg", 18.40, "Limpeza"),
+        ("Papel higiênico 12 rolos", 21.90, "Limpeza"),
+        ("Água mineral 1,5L", 2.99, "Bebidas"),
+        ("Suco de laranja 1L", 10.50, "Bebidas"),
+        ("Chocolate ao leite 90g", 6.25, "Doces"),
+        ("Biscoito cream cracker", 4.10, "Padaria"),
+    ]
+    .into_iter()
+    .zip(1..)
+    .map(|((nome, preco, categoria), id)| Item {
+        id,
+        nome: nome.to_string(),
+        preco,
+        descricao: None,
+        categoria: Some(categoria.to_string()),
+        sku: Some(format!("{}-{:03}", categoria[..3].to_uppercase(), id)),
+        estoque: Some(id * 7 % 40),
+        ativo: true,
+        criado_em: Some(data_hora(INICIO_EXEMPLO + id as u64 * DIA)),
+        atualizado_em: Some(data_hora(INICIO_EXEMPLO + id as u64 * DIA + 90 * DIA)),
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn novo(json: serde_json::Value) -> NovoItem {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn params(busca: &str, ordenar_por: &str, ordem: &str, pagina: i64, por_pagina: i64) -> ListarParams {
+        ListarParams {
+            busca: busca.into(),
+            ordenar_por: ordenar_por.into(),
+            ordem: ordem.into(),
+            pagina,
+            por_pagina,
+            ..ListarParams::default()
+        }
+    }
+
+    #[test]
+    fn pagina_com_totais() {
+        let store = Store::new(dados_de_exemplo());
+        let res = store.listar(&params("", "id", "asc", 3, 10)).unwrap();
+
+        assert_eq!(res.itens.iter().map(|i| i.id).collect::<Vec<_>>(), (21..=25).collect::<Vec<_>>());
+        assert_eq!((res.total, res.pagina, res.por_pagina, res.total_paginas), (25, 3, 10, 3));
+    }
+
+    #[test]
+    fn busca_por_id_ou_trecho_do_nome() {
+        let store = Store::new(dados_de_exemplo());
+
+        let por_id = store.listar(&params("16", "id", "asc", 1, 10)).unwrap();
+        assert_eq!(por_id.itens.iter().map(|i| i.id).collect::<Vec<_>>(), vec![16]);
+
+        let por_nome = store.listar(&params("  LEITE ", "id", "asc", 1, 10)).unwrap();
+        assert_eq!(por_nome.total, 2);
+    }
+
+    #[test]
+    fn modos_de_busca_pelo_nome() {
+        let store = Store::new(dados_de_exemplo());
+        let ids = |busca: &str, modo: &str| {
+            let params = ListarParams {
+                busca: busca.into(),
+                modo: modo.into(),
+                ..ListarParams::default()
+            };
+            store.listar(&params).map(|r| r.itens.iter().map(|i| i.id).collect::<Vec<_>>())
+        };
+
+        assert_eq!(ids("leite", "comeca_com"), Ok(vec![4]));
+        assert_eq!(ids("PÃO DE FORMA", "exato"), Ok(vec![5]));
+        assert_eq!(ids("^(arroz|feijão) ", "regex"), Ok(vec![9, 10]));
+        assert!(ids("(", "regex").unwrap_err().starts_with("Expressão regular inválida"));
+    }
+
+    #[test]
+    fn faixas_de_preco_e_id() {
+        let store = Store::new(dados_de_exemplo());
+        let params = ListarParams {
+            preco_min: Some(10.0),
+            preco_max: Some(20.0),
+            id_min: Some(5),
+            id_max: Some(20),
+            ..ListarParams::default()
+        };
+
+        let res = store.listar(&params).unwrap();
+        assert_eq!(res.itens.iter().map(|i| i.id).collect::<Vec<_>>(), vec![6, 8, 16, 20]);
+        assert_eq!(res.total, 4);
+    }
+
+    #[test]
+    fn ordena_por_coluna_e_direcao() {
+        let store = Store::new(dados_de_exemplo());
+
+        let caros = store.listar(&params("", "preco", "desc", 1, 2)).unwrap();
+        assert_eq!(caros.itens[0].nome, "Café em grãos 1kg");
+        assert_eq!(caros.itens[1].nome, "Azeite extra virgem 500ml");
+
+        let por_nome = store.listar(&params("", "nome", "asc", 1, 1)).unwrap();
+        assert_eq!(por_nome.itens[0].nome, "Arroz branco 5kg");
+    }
+
+    #[test]
+    fn pagina_e_tamanho_fora_dos_limites_sao_ajustados() {
+        let store = Store::new(dados_de_exemplo());
+        let res = store.listar(&params("", "id", "asc", 0, 1000)).unwrap();
+
+        assert_eq!((res.pagina, res.por_pagina), (1, POR_PAGINA_MAXIMO));
+        assert_eq!(res.itens.len(), 25);
+
+        let alem = store.listar(&params("", "id", "asc", i64::MAX, 100)).unwrap();
+        assert!(alem.itens.is_empty());
+        assert_eq!((alem.total, alem.total_paginas), (25, 1));
+    }
+
+    #[test]
+    fn crud_mantem_ids_crescentes() {
+        let mut store = Store::new(Vec::new());
+        let novo = |nome: &str| novo(serde_json::json!({ "nome": nome, "preco": 1.0 }));
+
+        let a = store.criar(novo("A")).unwrap();
+        let b = store.criar(novo("B")).unwrap();
+        assert_eq!((a.id, b.id), (1, 2));
+
+        assert!(store.remover(2).unwrap());
+        assert_eq!(store.criar(novo("C")).unwrap().id, 3);
+
+        assert_eq!(store.atualizar(1, novo("A2")).unwrap().unwrap().nome, "A2");
+        assert_eq!(store.atualizar(9, novo("X")).unwrap(), None);
+        assert!(!store.remover(9).unwrap());
+    }
+
+    #[test]
+    fn validacao_aponta_os_campos() {
+        let campos = validar(&novo(serde_json::json!({ "nome": " ", "preco": -1.0, "estoque": -2 })));
+        assert_eq!(campos.keys().copied().collect::<Vec<_>>(), vec!["estoque", "nome", "preco"]);
+    }
+
+    #[test]
+    fn categorias_filtram_a_lista() {
+        let store = Store::new(dados_de_exemplo());
+        assert_eq!(store.categorias()[..3], ["Bebidas", "Doces", "Frios"]);
+
+        let params = ListarParams {
+            categoria: "bebidas".into(),
+            ..ListarParams::default()
+        };
+        let res = store.listar(&params).unwrap();
+        assert_eq!(res.itens.iter().map(|i| i.id).collect::<Vec<_>>(), vec![2, 22, 23]);
+    }
+
+    #[test]
+    fn busca_pelo_sku_ou_pela_descricao() {
+        let mut store = Store::new(dados_de_exemplo());
+        let cafe = novo(serde_json::json!({ "nome": "Café", "preco": 30.0, "descricao": "Torra média, 100% arábica" }));
+        store.atualizar(1, cafe).unwrap();
+
+        let ids = |busca: &str| {
+            let res = store.listar(&params(busca, "id", "asc", 1, 10)).unwrap();
+            res.itens.iter().map(|i| i.id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids("lim-019"), vec![19]);
+        assert_eq!(ids("arábica"), vec![1]);
+    }
+
+    #[test]
+    fn data_hora_em_iso_8601() {
+        assert_eq!(data_hora(0), "1970-01-01T00:00:00Z");
+        assert_eq!(data_hora(INICIO_EXEMPLO), "2025-01-01T12:00:00Z");
+        assert_eq!(data_hora(1_709_210_096), "2024-02-29T12:34:56Z");
+    }
+
+    #[test]
+    fn historico_registra_as_mudancas_de_preco() {
+        let mut store = Store::exemplo();
+        let cafe = store.historico(1).unwrap();
+        assert_eq!(cafe.len(), 4);
+        assert_eq!(cafe[0].em, "2025-01-02T12:00:00Z");
+        assert_eq!(cafe.last().unwrap().preco, store.obter(1).unwrap().preco);
+
+        let criado = store.criar(novo(serde_json::json!({ "nome": "Chá", "preco": 5.0 }))).unwrap();
+        assert_eq!(criado.criado_em, criado.atualizado_em);
+        store.atualizar(criado.id, novo(serde_json::json!({ "nome": "Chá verde", "preco": 5.0 }))).unwrap();
+        let atualizado = store.atualizar(criado.id, novo(serde_json::json!({ "nome": "Chá", "preco": 6.5 }))).unwrap().unwrap();
+        assert_eq!(atualizado.criado_em, criado.criado_em);
+
+        let precos: Vec<f64> = store.historico(criado.id).unwrap().iter().map(|a| a.preco).collect();
+        assert_eq!(precos, vec![5.0, 6.5]);
+
+        store.remover(criado.id).unwrap();
+        assert_eq!(store.historico(criado.id), None);
+    }
+
+    #[test]
+    fn arquivo_antigo_recebe_os_valores_padrao() {
+        let itens: Vec<Item> = serde_json::from_str(r#"[{"id": 1, "nome": "Café", "preco": 19.9}]"#).unwrap();
+        assert_eq!((itens[0].categoria.as_deref(), itens[0].estoque, itens[0].ativo), (None, None, true));
+        assert_eq!(
+            serde_json::to_value(&itens[0]).unwrap(),
+            serde_json::json!({ "id": 1, "nome": "Café", "preco": 19.9, "ativo": true })
+        );
+    }
+}
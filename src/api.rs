@@ -0,0 +1,545 @@
+use std::cmp::Ordering;
+
+use gloo_net::http::{Method, Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use web_sys::AbortSignal;
+use yew::Callback;
+
+use crate::dinheiro::Dinheiro;
+use crate::error::ApiError;
+use crate::filtros::{Filtros, ModoNome};
+use crate::models::{AlteracaoPreco, Item, ListarResponse, NovoItem};
+
+/// Parâmetros da listagem. Também é o formato da query string da rota `/itens`,
+/// por isso campos com valor padrão são omitidos na serialização.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ListarParams {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub busca: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub categoria: String,
+    #[serde(skip_serializing_if = "eh_ordenar_por_padrao")]
+    pub ordenar_por: String,
+    #[serde(skip_serializing_if = "eh_ordem_padrao")]
+    pub ordem: String,
+    #[serde(skip_serializing_if = "eh_pagina_padrao", deserialize_with = "pagina_ou_padrao")]
+    pub pagina: i64,
+    #[serde(skip_serializing_if = "eh_por_pagina_padrao", deserialize_with = "por_pagina_ou_padrao")]
+    pub por_pagina: i64,
+    #[serde(skip_serializing_if = "Option::is_none", with = "preco_na_query")]
+    pub preco_min: Option<Dinheiro>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "preco_na_query")]
+    pub preco_max: Option<Dinheiro>,
+    #[serde(skip_serializing_if = "Option::is_none", deserialize_with = "id_ou_nenhum")]
+    pub id_min: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", deserialize_with = "id_ou_nenhum")]
+    pub id_max: Option<i32>,
+    #[serde(skip_serializing_if = "eh_modo_padrao", deserialize_with = "modo_ou_padrao")]
+    pub modo: ModoNome,
+}
+
+pub const COLUNAS_ORDENAVEIS: [&str; 3] = ["id", "nome", "preco"];
+pub const OPCOES_POR_PAGINA: [i64; 4] = [5, 10, 20, 50];
+/// Maior `por_pagina` aceito pela API.
+pub const POR_PAGINA_MAXIMO: i64 = 100;
+
+fn eh_ordenar_por_padrao(v: &str) -> bool {
+    v == "id"
+}
+
+fn eh_ordem_padrao(v: &str) -> bool {
+    v == "asc"
+}
+
+fn eh_pagina_padrao(v: &i64) -> bool {
+    *v == 1
+}
+
+fn eh_por_pagina_padrao(v: &i64) -> bool {
+    *v == 10
+}
+
+fn eh_modo_padrao(v: &ModoNome) -> bool {
+    *v == ModoNome::Contem
+}
+
+// Um parâmetro inválido numa URL editada à mão fica com o valor padrão, sem descartar os outros.
+
+/// Texto do parâmetro, venha da query string ou de JSON.
+fn texto_do_parametro<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(texto) => texto,
+        outro => outro.to_string(),
+    })
+}
+
+fn pagina_ou_padrao<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    Ok(texto_do_parametro(deserializer)?.parse().unwrap_or(ListarParams::default().pagina))
+}
+
+fn por_pagina_ou_padrao<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    Ok(texto_do_parametro(deserializer)?.parse().unwrap_or(ListarParams::default().por_pagina))
+}
+
+fn id_ou_nenhum<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i32>, D::Error> {
+    Ok(texto_do_parametro(deserializer)?.parse().ok())
+}
+
+fn modo_ou_padrao<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ModoNome, D::Error> {
+    Ok(ModoNome::de_valor(&texto_do_parametro(deserializer)?).unwrap_or_default())
+}
+
+/// Preços da query no formato canônico (`10.50`), sem passar por `f64`.
+mod preco_na_query {
+    use serde::{Deserializer, Serializer};
+
+    use crate::dinheiro::Dinheiro;
+
+    pub fn serialize<S: Serializer>(valor: &Option<Dinheiro>, serializer: S) -> Result<S::Ok, S::Error> {
+        match valor {
+            Some(valor) => serializer.serialize_some(&valor.canonico()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Um valor fora do formato fica sem filtro.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Dinheiro>, D::Error> {
+        Ok(Dinheiro::de_canonico(&super::texto_do_parametro(deserializer)?))
+    }
+}
+
+impl Default for ListarParams {
+    fn default() -> Self {
+        Self {
+            busca: String::new(),
+            categoria: String::new(),
+            ordenar_por: "id".to_string(),
+            ordem: "asc".to_string(),
+            pagina: 1,
+            por_pagina: 10,
+            preco_min: None,
+            preco_max: None,
+            id_min: None,
+            id_max: None,
+            modo: ModoNome::Contem,
+        }
+    }
+}
+
+impl ListarParams {
+    /// Descarta valores fora do domínio aceito (por exemplo, vindos de uma URL editada à mão).
+    pub fn normalizado(mut self) -> Self {
+        let padrao = Self::default();
+        if !COLUNAS_ORDENAVEIS.contains(&self.ordenar_por.as_str()) {
+            self.ordenar_por = padrao.ordenar_por;
+        }
+        if self.ordem != "asc" && self.ordem != "desc" {
+            self.ordem = padrao.ordem;
+        }
+        if self.pagina < 1 {
+            self.pagina = padrao.pagina;
+        }
+        if !(1..=POR_PAGINA_MAXIMO).contains(&self.por_pagina) {
+            self.por_pagina = padrao.por_pagina;
+        }
+        self
+    }
+
+    /// Clicar na coluna já ordenada inverte a ordem; outra coluna começa em `asc`.
+    pub fn ordenado_por(&self, coluna: &str) -> Self {
+        let ordem = if self.ordenar_por == coluna && self.ordem == "asc" {
+            "desc"
+        } else {
+            "asc"
+        };
+        Self {
+            ordenar_por: coluna.to_string(),
+            ordem: ordem.to_string(),
+            pagina: 1,
+            ..self.clone()
+        }
+    }
+
+    pub fn na_pagina(&self, pagina: i64) -> Self {
+        Self {
+            pagina,
+            ..self.clone()
+        }
+    }
+
+    pub fn com_busca(&self, busca: &str) -> Self {
+        Self {
+            busca: busca.to_string(),
+            pagina: 1,
+            ..self.clone()
+        }
+    }
+
+    pub fn com_categoria(&self, categoria: &str) -> Self {
+        Self {
+            categoria: categoria.to_string(),
+            pagina: 1,
+            ..self.clone()
+        }
+    }
+
+    pub fn com_por_pagina(&self, por_pagina: i64) -> Self {
+        Self {
+            por_pagina,
+            pagina: 1,
+            ..self.clone()
+        }
+    }
+
+    pub fn com_filtros(&self, filtros: &Filtros) -> Self {
+        Self {
+            preco_min: filtros.preco_min,
+            preco_max: filtros.preco_max,
+            id_min: filtros.id_min,
+            id_max: filtros.id_max,
+            modo: filtros.modo,
+            pagina: 1,
+            ..self.clone()
+        }
+    }
+
+    /// Se o item entra no resultado da consulta. `None` quando há uma busca por texto,
+    /// que só a API sabe aplicar (nome, descrição, SKU e expressões regulares).
+    pub fn confere(&self, item: &Item) -> Option<bool> {
+        if !self.busca.trim().is_empty() {
+            return None;
+        }
+        let categoria = self.categoria.is_empty()
+            || item.categoria.as_deref().is_some_and(|c| c.to_lowercase() == self.categoria.to_lowercase());
+        Some(
+            categoria
+                && self.preco_min.is_none_or(|min| item.preco >= min)
+                && self.preco_max.is_none_or(|max| item.preco <= max)
+                && self.id_min.is_none_or(|min| item.id >= min)
+                && self.id_max.is_none_or(|max| item.id <= max),
+        )
+    }
+
+    /// Ordem dos itens na consulta, a mesma aplicada pela API.
+    pub fn compara(&self, a: &Item, b: &Item) -> Ordering {
+        let ordem = match self.ordenar_por.as_str() {
+            "nome" => a.nome.to_lowercase().cmp(&b.nome.to_lowercase()),
+            "preco" => a.preco.cmp(&b.preco),
+            _ => a.id.cmp(&b.id),
+        };
+        if self.ordem == "desc" { ordem.reverse() } else { ordem }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct Credenciais<'a> {
+    usuario: &'a str,
+    senha: &'a str,
+}
+
+#[derive(serde::Deserialize)]
+struct RespostaLogin {
+    #[serde(alias = "access_token")]
+    token: String,
+}
+
+/// Cliente HTTP tipado para os endpoints `/itens` da `rust-api-sample`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItensClient {
+    base_url: String,
+    token: Option<String>,
+    /// Chamado quando a API responde 401, para pedir um novo login.
+    nao_autorizado: Option<Callback<()>>,
+}
+
+impl ItensClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self {
+            base_url,
+            token: None,
+            nao_autorizado: None,
+        }
+    }
+
+    /// Envia `Authorization: Bearer <token>` em todas as requisições de itens.
+    pub fn com_token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
+    pub fn ao_nao_autorizado(mut self, callback: Callback<()>) -> Self {
+        self.nao_autorizado = Some(callback);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn url(&self, caminho: &str) -> String {
+        format!("{}{}", self.base_url, caminho)
+    }
+
+    fn requisicao(&self, metodo: Method, caminho: &str) -> RequestBuilder {
+        let req = RequestBuilder::new(&self.url(caminho)).method(metodo);
+        match &self.token {
+            Some(token) => req.header("Authorization", &format!("Bearer {}", token)),
+            None => req,
+        }
+    }
+
+    async fn enviar(&self, requisicao: Result<Request, gloo_net::Error>) -> Result<Response, ApiError> {
+        let resp = requisicao
+            .map_err(|e| ApiError::Rede(e.to_string()))?
+            .send()
+            .await
+            .map_err(|e| ApiError::Rede(e.to_string()))?;
+        if let (401, Some(callback)) = (resp.status(), &self.nao_autorizado) {
+            callback.emit(());
+        }
+        Ok(resp)
+    }
+
+    /// Troca usuário e senha por um token em `POST /login`.
+    pub async fn login(&self, usuario: &str, senha: &str) -> Result<String, ApiError> {
+        let req = Request::post(&self.url("/login"))
+            .json(&Credenciais { usuario, senha })
+            .map_err(|e| ApiError::Rede(e.to_string()))?;
+        let resp = req.send().await.map_err(|e| ApiError::Rede(e.to_string()))?;
+        ler_json::<RespostaLogin>(resp).await.map(|r| r.token)
+    }
+
+    pub async fn list(&self, params: &ListarParams) -> Result<ListarResponse, ApiError> {
+        self.listar(params, None).await
+    }
+
+    /// Como `list`, mas a requisição é abortada quando `sinal` dispara.
+    pub async fn list_cancelavel(&self, params: &ListarParams, sinal: &AbortSignal) -> Result<ListarResponse, ApiError> {
+        self.listar(params, Some(sinal)).await
+    }
+
+    async fn listar(&self, params: &ListarParams, sinal: Option<&AbortSignal>) -> Result<ListarResponse, ApiError> {
+        let pagina = params.pagina.to_string();
+        let por_pagina = params.por_pagina.to_string();
+        let mut query = vec![
+            ("pagina", pagina.as_str()),
+            ("por_pagina", por_pagina.as_str()),
+            ("ordenar_por", params.ordenar_por.as_str()),
+            ("ordem", params.ordem.as_str()),
+        ];
+
+        if !params.busca.trim().is_empty() {
+            query.push(("busca", params.busca.as_str()));
+        }
+        if !params.categoria.is_empty() {
+            query.push(("categoria", params.categoria.as_str()));
+        }
+        if params.modo != ModoNome::Contem {
+            query.push(("modo", params.modo.valor()));
+        }
+        let limites = [
+            ("preco_min", params.preco_min.map(Dinheiro::canonico)),
+            ("preco_max", params.preco_max.map(Dinheiro::canonico)),
+            ("id_min", params.id_min.map(|v| v.to_string())),
+            ("id_max", params.id_max.map(|v| v.to_string())),
+        ];
+        for (nome, valor) in &limites {
+            if let Some(valor) = valor {
+                query.push((nome, valor.as_str()));
+            }
+        }
+
+        let req = self.requisicao(Method::GET, "/itens").query(query).abort_signal(sinal);
+        ler_json(self.enviar(req.build()).await?).await
+    }
+
+    /// Percorre todas as páginas da consulta, na ordem pedida, e devolve os itens juntos.
+    pub async fn list_all(&self, params: &ListarParams) -> Result<Vec<Item>, ApiError> {
+        let mut params = params.na_pagina(1);
+        params.por_pagina = POR_PAGINA_MAXIMO;
+        let mut itens = Vec::new();
+
+        loop {
+            let res = self.list(&params).await?;
+            let vazia = res.itens.is_empty();
+            itens.extend(res.itens);
+            if vazia || params.pagina >= res.total_paginas {
+                return Ok(itens);
+            }
+            params.pagina += 1;
+        }
+    }
+
+    /// Categorias dos itens cadastrados, em ordem alfabética (`GET /categorias`).
+    pub async fn categorias(&self) -> Result<Vec<String>, ApiError> {
+        let req = self.requisicao(Method::GET, "/categorias");
+        ler_json(self.enviar(req.build()).await?).await
+    }
+
+    pub async fn get(&self, id: i32) -> Result<Item, ApiError> {
+        let req = self.requisicao(Method::GET, &format!("/itens/{}", id));
+        ler_json(self.enviar(req.build()).await?).await
+    }
+
+    /// Preços que o item já teve, do mais antigo ao atual (`GET /itens/:id/historico`).
+    pub async fn historico(&self, id: i32) -> Result<Vec<AlteracaoPreco>, ApiError> {
+        let req = self.requisicao(Method::GET, &format!("/itens/{}/historico", id));
+        ler_json(self.enviar(req.build()).await?).await
+    }
+
+    pub async fn create(&self, novo: &NovoItem) -> Result<Item, ApiError> {
+        let req = self.requisicao(Method::POST, "/itens").json(novo);
+        ler_json(self.enviar(req).await?).await
+    }
+
+    pub async fn update(&self, id: i32, item: &NovoItem) -> Result<Item, ApiError> {
+        let req = self.requisicao(Method::PUT, &format!("/itens/{}", id)).json(item);
+        ler_json(self.enviar(req).await?).await
+    }
+
+    pub async fn delete(&self, id: i32) -> Result<(), ApiError> {
+        let req = self.requisicao(Method::DELETE, &format!("/itens/{}", id));
+        verificar_status(self.enviar(req.build()).await?).await.map(|_| ())
+    }
+}
+
+async fn verificar_status(resp: Response) -> Result<Response, ApiError> {
+    if resp.ok() {
+        return Ok(resp);
+    }
+    let corpo = resp.text().await.unwrap_or_default();
+    Err(ApiError::from_status(resp.status(), &corpo))
+}
+
+async fn ler_json<T: DeserializeOwned>(resp: Response) -> Result<T, ApiError> {
+    let resp = verificar_status(resp).await?;
+    resp.json::<T>()
+        .await
+        .map_err(|e| ApiError::Resposta(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicar_na_mesma_coluna_alterna_a_ordem() {
+        let consulta = ListarParams::default();
+
+        let desc = consulta.ordenado_por("id");
+        assert_eq!((desc.ordenar_por.as_str(), desc.ordem.as_str()), ("id", "desc"));
+
+        let asc = desc.ordenado_por("id");
+        assert_eq!((asc.ordenar_por.as_str(), asc.ordem.as_str()), ("id", "asc"));
+    }
+
+    #[test]
+    fn clicar_em_outra_coluna_comeca_ascendente_na_primeira_pagina() {
+        let consulta = ListarParams {
+            ordenar_por: "nome".into(),
+            ordem: "desc".into(),
+            pagina: 4,
+            ..ListarParams::default()
+        };
+
+        let nova = consulta.ordenado_por("preco");
+        assert_eq!(nova.ordenar_por, "preco");
+        assert_eq!(nova.ordem, "asc");
+        assert_eq!(nova.pagina, 1);
+    }
+
+    #[test]
+    fn busca_e_tamanho_da_pagina_voltam_para_a_primeira_pagina() {
+        let consulta = ListarParams::default().na_pagina(3);
+        assert_eq!(consulta.com_busca("cafe").pagina, 1);
+        assert_eq!(consulta.com_por_pagina(50).pagina, 1);
+        assert_eq!(consulta.com_categoria("Bebidas").pagina, 1);
+    }
+
+    #[test]
+    fn normalizado_descarta_valores_invalidos() {
+        let consulta = ListarParams {
+            busca: "cafe".into(),
+            ordenar_por: "drop table".into(),
+            ordem: "para cima".into(),
+            pagina: -2,
+            por_pagina: 1000,
+            ..ListarParams::default()
+        }
+        .normalizado();
+
+        assert_eq!(
+            consulta,
+            ListarParams {
+                busca: "cafe".into(),
+                ..ListarParams::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parametro_invalido_na_url_nao_descarta_os_outros() {
+        let consulta: ListarParams = serde_json::from_value(serde_json::json!({
+            "busca": "cafe",
+            "modo": "xyz",
+            "pagina": "abc",
+            "por_pagina": "20",
+            "id_min": "1.5",
+            "id_max": "8",
+            "preco_max": "dez",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            consulta,
+            ListarParams {
+                busca: "cafe".into(),
+                por_pagina: 20,
+                id_max: Some(8),
+                ..ListarParams::default()
+            }
+        );
+        let ida_e_volta: ListarParams = serde_json::from_value(serde_json::to_value(&consulta).unwrap()).unwrap();
+        assert_eq!(ida_e_volta, consulta);
+    }
+
+    #[test]
+    fn serializacao_omite_valores_padrao() {
+        let json = serde_json::to_value(ListarParams::default()).unwrap();
+        assert_eq!(json, serde_json::json!({}));
+
+        let json = serde_json::to_value(ListarParams::default().com_busca("cafe").ordenado_por("preco")).unwrap();
+        assert_eq!(json, serde_json::json!({ "busca": "cafe", "ordenar_por": "preco" }));
+
+        let json = serde_json::to_value(ListarParams::default().com_categoria("Bebidas")).unwrap();
+        assert_eq!(json, serde_json::json!({ "categoria": "Bebidas" }));
+    }
+
+    #[test]
+    fn filtros_entram_na_consulta_e_voltam_para_a_primeira_pagina() {
+        let filtros = Filtros {
+            preco_min: Some(Dinheiro::de_centavos(1050)),
+            id_max: Some(20),
+            modo: ModoNome::Regex,
+            ..Filtros::default()
+        };
+        let consulta = ListarParams::default().na_pagina(3).com_filtros(&filtros);
+
+        assert_eq!(consulta.pagina, 1);
+        assert_eq!(Filtros::de_consulta(&consulta), filtros);
+        assert_eq!(
+            serde_json::to_value(&consulta).unwrap(),
+            serde_json::json!({ "preco_min": "10.50", "id_max": 20, "modo": "regex" })
+        );
+        let lida: ListarParams = serde_json::from_value(serde_json::json!({ "preco_min": "10.50", "preco_max": "1e9" })).unwrap();
+        assert_eq!((lida.preco_min, lida.preco_max), (Some(Dinheiro::de_centavos(1050)), None));
+    }
+
+    #[test]
+    fn base_url_sem_barra_final() {
+        assert_eq!(ItensClient::new("http://localhost:3000/").base_url(), "http://localhost:3000");
+    }
+}
@@ -0,0 +1,207 @@
+//! Sessão do usuário: token JWT guardado no `localStorage`, suas claims e as permissões sobre itens.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use yew::prelude::*;
+
+use crate::i18n::{self, fluent_args};
+
+const CHAVE_STORAGE: &str = "rust-app-sample:token";
+
+/// Ações sobre itens que dependem das claims do token.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permissao {
+    Criar,
+    Editar,
+    Remover,
+}
+
+impl Permissao {
+    /// Escopo esperado nas claims, como `itens:criar`.
+    pub fn escopo(&self) -> &'static str {
+        match self {
+            Permissao::Criar => "itens:criar",
+            Permissao::Editar => "itens:editar",
+            Permissao::Remover => "itens:remover",
+        }
+    }
+}
+
+/// Claims lidas do payload do JWT. A assinatura é conferida pela API, não aqui.
+#[derive(Clone, Debug, PartialEq, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct Claims {
+    pub sub: String,
+    #[serde(alias = "name")]
+    pub nome: Option<String>,
+    /// Expiração, em segundos desde 1970.
+    pub exp: Option<i64>,
+    #[serde(alias = "permissions")]
+    pub permissoes: Vec<String>,
+    /// Escopos separados por espaço, no formato do OAuth.
+    pub scope: String,
+}
+
+impl Claims {
+    pub fn de_token(token: &str) -> Result<Self, String> {
+        let payload = token.split('.').nth(1).ok_or_else(|| i18n::t("token-nao-e-jwt"))?;
+        let json = URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|_| i18n::t("token-sem-base64"))?;
+        serde_json::from_slice(&json).map_err(|e| i18n::t_args("token-invalido", &fluent_args!["erro" => e.to_string()]))
+    }
+
+    pub fn permite(&self, permissao: Permissao) -> bool {
+        let escopo = permissao.escopo();
+        self.permissoes.iter().any(|p| p == escopo) || self.scope.split_whitespace().any(|p| p == escopo)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sessao {
+    pub token: String,
+    pub claims: Claims,
+}
+
+impl Sessao {
+    pub fn new(token: impl Into<String>) -> Result<Self, String> {
+        let token = token.into();
+        let claims = Claims::de_token(&token)?;
+        Ok(Self { token, claims })
+    }
+
+    pub fn usuario(&self) -> &str {
+        self.claims.nome.as_deref().unwrap_or(&self.claims.sub)
+    }
+
+    pub fn permite(&self, permissao: Permissao) -> bool {
+        self.claims.permite(permissao)
+    }
+
+    /// `agora` em segundos desde 1970.
+    pub fn expirada(&self, agora: i64) -> bool {
+        self.claims.exp.is_some_and(|exp| exp <= agora)
+    }
+
+    /// Milissegundos até a expiração; `None` se o token não expira.
+    pub fn restante_ms(&self, agora_ms: f64) -> Option<f64> {
+        self.claims.exp.map(|exp| (exp as f64 * 1000.0 - agora_ms).max(0.0))
+    }
+}
+
+fn agora_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Sessão gravada, descartando tokens inválidos ou expirados.
+pub fn carregar() -> Option<Sessao> {
+    let token = storage()?.get_item(CHAVE_STORAGE).ok()??;
+    Sessao::new(token)
+        .ok()
+        .filter(|s| !s.expirada((agora_ms() / 1000.0) as i64))
+}
+
+pub fn gravar(sessao: &Sessao) {
+    if let Some(storage) = storage() {
+        let _ = storage.set_item(CHAVE_STORAGE, &sessao.token);
+    }
+}
+
+pub fn apagar() {
+    if let Some(storage) = storage() {
+        let _ = storage.remove_item(CHAVE_STORAGE);
+    }
+}
+
+/// Tempo até a sessão expirar, para agendar a saída; `None` se não expira ou se passa do
+/// limite do `setTimeout`.
+pub fn ms_ate_expirar(sessao: &Sessao) -> Option<u32> {
+    sessao
+        .restante_ms(agora_ms())
+        .filter(|ms| *ms <= i32::MAX as f64)
+        .map(|ms| ms as u32)
+}
+
+/// Sessão atual e as ações de entrar e sair, compartilhadas por contexto.
+#[derive(Clone, PartialEq)]
+pub struct Autenticacao {
+    pub sessao: Option<Sessao>,
+    /// Por que a última sessão terminou, mostrado na tela de login.
+    pub aviso: Option<String>,
+    pub entrar: Callback<Sessao>,
+    /// Encerra a sessão e vai para o login, com o motivo opcional.
+    pub sair: Callback<Option<String>>,
+}
+
+impl Autenticacao {
+    /// Sem sessão, a API é tratada como aberta; se não for, ela responde 401 e o login é pedido.
+    pub fn pode(&self, permissao: Permissao) -> bool {
+        self.sessao.as_ref().is_none_or(|s| s.permite(permissao))
+    }
+}
+
+#[hook]
+pub fn use_autenticacao() -> Autenticacao {
+    use_context::<Autenticacao>().expect("use_autenticacao chamado fora de um ContextProvider<Autenticacao>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(payload: &str) -> String {
+        format!("eyJhbGciOiJIUzI1NiJ9.{}.assinatura", URL_SAFE_NO_PAD.encode(payload))
+    }
+
+    #[test]
+    fn le_as_claims_do_payload() {
+        let sessao = Sessao::new(token(
+            r#"{"sub": "ana", "name": "Ana", "exp": 2000, "permissions": ["itens:criar", "itens:remover"]}"#,
+        ))
+        .unwrap();
+
+        assert_eq!(sessao.usuario(), "Ana");
+        assert!(sessao.permite(Permissao::Criar));
+        assert!(!sessao.permite(Permissao::Editar));
+        assert!(sessao.permite(Permissao::Remover));
+        assert!(!sessao.expirada(1999));
+        assert!(sessao.expirada(2000));
+        assert_eq!(sessao.restante_ms(1_500_000.0), Some(500_000.0));
+    }
+
+    #[test]
+    fn aceita_escopos_no_formato_oauth() {
+        let sessao = Sessao::new(token(r#"{"sub": "bot", "scope": "itens:ler itens:editar"}"#)).unwrap();
+
+        assert_eq!(sessao.usuario(), "bot");
+        assert!(sessao.permite(Permissao::Editar));
+        assert!(!sessao.permite(Permissao::Criar));
+        assert!(!sessao.expirada(i64::MAX));
+        assert_eq!(sessao.restante_ms(0.0), None);
+    }
+
+    #[test]
+    fn rejeita_tokens_malformados() {
+        assert!(Sessao::new("sem-pontos").unwrap_err().contains("não é um JWT"));
+        assert!(Sessao::new("a.%%%.c").unwrap_err().contains("base64"));
+        assert!(Sessao::new(token("[1, 2]")).is_err());
+    }
+
+    #[test]
+    fn sem_sessao_tudo_e_permitido() {
+        let mut auth = Autenticacao {
+            sessao: None,
+            aviso: None,
+            entrar: Callback::noop(),
+            sair: Callback::noop(),
+        };
+        assert!(auth.pode(Permissao::Remover));
+
+        auth.sessao = Some(Sessao::new(token(r#"{"sub": "leitor"}"#)).unwrap());
+        assert!(!auth.pode(Permissao::Remover));
+    }
+}
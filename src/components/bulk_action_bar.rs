@@ -0,0 +1,134 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::dinheiro;
+use crate::i18n::{fluent_args, use_i18n};
+use crate::lote::AjustePreco;
+
+#[derive(Properties, PartialEq)]
+pub struct BulkActionBarProps {
+    pub quantidade: usize,
+    /// Itens que correspondem à busca atual, em todas as páginas.
+    pub total_busca: i64,
+    /// `(concluídos, total)` enquanto uma operação está em andamento.
+    #[prop_or_default]
+    pub progresso: Option<(usize, usize)>,
+    #[prop_or_default]
+    pub carregando_todos: bool,
+    pub on_select_all: Callback<()>,
+    pub on_clear: Callback<()>,
+    /// Sem o callback, a ação correspondente não aparece.
+    #[prop_or_default]
+    pub on_delete: Option<Callback<()>>,
+    #[prop_or_default]
+    pub on_adjust: Option<Callback<AjustePreco>>,
+}
+
+#[function_component(BulkActionBar)]
+pub fn bulk_action_bar(props: &BulkActionBarProps) -> Html {
+    let i18n = use_i18n();
+    let valor = use_state(String::new);
+    let percentual = use_state(|| false);
+
+    if let Some((feitos, total)) = props.progresso {
+        return html! {
+            <div class="notification is-info is-light">
+                <p class="mb-2">{ i18n.t_args("lote-processando", &fluent_args!["feitos" => feitos, "total" => total]) }</p>
+                <progress class="progress is-info" value={feitos.to_string()} max={total.to_string()}></progress>
+            </div>
+        };
+    }
+
+    let ajuste = AjustePreco::parse(&valor, *percentual);
+
+    let on_change_valor = {
+        let valor = valor.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            valor.set(input.value());
+        })
+    };
+
+    let on_change_tipo = {
+        let percentual = percentual.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            percentual.set(select.value() == "percentual");
+        })
+    };
+
+    let on_apply = {
+        let on_adjust = props.on_adjust.clone();
+        let valor = valor.clone();
+        Callback::from(move |_| {
+            if let (Some(ajuste), Some(on_adjust)) = (ajuste, &on_adjust) {
+                on_adjust.emit(ajuste);
+                valor.set(String::new());
+            }
+        })
+    };
+
+    html! {
+        <div class="notification is-link is-light">
+            <div class="level">
+                <div class="level-left">
+                    <div class="level-item">
+                        <strong>{ i18n.t_args("lote-selecionados", &fluent_args!["n" => props.quantidade]) }</strong>
+                    </div>
+                    if (props.quantidade as i64) < props.total_busca {
+                        <div class="level-item">
+                            <button
+                                class={classes!("button", "is-small", "is-text", props.carregando_todos.then_some("is-loading"))}
+                                onclick={props.on_select_all.reform(|_| ())}
+                            >
+                                { i18n.t_args("lote-selecionar-todos", &fluent_args!["n" => props.total_busca]) }
+                            </button>
+                        </div>
+                    }
+                    <div class="level-item">
+                        <button class="button is-small is-text" onclick={props.on_clear.reform(|_| ())}>{ i18n.t("lote-limpar-selecao") }</button>
+                    </div>
+                </div>
+                <div class="level-right">
+                    if props.on_adjust.is_some() {
+                        <div class="level-item">
+                            <div class="field has-addons">
+                                <div class="control">
+                                    <div class="select is-small">
+                                        <select aria-label={i18n.t("lote-tipo-ajuste")} onchange={on_change_tipo}>
+                                            <option value="valor" selected={!*percentual}>{ dinheiro::moeda().simbolo(i18n.idioma) }</option>
+                                            <option value="percentual" selected={*percentual}>{ "%" }</option>
+                                        </select>
+                                    </div>
+                                </div>
+                                <div class="control">
+                                    <input
+                                        class="input is-small"
+                                        type="text"
+                                        inputmode="decimal"
+                                        placeholder={i18n.t("lote-ajuste-placeholder")}
+                                        aria-label={i18n.t("lote-ajuste-rotulo")}
+                                        value={(*valor).clone()}
+                                        oninput={on_change_valor}
+                                    />
+                                </div>
+                                <div class="control">
+                                    <button class="button is-small is-link" disabled={ajuste.is_none()} onclick={on_apply}>
+                                        { i18n.t("lote-ajustar") }
+                                    </button>
+                                </div>
+                            </div>
+                        </div>
+                    }
+                    if let Some(on_delete) = &props.on_delete {
+                        <div class="level-item">
+                            <button class="button is-small is-danger" onclick={on_delete.reform(|_| ())}>
+                                { i18n.t("lote-remover") }
+                            </button>
+                        </div>
+                    }
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,45 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::i18n::use_i18n;
+
+#[derive(Properties, PartialEq)]
+pub struct CategorySelectProps {
+    pub categorias: Vec<String>,
+    /// Categoria escolhida; vazia para todas.
+    pub valor: AttrValue,
+    pub on_change: Callback<String>,
+}
+
+#[function_component(CategorySelect)]
+pub fn category_select(props: &CategorySelectProps) -> Html {
+    let i18n = use_i18n();
+    let onchange = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_change.emit(select.value());
+        })
+    };
+
+    // A categoria da URL continua na lista mesmo que a API não a devolva.
+    let mut categorias = props.categorias.clone();
+    if !props.valor.is_empty() && !categorias.iter().any(|c| *c == props.valor.as_str()) {
+        categorias.push(props.valor.to_string());
+    }
+
+    html! {
+        <div class="field">
+            <div class="control">
+                <div class="select is-fullwidth">
+                    <select aria-label={i18n.t("coluna-categoria")} {onchange}>
+                        <option value="" selected={props.valor.is_empty()}>{ i18n.t("categoria-todas") }</option>
+                        { for categorias.iter().map(|c| html! {
+                            <option value={c.clone()} selected={props.valor == c.as_str()}>{ c }</option>
+                        })}
+                    </select>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,55 @@
+use yew::prelude::*;
+
+use crate::components::ColunaOpcional;
+use crate::i18n::use_i18n;
+
+#[derive(Properties, PartialEq)]
+pub struct ColumnsMenuProps {
+    /// Colunas opcionais visíveis.
+    pub colunas: Vec<ColunaOpcional>,
+    pub on_change: Callback<Vec<ColunaOpcional>>,
+}
+
+#[function_component(ColumnsMenu)]
+pub fn columns_menu(props: &ColumnsMenuProps) -> Html {
+    let i18n = use_i18n();
+    let aberto = use_state(|| false);
+
+    let alternar = {
+        let aberto = aberto.clone();
+        Callback::from(move |_| aberto.set(!*aberto))
+    };
+
+    html! {
+        <div class={classes!("dropdown", "is-right", aberto.then_some("is-active"))}>
+            <div class="dropdown-trigger">
+                <button class="button" aria-haspopup="true" onclick={alternar}>{ i18n.t("tabela-colunas") }</button>
+            </div>
+            <div class="dropdown-menu" role="menu">
+                <div class="dropdown-content">
+                    { for ColunaOpcional::TODAS.into_iter().map(|coluna| {
+                        let visivel = props.colunas.contains(&coluna);
+                        let onchange = {
+                            let colunas = props.colunas.clone();
+                            props.on_change.reform(move |_: Event| {
+                                let mut colunas = colunas.clone();
+                                if visivel {
+                                    colunas.retain(|c| *c != coluna);
+                                } else {
+                                    colunas.push(coluna);
+                                }
+                                colunas
+                            })
+                        };
+                        html! {
+                            <label class="dropdown-item checkbox" key={coluna.nome_api()}>
+                                <input type="checkbox" class="mr-2" aria-label={coluna.rotulo()} checked={visivel} {onchange} />
+                                { coluna.rotulo() }
+                            </label>
+                        }
+                    })}
+                </div>
+            </div>
+        </div>
+    }
+}
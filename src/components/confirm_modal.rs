@@ -0,0 +1,51 @@
+use yew::prelude::*;
+
+use crate::i18n::use_i18n;
+
+#[derive(Properties, PartialEq)]
+pub struct ConfirmModalProps {
+    pub titulo: AttrValue,
+    /// Padrão: "Confirmar", no idioma atual.
+    #[prop_or_default]
+    pub texto_confirmar: Option<AttrValue>,
+    /// Classe de cor do botão de confirmação.
+    #[prop_or(AttrValue::from("is-danger"))]
+    pub tipo: AttrValue,
+    #[prop_or_default]
+    pub carregando: bool,
+    pub on_confirm: Callback<()>,
+    pub on_cancel: Callback<()>,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(ConfirmModal)]
+pub fn confirm_modal(props: &ConfirmModalProps) -> Html {
+    let i18n = use_i18n();
+    let on_cancel = props.on_cancel.reform(|_: MouseEvent| ());
+
+    html! {
+        <div class="modal is-active">
+            <div class="modal-background" onclick={on_cancel.clone()}></div>
+            <div class="modal-card">
+                <header class="modal-card-head">
+                    <p class="modal-card-title">{ props.titulo.clone() }</p>
+                    <button class="delete" aria-label={i18n.t("fechar")} onclick={on_cancel.clone()}></button>
+                </header>
+                <section class="modal-card-body">
+                    { props.children.clone() }
+                </section>
+                <footer class="modal-card-foot">
+                    <button
+                        class={classes!("button", props.tipo.to_string(), props.carregando.then_some("is-loading"))}
+                        disabled={props.carregando}
+                        onclick={props.on_confirm.reform(|_| ())}
+                    >
+                        { props.texto_confirmar.clone().unwrap_or_else(|| i18n.t("confirmar").into()) }
+                    </button>
+                    <button class="button" disabled={props.carregando} onclick={on_cancel}>{ i18n.t("cancelar") }</button>
+                </footer>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,80 @@
+use yew::prelude::*;
+
+use crate::exportacao::{Escopo, Formato};
+use crate::i18n::use_i18n;
+
+#[derive(Properties, PartialEq)]
+pub struct ExportMenuProps {
+    #[prop_or_default]
+    pub carregando: bool,
+    pub on_export: Callback<(Formato, Escopo)>,
+}
+
+#[function_component(ExportMenu)]
+pub fn export_menu(props: &ExportMenuProps) -> Html {
+    let i18n = use_i18n();
+    let aberto = use_state(|| false);
+    let escopo = use_state(|| Escopo::PaginaAtual);
+
+    let alternar = {
+        let aberto = aberto.clone();
+        Callback::from(move |_| aberto.set(!*aberto))
+    };
+
+    let opcao_escopo = |valor: Escopo, id: &str| {
+        let rotulo = i18n.t(id);
+        let escopo_handle = escopo.clone();
+        html! {
+            <label class="radio">
+                <input
+                    type="radio"
+                    name="escopo-exportacao"
+                    checked={*escopo == valor}
+                    onchange={Callback::from(move |_| escopo_handle.set(valor))}
+                />
+                { format!(" {}", rotulo) }
+            </label>
+        }
+    };
+
+    html! {
+        <div class={classes!("dropdown", "is-right", aberto.then_some("is-active"))}>
+            <div class="dropdown-trigger">
+                <button
+                    class={classes!("button", props.carregando.then_some("is-loading"))}
+                    aria-haspopup="true"
+                    disabled={props.carregando}
+                    onclick={alternar}
+                >
+                    { i18n.t("exportar-botao") }
+                </button>
+            </div>
+            <div class="dropdown-menu" role="menu">
+                <div class="dropdown-content">
+                    <div class="dropdown-item">
+                        <p class="has-text-weight-semibold mb-2">{ i18n.t("exportar-itens") }</p>
+                        <div class="control">
+                            { opcao_escopo(Escopo::PaginaAtual, "exportar-pagina-atual") }
+                            <br />
+                            { opcao_escopo(Escopo::Todas, "exportar-todas") }
+                        </div>
+                    </div>
+                    <hr class="dropdown-divider" />
+                    { for Formato::TODOS.iter().map(|&formato| {
+                        let on_export = props.on_export.clone();
+                        let aberto = aberto.clone();
+                        let escopo = *escopo;
+                        let onclick = Callback::from(move |e: MouseEvent| {
+                            e.prevent_default();
+                            aberto.set(false);
+                            on_export.emit((formato, escopo));
+                        });
+                        html! {
+                            <a class="dropdown-item" href="#" {onclick}>{ formato.rotulo() }</a>
+                        }
+                    })}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,47 @@
+use yew::prelude::*;
+
+use crate::filtros::Filtros;
+use crate::i18n::{fluent_args, use_i18n};
+
+#[derive(Properties, PartialEq)]
+pub struct FilterChipsProps {
+    pub filtros: Filtros,
+    /// Recebe os filtros sem o que foi removido.
+    pub on_change: Callback<Filtros>,
+}
+
+/// Etiquetas dos filtros ativos, cada uma com um botão para removê-la.
+#[function_component(FilterChips)]
+pub fn filter_chips(props: &FilterChipsProps) -> Html {
+    let i18n = use_i18n();
+    let ativos = props.filtros.ativos();
+    if ativos.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="field is-grouped is-grouped-multiline mb-4">
+            { for ativos.into_iter().map(|campo| {
+                let rotulo = props.filtros.rotulo(campo);
+                let restante = props.filtros.sem(campo);
+                html! {
+                    <div class="control">
+                        <span class="tag is-info is-light is-medium">
+                            { rotulo.clone() }
+                            <button
+                                class="delete is-small"
+                                aria-label={i18n.t_args("filtros-remover", &fluent_args!["rotulo" => rotulo.as_str()])}
+                                onclick={props.on_change.reform(move |_| restante)}
+                            ></button>
+                        </span>
+                    </div>
+                }
+            })}
+            <div class="control">
+                <button class="button is-small is-text" onclick={props.on_change.reform(|_| Filtros::default())}>
+                    { i18n.t("filtros-limpar") }
+                </button>
+            </div>
+        </div>
+    }
+}
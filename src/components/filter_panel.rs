@@ -0,0 +1,179 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::dinheiro::Dinheiro;
+use crate::filtros::{Filtros, ModoNome};
+use crate::i18n::{Idioma, fluent_args, use_i18n};
+
+#[derive(Properties, PartialEq)]
+pub struct FilterPanelProps {
+    /// Filtros aplicados no momento; os campos são ressincronizados quando eles mudam.
+    pub filtros: Filtros,
+    pub on_apply: Callback<Filtros>,
+}
+
+/// Texto digitado nos campos, ainda não validado.
+#[derive(Clone, Default, PartialEq)]
+struct Rascunho {
+    preco_min: String,
+    preco_max: String,
+    id_min: String,
+    id_max: String,
+    modo: ModoNome,
+}
+
+impl Rascunho {
+    fn de(filtros: &Filtros, idioma: Idioma) -> Self {
+        let texto = |v: Option<String>| v.unwrap_or_default();
+        let preco = |v: Option<Dinheiro>| texto(v.map(|v| v.numero(idioma)));
+        Self {
+            preco_min: preco(filtros.preco_min),
+            preco_max: preco(filtros.preco_max),
+            id_min: texto(filtros.id_min.map(|v| v.to_string())),
+            id_max: texto(filtros.id_max.map(|v| v.to_string())),
+            modo: filtros.modo,
+        }
+    }
+}
+
+#[function_component(FilterPanel)]
+pub fn filter_panel(props: &FilterPanelProps) -> Html {
+    let i18n = use_i18n();
+    let aberto = use_state(|| false);
+    let rascunho = use_state(|| Rascunho::de(&props.filtros, i18n.idioma));
+    let erro = use_state(|| None::<String>);
+
+    {
+        let rascunho = rascunho.clone();
+        let erro = erro.clone();
+        use_effect_with((props.filtros, i18n.idioma), move |(filtros, idioma)| {
+            rascunho.set(Rascunho::de(filtros, *idioma));
+            erro.set(None);
+            || ()
+        });
+    }
+
+    let alternar = {
+        let aberto = aberto.clone();
+        Callback::from(move |_| aberto.set(!*aberto))
+    };
+
+    let campo = |id: &str, exemplo: &str, atualizar: fn(&mut Rascunho, String), valor: &str| {
+        let rotulo = i18n.t(id);
+        let placeholder = i18n.t_args("exemplo", &fluent_args!["valor" => exemplo]);
+        let rascunho = rascunho.clone();
+        let oninput = Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut novo = (*rascunho).clone();
+            atualizar(&mut novo, input.value());
+            rascunho.set(novo);
+        });
+        html! {
+            <div class="field">
+                <label class="label is-small">{ rotulo.clone() }</label>
+                <div class="control">
+                    <input
+                        class="input is-small"
+                        type="text"
+                        inputmode="decimal"
+                        aria-label={rotulo}
+                        {placeholder}
+                        value={valor.to_string()}
+                        {oninput}
+                    />
+                </div>
+            </div>
+        }
+    };
+
+    let on_change_modo = {
+        let rascunho = rascunho.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(modo) = ModoNome::de_valor(&select.value()) {
+                rascunho.set(Rascunho {
+                    modo,
+                    ..(*rascunho).clone()
+                });
+            }
+        })
+    };
+
+    let on_apply = {
+        let rascunho = rascunho.clone();
+        let erro = erro.clone();
+        let on_apply = props.on_apply.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let r = &*rascunho;
+            match Filtros::ler(&r.preco_min, &r.preco_max, &r.id_min, &r.id_max, r.modo) {
+                Ok(filtros) => {
+                    erro.set(None);
+                    on_apply.emit(filtros);
+                }
+                Err(msg) => erro.set(Some(msg)),
+            }
+        })
+    };
+
+    let on_clear = {
+        let on_apply = props.on_apply.clone();
+        Callback::from(move |_| on_apply.emit(Filtros::default()))
+    };
+
+    let ativos = props.filtros.ativos().len();
+    let r = &*rascunho;
+
+    html! {
+        <div class="mb-4">
+            <button class={classes!("button", "is-small", aberto.then_some("is-active"))} aria-expanded={aberto.to_string()} onclick={alternar}>
+                { format!("{} {}", if *aberto { "▾" } else { "▸" }, i18n.t("filtros-botao")) }
+                if ativos > 0 {
+                    <span class="tag is-info is-rounded ml-2">{ ativos }</span>
+                }
+            </button>
+
+            if *aberto {
+                <form class="box mt-3" onsubmit={on_apply}>
+                    <div class="columns is-multiline">
+                        <div class="column is-3">
+                            { campo("filtros-preco-min", &Dinheiro::de_centavos(1000).numero(i18n.idioma), |r, v| r.preco_min = v, &r.preco_min) }
+                        </div>
+                        <div class="column is-3">
+                            { campo("filtros-preco-max", &Dinheiro::de_centavos(9990).numero(i18n.idioma), |r, v| r.preco_max = v, &r.preco_max) }
+                        </div>
+                        <div class="column is-3">
+                            { campo("filtros-id-min", "1", |r, v| r.id_min = v, &r.id_min) }
+                        </div>
+                        <div class="column is-3">
+                            { campo("filtros-id-max", "100", |r, v| r.id_max = v, &r.id_max) }
+                        </div>
+                        <div class="column is-6">
+                            <div class="field">
+                                <label class="label is-small">{ i18n.t("filtros-nome-da-busca") }</label>
+                                <div class="control">
+                                    <div class="select is-small is-fullwidth">
+                                        <select aria-label={i18n.t("filtros-modo-do-nome")} onchange={on_change_modo}>
+                                            { for ModoNome::TODOS.iter().map(|m| html! {
+                                                <option value={m.valor()} selected={r.modo == *m}>{ m.rotulo() }</option>
+                                            })}
+                                        </select>
+                                    </div>
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+
+                    if let Some(msg) = &*erro {
+                        <p class="help is-danger mb-3">{ msg }</p>
+                    }
+
+                    <div class="buttons">
+                        <button type="submit" class="button is-small is-info">{ i18n.t("filtros-aplicar") }</button>
+                        <button type="button" class="button is-small" onclick={on_clear}>{ i18n.t("filtros-limpar") }</button>
+                    </div>
+                </form>
+            }
+        </div>
+    }
+}
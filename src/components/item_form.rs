@@ -0,0 +1,352 @@
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::components::Notification;
+use crate::dinheiro::{self, Dinheiro};
+use crate::error::ErroCampo;
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::models::NovoItem;
+
+pub fn validar_formulario(nome: &str, preco_txt: &str) -> Result<NovoItem, Vec<ErroCampo>> {
+    validar_item(nome, preco_txt, |texto| Dinheiro::ler(texto, i18n::atual()))
+}
+
+/// Como `validar_formulario`, mas o preço é lido por `ler_preco`, como na importação.
+pub fn validar_item(nome: &str, preco_txt: &str, ler_preco: impl Fn(&str) -> Option<Dinheiro>) -> Result<NovoItem, Vec<ErroCampo>> {
+    let mut erros = Vec::new();
+
+    if nome.trim().is_empty() {
+        erros.push(ErroCampo::new("nome", i18n::t("form-nome-obrigatorio")));
+    }
+
+    let preco = if preco_txt.trim().is_empty() {
+        erros.push(ErroCampo::new("preco", i18n::t("form-preco-obrigatorio")));
+        None
+    } else {
+        match ler_preco(preco_txt) {
+            Some(v) if !v.negativo() => Some(v),
+            _ => {
+                erros.push(ErroCampo::new("preco", i18n::t("form-preco-invalido")));
+                None
+            }
+        }
+    };
+
+    match preco {
+        Some(preco) if erros.is_empty() => Ok(NovoItem {
+            nome: nome.trim().to_string(),
+            preco,
+            ..NovoItem::default()
+        }),
+        _ => Err(erros),
+    }
+}
+
+/// Estoque opcional: vazio fica sem informação, senão um inteiro não negativo.
+pub fn ler_estoque(texto: &str) -> Result<Option<i32>, ErroCampo> {
+    let texto = texto.trim();
+    if texto.is_empty() {
+        return Ok(None);
+    }
+    match texto.parse::<i32>() {
+        Ok(n) if n >= 0 => Ok(Some(n)),
+        _ => Err(ErroCampo::new("estoque", i18n::t("form-estoque-invalido"))),
+    }
+}
+
+fn opcional(texto: &str) -> Option<String> {
+    let texto = texto.trim();
+    (!texto.is_empty()).then(|| texto.to_string())
+}
+
+fn mensagem_do_campo(erros: &[ErroCampo], campo: &str) -> Option<String> {
+    erros
+        .iter()
+        .filter(|e| e.campo == campo)
+        .map(|e| e.mensagem.clone())
+        .reduce(|a, b| format!("{}; {}", a, b))
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ItemFormProps {
+    /// Presente ao editar um item existente.
+    #[prop_or_default]
+    pub id: Option<i32>,
+    /// Valores carregados nos campos; o formulário é reiniciado quando mudam.
+    #[prop_or_default]
+    pub inicial: Option<NovoItem>,
+    /// Sugestões para o campo de categoria.
+    #[prop_or_default]
+    pub categorias: Vec<String>,
+    #[prop_or_default]
+    pub carregando: bool,
+    #[prop_or_default]
+    pub erro: Option<AttrValue>,
+    /// Erros de validação devolvidos pela API.
+    #[prop_or_default]
+    pub erros: Vec<ErroCampo>,
+    pub on_submit: Callback<NovoItem>,
+    pub on_cancel: Callback<()>,
+}
+
+#[function_component(ItemForm)]
+pub fn item_form(props: &ItemFormProps) -> Html {
+    let i18n = use_i18n();
+    let nome = use_state(String::new);
+    let preco = use_state(String::new);
+    let descricao = use_state(String::new);
+    let categoria = use_state(String::new);
+    let sku = use_state(String::new);
+    let estoque = use_state(String::new);
+    let ativo = use_state(|| true);
+    let erros_locais = use_state(Vec::<ErroCampo>::new);
+
+    {
+        let nome = nome.clone();
+        let preco = preco.clone();
+        let descricao = descricao.clone();
+        let categoria = categoria.clone();
+        let sku = sku.clone();
+        let estoque = estoque.clone();
+        let ativo = ativo.clone();
+        let erros_locais = erros_locais.clone();
+        let idioma = i18n.idioma;
+        use_effect_with((props.id, props.inicial.clone()), move |(_, inicial)| {
+            let item = inicial.clone().unwrap_or_default();
+            nome.set(item.nome);
+            preco.set(if inicial.is_some() { item.preco.numero(idioma) } else { String::new() });
+            descricao.set(item.descricao.unwrap_or_default());
+            categoria.set(item.categoria.unwrap_or_default());
+            sku.set(item.sku.unwrap_or_default());
+            estoque.set(item.estoque.map(|n| n.to_string()).unwrap_or_default());
+            ativo.set(item.ativo);
+            erros_locais.set(Vec::new());
+            || ()
+        });
+    }
+
+    let ao_digitar = |estado: &UseStateHandle<String>| {
+        let estado = estado.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            estado.set(input.value());
+        })
+    };
+    let on_change_nome = ao_digitar(&nome);
+    let on_change_categoria = ao_digitar(&categoria);
+    let on_change_sku = ao_digitar(&sku);
+    let on_change_estoque = ao_digitar(&estoque);
+
+    let on_change_descricao = {
+        let descricao = descricao.clone();
+        Callback::from(move |e: InputEvent| {
+            let textarea: HtmlTextAreaElement = e.target_unchecked_into();
+            descricao.set(textarea.value());
+        })
+    };
+
+    let on_change_ativo = {
+        let ativo = ativo.clone();
+        Callback::from(move |_: Event| ativo.set(!*ativo))
+    };
+
+    let on_change_preco = {
+        let preco = preco.clone();
+        let idioma = i18n.idioma;
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            preco.set(dinheiro::mascara(&input.value(), idioma));
+        })
+    };
+
+    // Ao sair do campo, um valor válido é reescrito no formato do idioma, como `1.234,50`.
+    let on_blur_preco = {
+        let preco = preco.clone();
+        let idioma = i18n.idioma;
+        Callback::from(move |_: FocusEvent| {
+            if let Some(valor) = Dinheiro::ler(&preco, idioma) {
+                preco.set(valor.numero(idioma));
+            }
+        })
+    };
+
+    let salvar = {
+        let nome = nome.clone();
+        let preco = preco.clone();
+        let descricao = descricao.clone();
+        let categoria = categoria.clone();
+        let sku = sku.clone();
+        let estoque = estoque.clone();
+        let ativo = ativo.clone();
+        let erros_locais = erros_locais.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |_| match (validar_formulario(&nome, &preco), ler_estoque(&estoque)) {
+            (Ok(item), Ok(estoque)) => {
+                erros_locais.set(Vec::new());
+                on_submit.emit(NovoItem {
+                    descricao: opcional(&descricao),
+                    categoria: opcional(&categoria),
+                    sku: opcional(&sku),
+                    estoque,
+                    ativo: *ativo,
+                    ..item
+                });
+            }
+            (validado, estoque) => {
+                let mut erros = validado.err().unwrap_or_default();
+                erros.extend(estoque.err());
+                erros_locais.set(erros);
+            }
+        })
+    };
+
+    let cancelar = props.on_cancel.reform(|_: MouseEvent| ());
+
+    let is_edit = props.id.is_some();
+    let erros: &[ErroCampo] = if erros_locais.is_empty() { &props.erros } else { &erros_locais };
+    let erro_nome = mensagem_do_campo(erros, "nome");
+    let erro_preco = mensagem_do_campo(erros, "preco");
+    let erro_estoque = mensagem_do_campo(erros, "estoque");
+    let erro_geral = if erros_locais.is_empty() {
+        props.erro.clone()
+    } else {
+        Some(AttrValue::from(i18n.t("form-verifique")))
+    };
+
+    html! {
+        <div class="box">
+            <h1 class="title is-4">
+                { i18n.t(if is_edit { "form-editar" } else { "form-novo" }) }
+            </h1>
+            <p class="subtitle is-6">{ i18n.t("form-subtitulo") }</p>
+
+            if props.carregando {
+                <Notification tipo="is-info">{ i18n.t("carregando") }</Notification>
+            }
+
+            if let Some(msg) = erro_geral {
+                <Notification tipo="is-danger">{ msg }</Notification>
+            }
+
+            if let Some(id) = props.id {
+                <div class="field">
+                    <label class="label">{ i18n.t("coluna-id") }</label>
+                    <div class="control">
+                        <input class="input" type="number" value={id.to_string()} disabled=true />
+                    </div>
+                </div>
+            }
+
+            <div class="field">
+                <label class="label">{ i18n.t("coluna-nome") }</label>
+                <div class="control">
+                    <input class={classes!("input", erro_nome.is_some().then_some("is-danger"))} value={(*nome).clone()} oninput={on_change_nome} placeholder={i18n.t("form-nome-placeholder")} />
+                </div>
+                if let Some(msg) = &erro_nome {
+                    <p class="help is-danger">{ msg }</p>
+                }
+            </div>
+
+            <div class="field">
+                <label class="label">{ i18n.t("coluna-preco") }</label>
+                <div class="control">
+                    <input class={classes!("input", erro_preco.is_some().then_some("is-danger"))} type="text" inputmode="decimal" value={(*preco).clone()} oninput={on_change_preco} onblur={on_blur_preco} placeholder={i18n.t_args("exemplo", &fluent_args!["valor" => Dinheiro::de_centavos(4990).numero(i18n.idioma)])} />
+                </div>
+                if let Some(msg) = &erro_preco {
+                    <p class="help is-danger">{ msg }</p>
+                }
+            </div>
+
+            <div class="field">
+                <label class="label">{ i18n.t("coluna-descricao") }</label>
+                <div class="control">
+                    <textarea class="textarea" rows="3" value={(*descricao).clone()} oninput={on_change_descricao} placeholder={i18n.t("form-descricao-placeholder")} />
+                </div>
+            </div>
+
+            <div class="columns">
+                <div class="column field">
+                    <label class="label">{ i18n.t("coluna-categoria") }</label>
+                    <div class="control">
+                        <input class="input" list="categorias-item" value={(*categoria).clone()} oninput={on_change_categoria} placeholder={i18n.t_args("exemplo", &fluent_args!["valor" => i18n.t("form-categoria-exemplo")])} />
+                        <datalist id="categorias-item">
+                            { for props.categorias.iter().map(|c| html! { <option value={c.clone()} /> }) }
+                        </datalist>
+                    </div>
+                </div>
+                <div class="column field">
+                    <label class="label">{ i18n.t("coluna-sku") }</label>
+                    <div class="control">
+                        <input class="input" value={(*sku).clone()} oninput={on_change_sku} placeholder={i18n.t_args("exemplo", &fluent_args!["valor" => "CAF-001"])} />
+                    </div>
+                </div>
+                <div class="column field">
+                    <label class="label">{ i18n.t("coluna-estoque") }</label>
+                    <div class="control">
+                        <input class={classes!("input", erro_estoque.is_some().then_some("is-danger"))} type="number" min="0" step="1" value={(*estoque).clone()} oninput={on_change_estoque} aria-label={i18n.t("coluna-estoque")} />
+                    </div>
+                    if let Some(msg) = &erro_estoque {
+                        <p class="help is-danger">{ msg }</p>
+                    }
+                </div>
+            </div>
+
+            <div class="field">
+                <label class="checkbox">
+                    <input type="checkbox" class="mr-2" checked={*ativo} onchange={on_change_ativo} />
+                    { i18n.t("form-ativo") }
+                </label>
+            </div>
+
+            <div class="buttons">
+                if is_edit {
+                    <button class="button is-link" onclick={salvar} disabled={props.carregando}>{ i18n.t("form-salvar-alteracoes") }</button>
+                } else {
+                    <button class="button is-primary" onclick={salvar} disabled={props.carregando}>{ i18n.t("salvar") }</button>
+                }
+                <button class="button" onclick={cancelar}>{ i18n.t("cancelar") }</button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formulario_valido_gera_novo_item() {
+        assert_eq!(
+            validar_formulario("  Café  ", "19.90"),
+            Ok(NovoItem {
+                nome: "Café".into(),
+                preco: Dinheiro::de_centavos(1990),
+                ..NovoItem::default()
+            })
+        );
+    }
+
+    #[test]
+    fn estoque_vazio_ou_inteiro_nao_negativo() {
+        assert_eq!(ler_estoque(" "), Ok(None));
+        assert_eq!(ler_estoque("12"), Ok(Some(12)));
+        for texto in ["-1", "1,5", "muito"] {
+            assert_eq!(ler_estoque(texto).unwrap_err().mensagem, "Estoque inválido", "{}", texto);
+        }
+    }
+
+    #[test]
+    fn campos_vazios_geram_um_erro_por_campo() {
+        let erros = validar_formulario("", " ").unwrap_err();
+        let campos: Vec<_> = erros.iter().map(|e| e.campo.as_str()).collect();
+        assert_eq!(campos, vec!["nome", "preco"]);
+    }
+
+    #[test]
+    fn preco_invalido_ou_negativo_e_rejeitado() {
+        for preco in ["abc", "-1", "12,345"] {
+            let erros = validar_formulario("Café", preco).unwrap_err();
+            assert_eq!(mensagem_do_campo(&erros, "preco").as_deref(), Some("Preço inválido"));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use std::collections::BTreeMap;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::models::Item;
+use crate::routes::Route;
+
+/// Colunas que podem ser editadas direto na tabela, na ordem do Tab.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CampoEditavel {
+    Nome,
+    Preco,
+}
+
+impl CampoEditavel {
+    pub const TODOS: [CampoEditavel; 2] = [CampoEditavel::Nome, CampoEditavel::Preco];
+
+    /// Texto inicial do campo ao entrar em edição.
+    pub fn valor(&self, item: &Item) -> String {
+        match self {
+            CampoEditavel::Nome => item.nome.clone(),
+            CampoEditavel::Preco => item.preco.numero(i18n::atual()),
+        }
+    }
+
+    pub fn nome_api(&self) -> &'static str {
+        match self {
+            CampoEditavel::Nome => "nome",
+            CampoEditavel::Preco => "preco",
+        }
+    }
+}
+
+/// Colunas que o usuário pode mostrar além de ID, nome e preço.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColunaOpcional {
+    Descricao,
+    Categoria,
+    Sku,
+    Estoque,
+    Ativo,
+}
+
+impl ColunaOpcional {
+    pub const TODAS: [ColunaOpcional; 5] = [
+        ColunaOpcional::Descricao,
+        ColunaOpcional::Categoria,
+        ColunaOpcional::Sku,
+        ColunaOpcional::Estoque,
+        ColunaOpcional::Ativo,
+    ];
+
+    pub fn nome_api(&self) -> &'static str {
+        match self {
+            ColunaOpcional::Descricao => "descricao",
+            ColunaOpcional::Categoria => "categoria",
+            ColunaOpcional::Sku => "sku",
+            ColunaOpcional::Estoque => "estoque",
+            ColunaOpcional::Ativo => "ativo",
+        }
+    }
+
+    pub fn rotulo(&self) -> String {
+        i18n::t(&format!("coluna-{}", self.nome_api()))
+    }
+
+    /// Conteúdo da célula; `—` quando a API não informa o valor.
+    pub fn texto(&self, item: &Item) -> String {
+        let valor = match self {
+            ColunaOpcional::Descricao => item.descricao.clone(),
+            ColunaOpcional::Categoria => item.categoria.clone(),
+            ColunaOpcional::Sku => item.sku.clone(),
+            ColunaOpcional::Estoque => item.estoque.map(|n| n.to_string()),
+            ColunaOpcional::Ativo => Some(i18n::t(if item.ativo { "sim" } else { "nao" })),
+        };
+        valor.unwrap_or_else(|| "—".to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EstadoCelula {
+    Salvando,
+    Erro(String),
+}
+
+/// Valor digitado em uma célula, emitido ao confirmar com Enter, Tab ou ao sair do campo.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdicaoCelula {
+    pub item: Item,
+    pub campo: CampoEditavel,
+    pub valor: String,
+}
+
+/// Célula seguinte (ou anterior) na ordem de leitura, para navegar com Tab.
+pub fn proxima_celula(itens: &[Item], atual: (i32, CampoEditavel), voltar: bool) -> Option<(i32, CampoEditavel)> {
+    let ordem: Vec<(i32, CampoEditavel)> = itens
+        .iter()
+        .flat_map(|i| CampoEditavel::TODOS.map(|c| (i.id, c)))
+        .collect();
+    let posicao = ordem.iter().position(|&c| c == atual)?;
+    let destino = if voltar { posicao.checked_sub(1)? } else { posicao + 1 };
+    ordem.get(destino).copied()
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ItemTableProps {
+    pub itens: Vec<Item>,
+    /// Colunas opcionais exibidas entre o preço e as ações, na ordem de `ColunaOpcional::TODAS`.
+    #[prop_or_default]
+    pub colunas: Vec<ColunaOpcional>,
+    #[prop_or(AttrValue::from("id"))]
+    pub ordenar_por: AttrValue,
+    #[prop_or(AttrValue::from("asc"))]
+    pub ordem: AttrValue,
+    /// Recebe a coluna clicada no cabeçalho.
+    #[prop_or_default]
+    pub on_sort: Option<Callback<String>>,
+    /// Liga o ID de cada item à página de detalhes.
+    #[prop_or_default]
+    pub links_detalhe: bool,
+    #[prop_or_default]
+    pub on_edit: Option<Callback<Item>>,
+    #[prop_or_default]
+    pub on_delete: Option<Callback<Item>>,
+    /// Ids marcados; só usado quando `on_select` é informado.
+    #[prop_or_default]
+    pub selecionados: Vec<i32>,
+    #[prop_or_default]
+    pub on_select: Option<Callback<Item>>,
+    /// Caixa do cabeçalho, que marca ou desmarca a página inteira.
+    #[prop_or_default]
+    pub on_select_page: Option<Callback<()>>,
+    /// Habilita a edição com duplo clique nas células de nome e preço.
+    #[prop_or_default]
+    pub on_cell_edit: Option<Callback<EdicaoCelula>>,
+    #[prop_or_default]
+    pub estados_celulas: BTreeMap<(i32, CampoEditavel), EstadoCelula>,
+}
+
+#[function_component(ItemTable)]
+pub fn item_table(props: &ItemTableProps) -> Html {
+    let i18n = use_i18n();
+    let editando = use_state(|| None::<(i32, CampoEditavel)>);
+    // Espelho de `editando` lido no blur, que pode chegar depois de um Enter ou Tab.
+    let editando_agora = use_mut_ref(|| None::<(i32, CampoEditavel)>);
+    let input_ref = use_node_ref();
+
+    {
+        let input_ref = input_ref.clone();
+        use_effect_with(*editando, move |_| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let _ = input.focus();
+                input.select();
+            }
+            || ()
+        });
+    }
+
+    if props.itens.is_empty() {
+        return html! {
+            <p class="has-text-grey has-text-centered">{ i18n.t("lista-vazia") }</p>
+        };
+    }
+
+    let seta = |coluna: &str| -> &'static str {
+        if props.ordenar_por == coluna {
+            if props.ordem == "asc" { " ▲" } else { " ▼" }
+        } else {
+            ""
+        }
+    };
+
+    let cabecalho = |coluna: &'static str, alinhamento: Option<&'static str>| {
+        let titulo = i18n.t(&format!("coluna-{}", coluna));
+        match &props.on_sort {
+            Some(on_sort) => html! {
+                <th
+                    class={classes!("is-clickable", alinhamento)}
+                    onclick={on_sort.reform(move |_: MouseEvent| coluna.to_string())}
+                    style="cursor: pointer;"
+                >
+                    { format!("{}{}", titulo, seta(coluna)) }
+                </th>
+            },
+            None => html! { <th class={classes!(alinhamento)}>{ titulo }</th> },
+        }
+    };
+
+    let mudar_edicao = {
+        let editando = editando.clone();
+        let editando_agora = editando_agora.clone();
+        move |destino: Option<(i32, CampoEditavel)>| {
+            *editando_agora.borrow_mut() = destino;
+            editando.set(destino);
+        }
+    };
+
+    let celula = |item: &Item, campo: CampoEditavel| -> Html {
+        let chave = (item.id, campo);
+        let texto = match campo {
+            CampoEditavel::Nome => item.nome.clone(),
+            CampoEditavel::Preco => item.preco.to_string(),
+        };
+        let alinhamento = (campo == CampoEditavel::Preco).then_some("has-text-right");
+        let Some(on_cell_edit) = props.on_cell_edit.as_ref().filter(|_| !item.provisorio()) else {
+            return html! { <td class={classes!(alinhamento)}>{ texto }</td> };
+        };
+
+        let concluir = {
+            let on_cell_edit = on_cell_edit.clone();
+            let item = item.clone();
+            move |valor: String| {
+                if valor.trim() != campo.valor(&item) {
+                    on_cell_edit.emit(EdicaoCelula {
+                        item: item.clone(),
+                        campo,
+                        valor: valor.trim().to_string(),
+                    });
+                }
+            }
+        };
+
+        if *editando == Some(chave) {
+            let onkeydown = {
+                let concluir = concluir.clone();
+                let mudar_edicao = mudar_edicao.clone();
+                let itens = props.itens.clone();
+                Callback::from(move |e: KeyboardEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    match e.key().as_str() {
+                        "Enter" => {
+                            e.prevent_default();
+                            mudar_edicao(None);
+                            concluir(input.value());
+                        }
+                        "Escape" => {
+                            e.prevent_default();
+                            mudar_edicao(None);
+                        }
+                        "Tab" => {
+                            e.prevent_default();
+                            mudar_edicao(proxima_celula(&itens, chave, e.shift_key()));
+                            concluir(input.value());
+                        }
+                        _ => {}
+                    }
+                })
+            };
+            let onblur = {
+                let editando_agora = editando_agora.clone();
+                let mudar_edicao = mudar_edicao.clone();
+                Callback::from(move |e: FocusEvent| {
+                    if *editando_agora.borrow() == Some(chave) {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        mudar_edicao(None);
+                        concluir(input.value());
+                    }
+                })
+            };
+
+            return html! {
+                <td class={classes!(alinhamento)}>
+                    <input
+                        ref={input_ref.clone()}
+                        class={classes!("input", "is-small", alinhamento)}
+                        aria-label={i18n.t(&format!("tabela-editar-{}", campo.nome_api()))}
+                        value={campo.valor(item)}
+                        {onkeydown}
+                        {onblur}
+                    />
+                </td>
+            };
+        }
+
+        let ondblclick = {
+            let mudar_edicao = mudar_edicao.clone();
+            Callback::from(move |_: MouseEvent| mudar_edicao(Some(chave)))
+        };
+        let estado = props.estados_celulas.get(&chave);
+        let erro = match estado {
+            Some(EstadoCelula::Erro(msg)) => Some(msg.clone()),
+            _ => None,
+        };
+
+        html! {
+            <td
+                class={classes!(alinhamento, erro.is_some().then_some("has-background-danger-light"))}
+                title={i18n.t("tabela-dica-edicao")}
+                {ondblclick}
+            >
+                { texto }
+                if estado == Some(&EstadoCelula::Salvando) {
+                    <span class="tag is-info is-light ml-2">{ i18n.t("salvando") }</span>
+                }
+                if let Some(msg) = erro {
+                    <p class="help is-danger">{ msg }</p>
+                }
+            </td>
+        }
+    };
+
+    let colunas: Vec<ColunaOpcional> = ColunaOpcional::TODAS.into_iter().filter(|c| props.colunas.contains(c)).collect();
+    let tem_acoes = props.on_edit.is_some() || props.on_delete.is_some();
+    let pagina_marcada = props.itens.iter().all(|i| props.selecionados.contains(&i.id));
+
+    html! {
+        <div class="table-container">
+            <table class="table is-fullwidth is-striped is-hoverable">
+                <thead>
+                    <tr>
+                        if props.on_select.is_some() {
+                            <th style="width: 2.5rem;">
+                                if let Some(on_select_page) = &props.on_select_page {
+                                    <input
+                                        type="checkbox"
+                                        aria-label={i18n.t("tabela-selecionar-pagina")}
+                                        checked={pagina_marcada}
+                                        onchange={on_select_page.reform(|_: Event| ())}
+                                    />
+                                }
+                            </th>
+                        }
+                        { cabecalho("id", None) }
+                        { cabecalho("nome", None) }
+                        { cabecalho("preco", Some("has-text-right")) }
+                        { for colunas.iter().map(|c| html! {
+                            <th class={classes!((*c == ColunaOpcional::Estoque).then_some("has-text-right"))}>{ c.rotulo() }</th>
+                        })}
+                        if tem_acoes {
+                            <th class="has-text-centered">{ i18n.t("coluna-acoes") }</th>
+                        }
+                    </tr>
+                </thead>
+                <tbody>
+                    { for props.itens.iter().map(|item| {
+                        let on_edit = props.on_edit.as_ref().map(|cb| {
+                            let item = item.clone();
+                            cb.reform(move |_: MouseEvent| item.clone())
+                        });
+                        let on_delete = props.on_delete.as_ref().map(|cb| {
+                            let item = item.clone();
+                            cb.reform(move |_: MouseEvent| item.clone())
+                        });
+
+                        let on_select = props.on_select.as_ref().map(|cb| {
+                            let item = item.clone();
+                            cb.reform(move |_: Event| item.clone())
+                        });
+                        let marcado = props.selecionados.contains(&item.id);
+                        let provisorio = item.provisorio();
+
+                        html! {
+                            <tr key={item.id} class={classes!(marcado.then_some("is-selected"), (!item.ativo).then_some("has-text-grey"))}>
+                                if let Some(on_select) = on_select {
+                                    <td>
+                                        <input
+                                            type="checkbox"
+                                            aria-label={i18n.t_args("tabela-selecionar-item", &fluent_args!["nome" => item.nome.as_str()])}
+                                            checked={marcado}
+                                            disabled={provisorio}
+                                            onchange={on_select}
+                                        />
+                                    </td>
+                                }
+                                if provisorio {
+                                    <td><span class="tag is-info is-light">{ i18n.t("salvando") }</span></td>
+                                } else if props.links_detalhe {
+                                    <td>
+                                        <Link<Route> to={Route::Detalhe { id: item.id }}>
+                                            <span title={i18n.t("tabela-ver-detalhes")}>{ item.id }</span>
+                                        </Link<Route>>
+                                    </td>
+                                } else {
+                                    <td>{ item.id }</td>
+                                }
+                                { celula(item, CampoEditavel::Nome) }
+                                { celula(item, CampoEditavel::Preco) }
+                                { for colunas.iter().map(|c| html! {
+                                    <td class={classes!((*c == ColunaOpcional::Estoque).then_some("has-text-right"))}>{ c.texto(item) }</td>
+                                })}
+                                if tem_acoes {
+                                    <td class="has-text-centered">
+                                        <div class="buttons is-centered">
+                                            if let Some(on_edit) = on_edit {
+                                                <button class="button is-small is-link is-light" disabled={provisorio} onclick={on_edit}>{ "✏️" }</button>
+                                            }
+                                            if let Some(on_delete) = on_delete {
+                                                <button class="button is-small is-danger is-light" disabled={provisorio} onclick={on_delete}>{ "🗑️" }</button>
+                                            }
+                                        </div>
+                                    </td>
+                                }
+                            </tr>
+                        }
+                    })}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dinheiro::Dinheiro;
+
+    fn itens() -> Vec<Item> {
+        (1..=2)
+            .map(|id| Item {
+                id,
+                nome: format!("Item {}", id),
+                preco: Dinheiro::de_centavos(100),
+                ..Item::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tab_percorre_nome_e_preco_linha_a_linha() {
+        let itens = itens();
+
+        assert_eq!(proxima_celula(&itens, (1, CampoEditavel::Nome), false), Some((1, CampoEditavel::Preco)));
+        assert_eq!(proxima_celula(&itens, (1, CampoEditavel::Preco), false), Some((2, CampoEditavel::Nome)));
+        assert_eq!(proxima_celula(&itens, (2, CampoEditavel::Preco), false), None);
+    }
+
+    #[test]
+    fn colunas_opcionais_mostram_traco_sem_valor() {
+        let mut item = itens().remove(0);
+        assert_eq!(ColunaOpcional::Categoria.texto(&item), "—");
+        assert_eq!(ColunaOpcional::Ativo.texto(&item), "Sim");
+
+        item.estoque = Some(0);
+        item.ativo = false;
+        assert_eq!(ColunaOpcional::Estoque.texto(&item), "0");
+        assert_eq!(ColunaOpcional::Ativo.texto(&item), "Não");
+    }
+
+    #[test]
+    fn shift_tab_volta() {
+        let itens = itens();
+
+        assert_eq!(proxima_celula(&itens, (2, CampoEditavel::Nome), true), Some((1, CampoEditavel::Preco)));
+        assert_eq!(proxima_celula(&itens, (1, CampoEditavel::Nome), true), None);
+        assert_eq!(proxima_celula(&itens, (9, CampoEditavel::Nome), false), None);
+    }
+}
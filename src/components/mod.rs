@@ -0,0 +1,37 @@
+mod bulk_action_bar;
+mod category_select;
+mod columns_menu;
+mod confirm_modal;
+mod export_menu;
+mod filter_chips;
+mod filter_panel;
+mod item_form;
+mod item_table;
+mod navbar;
+mod notification;
+mod page_size_select;
+mod pagination;
+mod price_histogram;
+mod price_history_chart;
+mod saved_views_menu;
+mod search_bar;
+mod toast;
+
+pub use bulk_action_bar::{BulkActionBar, BulkActionBarProps};
+pub use category_select::{CategorySelect, CategorySelectProps};
+pub use columns_menu::{ColumnsMenu, ColumnsMenuProps};
+pub use confirm_modal::{ConfirmModal, ConfirmModalProps};
+pub use export_menu::{ExportMenu, ExportMenuProps};
+pub use filter_chips::{FilterChips, FilterChipsProps};
+pub use filter_panel::{FilterPanel, FilterPanelProps};
+pub use item_form::{ItemForm, ItemFormProps, ler_estoque, validar_formulario, validar_item};
+pub use item_table::{CampoEditavel, ColunaOpcional, EdicaoCelula, EstadoCelula, ItemTable, ItemTableProps, proxima_celula};
+pub use navbar::{Navbar, NavbarProps};
+pub use notification::{Notification, NotificationProps};
+pub use page_size_select::{PageSizeSelect, PageSizeSelectProps};
+pub use pagination::{Pagination, PaginationProps, gerar_paginas};
+pub use price_histogram::{PriceHistogram, PriceHistogramProps};
+pub use price_history_chart::{PriceHistoryChart, PriceHistoryChartProps};
+pub use saved_views_menu::{SavedViewsMenu, SavedViewsMenuProps};
+pub use search_bar::{ATRASO_BUSCA_MS, SearchBar, SearchBarProps};
+pub use toast::{Toast, ToastProps};
@@ -0,0 +1,129 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::auth::use_autenticacao;
+use crate::components::ConfirmModal;
+use crate::config::AppConfig;
+use crate::i18n::{Idioma, fluent_args, use_i18n};
+use crate::routes::Route;
+use crate::store::use_items_store;
+
+#[derive(Properties, PartialEq)]
+pub struct NavbarProps {
+    pub config: AppConfig,
+}
+
+#[function_component(Navbar)]
+pub fn navbar(props: &NavbarProps) -> Html {
+    let auth = use_autenticacao();
+    let i18n = use_i18n();
+    let store = use_items_store();
+    let nav_open = use_state(|| false);
+    let confirmando_sair = use_state(|| false);
+
+    let burger_class = if *nav_open { "navbar-burger is-active" } else { "navbar-burger" };
+    let menu_class = if *nav_open { "navbar-menu is-active" } else { "navbar-menu" };
+
+    let toggle_nav = {
+        let nav_open = nav_open.clone();
+        Callback::from(move |_| nav_open.set(!*nav_open))
+    };
+
+    // Alterações ainda na fila não são apagadas, mas quem sai precisa saber que elas não foram enviadas.
+    let on_sair = {
+        let sair = auth.sair.clone();
+        let confirmando_sair = confirmando_sair.clone();
+        let pendentes = store.pendentes;
+        Callback::from(move |_: MouseEvent| {
+            if pendentes > 0 {
+                confirmando_sair.set(true);
+            } else {
+                sair.emit(None);
+            }
+        })
+    };
+
+    let on_confirm_sair = {
+        let sair = auth.sair.clone();
+        let confirmando_sair = confirmando_sair.clone();
+        Callback::from(move |_| {
+            confirmando_sair.set(false);
+            sair.emit(None);
+        })
+    };
+
+    let on_cancel_sair = {
+        let confirmando_sair = confirmando_sair.clone();
+        Callback::from(move |_| confirmando_sair.set(false))
+    };
+
+    let on_idioma = i18n.mudar.reform(|e: Event| {
+        let select: HtmlSelectElement = e.target_unchecked_into();
+        Idioma::de_codigo(&select.value()).unwrap_or_default()
+    });
+
+    html! {
+        <nav class="navbar is-primary" role="navigation" aria-label="main navigation">
+            <div class="navbar-brand">
+                <a class="navbar-item">
+                    <strong>{ i18n.t("app-nome") }</strong>
+                </a>
+                <a
+                    role="button"
+                    class={burger_class}
+                    aria-label="menu"
+                    aria-expanded={(*nav_open).to_string()}
+                    onclick={toggle_nav}
+                >
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                </a>
+            </div>
+            <div class={menu_class}>
+                <div class="navbar-start">
+                    <Link<Route> classes="navbar-item" to={Route::Itens}>
+                        { i18n.t("nav-itens") }
+                    </Link<Route>>
+                    <Link<Route> classes="navbar-item" to={Route::Painel}>
+                        { i18n.t("nav-painel") }
+                    </Link<Route>>
+                </div>
+                <div class="navbar-end">
+                    <div class="navbar-item">
+                        <span class={classes!("tag", props.config.classe_indicador())} title={props.config.api_url.clone()}>
+                            { format!("{} · {}", props.config.perfil, props.config.api_url) }
+                        </span>
+                    </div>
+                    <div class="navbar-item">
+                        <div class="select is-small">
+                            <select aria-label={i18n.t("idioma")} onchange={on_idioma}>
+                                { for Idioma::TODOS.iter().map(|idioma| html! {
+                                    <option value={idioma.codigo()} selected={*idioma == i18n.idioma}>{ idioma.nome() }</option>
+                                })}
+                            </select>
+                        </div>
+                    </div>
+                    if let Some(sessao) = &auth.sessao {
+                        <div class="navbar-item">{ sessao.usuario() }</div>
+                        <a class="navbar-item" onclick={on_sair}>{ i18n.t("nav-sair") }</a>
+                    } else {
+                        <Link<Route> classes="navbar-item" to={Route::Login}>{ i18n.t("nav-entrar") }</Link<Route>>
+                    }
+                </div>
+            </div>
+            if *confirmando_sair {
+                <ConfirmModal
+                    titulo={i18n.t("sair-pendentes-titulo")}
+                    texto_confirmar={i18n.t("nav-sair")}
+                    tipo="is-warning"
+                    on_confirm={on_confirm_sair}
+                    on_cancel={on_cancel_sair}
+                >
+                    <p>{ i18n.t_args("sair-pendentes-pergunta", &fluent_args!["n" => store.pendentes]) }</p>
+                </ConfirmModal>
+            }
+        </nav>
+    }
+}
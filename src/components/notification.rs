@@ -0,0 +1,24 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct NotificationProps {
+    /// Classe de cor do Bulma, como `is-danger` ou `is-info`.
+    #[prop_or(AttrValue::from("is-info"))]
+    pub tipo: AttrValue,
+    #[prop_or_default]
+    pub on_close: Option<Callback<()>>,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(Notification)]
+pub fn notification(props: &NotificationProps) -> Html {
+    html! {
+        <div class={classes!("notification", "is-light", props.tipo.to_string())}>
+            if let Some(on_close) = &props.on_close {
+                <button class="delete" onclick={on_close.reform(|_| ())}></button>
+            }
+            { props.children.clone() }
+        </div>
+    }
+}
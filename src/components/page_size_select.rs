@@ -0,0 +1,41 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::api::OPCOES_POR_PAGINA;
+use crate::i18n::{fluent_args, use_i18n};
+
+#[derive(Properties, PartialEq)]
+pub struct PageSizeSelectProps {
+    pub valor: i64,
+    #[prop_or(OPCOES_POR_PAGINA.to_vec())]
+    pub opcoes: Vec<i64>,
+    pub on_change: Callback<i64>,
+}
+
+#[function_component(PageSizeSelect)]
+pub fn page_size_select(props: &PageSizeSelectProps) -> Html {
+    let i18n = use_i18n();
+    let onchange = {
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(v) = select.value().parse() {
+                on_change.emit(v);
+            }
+        })
+    };
+
+    html! {
+        <div class="field">
+            <div class="control">
+                <div class="select is-fullwidth">
+                    <select {onchange}>
+                        { for props.opcoes.iter().map(|&n| html! {
+                            <option value={n.to_string()} selected={props.valor == n}>{ i18n.t_args("por-pagina", &fluent_args!["n" => n]) }</option>
+                        })}
+                    </select>
+                </div>
+            </div>
+        </div>
+    }
+}
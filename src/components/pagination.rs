@@ -0,0 +1,124 @@
+use yew::prelude::*;
+
+/// Números de página a exibir; `-1` marca uma reticência.
+pub fn gerar_paginas(atual: i64, total: i64) -> Vec<i64> {
+    let mut paginas = Vec::new();
+
+    if total <= 7 {
+        for i in 1..=total {
+            paginas.push(i);
+        }
+    } else {
+        paginas.push(1);
+
+        if atual > 3 {
+            paginas.push(-1);
+        }
+
+        let inicio = (atual - 1).max(2);
+        let fim = (atual + 1).min(total - 1);
+
+        for i in inicio..=fim {
+            if !paginas.contains(&i) {
+                paginas.push(i);
+            }
+        }
+
+        if atual < total - 2 {
+            paginas.push(-1);
+        }
+
+        if !paginas.contains(&total) {
+            paginas.push(total);
+        }
+    }
+
+    paginas
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PaginationProps {
+    pub pagina_atual: i64,
+    pub total_paginas: i64,
+    pub on_change: Callback<i64>,
+}
+
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProps) -> Html {
+    let pagina_atual = props.pagina_atual;
+    let total_pags = props.total_paginas;
+
+    if total_pags <= 1 {
+        return html! {};
+    }
+
+    let ir_para_pagina = |p: i64| props.on_change.reform(move |_: MouseEvent| p);
+    let paginas = gerar_paginas(pagina_atual, total_pags);
+
+    html! {
+        <nav class="pagination is-centered" role="navigation" aria-label="pagination">
+            <a
+                class={if pagina_atual <= 1 { "pagination-previous is-disabled" } else { "pagination-previous" }}
+                onclick={ir_para_pagina((pagina_atual - 1).max(1))}
+                disabled={pagina_atual <= 1}
+            >
+                { "<" }
+            </a>
+            <a
+                class={if pagina_atual >= total_pags { "pagination-next is-disabled" } else { "pagination-next" }}
+                onclick={ir_para_pagina((pagina_atual + 1).min(total_pags))}
+                disabled={pagina_atual >= total_pags}
+            >
+                { ">" }
+            </a>
+            <ul class="pagination-list">
+                { for paginas.iter().map(|&p| {
+                    if p == -1 {
+                        html! {
+                            <li>
+                                <span class="pagination-ellipsis">{ "…" }</span>
+                            </li>
+                        }
+                    } else {
+                        let is_current = p == pagina_atual;
+                        let class = if is_current { "pagination-link is-current" } else { "pagination-link" };
+                        html! {
+                            <li>
+                                <a class={class} onclick={ir_para_pagina(p)}>{ p }</a>
+                            </li>
+                        }
+                    }
+                })}
+            </ul>
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lista_todas_as_paginas_ate_sete() {
+        assert_eq!(gerar_paginas(1, 0), Vec::<i64>::new());
+        assert_eq!(gerar_paginas(1, 1), vec![1]);
+        assert_eq!(gerar_paginas(4, 7), vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn usa_reticencias_no_fim_quando_esta_no_inicio() {
+        assert_eq!(gerar_paginas(1, 10), vec![1, 2, -1, 10]);
+        assert_eq!(gerar_paginas(3, 10), vec![1, 2, 3, 4, -1, 10]);
+    }
+
+    #[test]
+    fn usa_reticencias_dos_dois_lados_no_meio() {
+        assert_eq!(gerar_paginas(5, 10), vec![1, -1, 4, 5, 6, -1, 10]);
+    }
+
+    #[test]
+    fn usa_reticencias_no_inicio_quando_esta_no_fim() {
+        assert_eq!(gerar_paginas(8, 10), vec![1, -1, 7, 8, 9, 10]);
+        assert_eq!(gerar_paginas(10, 10), vec![1, -1, 9, 10]);
+    }
+}
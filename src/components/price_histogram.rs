@@ -0,0 +1,69 @@
+use yew::prelude::*;
+
+use crate::estatisticas::Faixa;
+use crate::graficos::{self, Area};
+use crate::i18n::{fluent_args, use_i18n};
+
+const AREA: Area = Area {
+    largura: 320.0,
+    altura: 140.0,
+    margem: 16.0,
+};
+
+#[derive(Properties, PartialEq)]
+pub struct PriceHistogramProps {
+    /// Faixas de preço em ordem crescente, como as de `estatisticas::histograma`.
+    pub faixas: Vec<Faixa>,
+}
+
+/// Quantidade de itens em cada faixa de preço, em barras.
+#[function_component(PriceHistogram)]
+pub fn price_histogram(props: &PriceHistogramProps) -> Html {
+    let i18n = use_i18n();
+    let quantidades: Vec<f64> = props.faixas.iter().map(|f| f.quantidade as f64).collect();
+    let barras = graficos::barras(&quantidades, 4.0, AREA);
+    let base = AREA.altura - AREA.margem;
+
+    html! {
+        <svg
+            class="grafico-histograma"
+            role="img"
+            aria-label={i18n.t("painel-histograma")}
+            viewBox={format!("0 0 {} {}", AREA.largura, AREA.altura)}
+            width="100%"
+            style="max-width: 40rem; height: auto;"
+        >
+            <line x1={AREA.margem.to_string()} x2={(AREA.largura - AREA.margem).to_string()} y1={base.to_string()} y2={base.to_string()} stroke="hsl(0, 0%, 71%)" />
+            { for props.faixas.iter().zip(&barras).map(|(f, b)| {
+                let rotulo = i18n.t_args(
+                    "painel-faixa",
+                    &fluent_args!["de" => f.de.to_string(), "ate" => f.ate.to_string(), "n" => f.quantidade],
+                );
+                html! {
+                    <g>
+                        <rect
+                            x={format!("{:.1}", b.x)}
+                            y={format!("{:.1}", b.y)}
+                            width={format!("{:.1}", b.largura)}
+                            height={format!("{:.1}", b.altura)}
+                            fill="hsl(171, 100%, 41%)"
+                        >
+                            <title>{ rotulo }</title>
+                        </rect>
+                        if f.quantidade > 0 {
+                            <text x={format!("{:.1}", b.x + b.largura / 2.0)} y={format!("{:.1}", b.y - 3.0)} font-size="9" text-anchor="middle">
+                                { f.quantidade }
+                            </text>
+                        }
+                    </g>
+                }
+            })}
+            if let (Some(primeira), Some(ultima)) = (props.faixas.first(), props.faixas.last()) {
+                <text x={AREA.margem.to_string()} y={(AREA.altura - 4.0).to_string()} font-size="9">{ primeira.de.to_string() }</text>
+                <text x={(AREA.largura - AREA.margem).to_string()} y={(AREA.altura - 4.0).to_string()} font-size="9" text-anchor="end">
+                    { ultima.ate.to_string() }
+                </text>
+            }
+        </svg>
+    }
+}
@@ -0,0 +1,60 @@
+use yew::prelude::*;
+
+use crate::graficos::{self, Area};
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::models::AlteracaoPreco;
+
+const AREA: Area = Area {
+    largura: 320.0,
+    altura: 80.0,
+    margem: 8.0,
+};
+
+#[derive(Properties, PartialEq)]
+pub struct PriceHistoryChartProps {
+    /// Alterações do item, da mais antiga à atual.
+    pub historico: Vec<AlteracaoPreco>,
+}
+
+/// Linha do preço ao longo do tempo, com um ponto em cada alteração.
+#[function_component(PriceHistoryChart)]
+pub fn price_history_chart(props: &PriceHistoryChartProps) -> Html {
+    let i18n = use_i18n();
+
+    // Datas que o navegador não entende ficam igualmente espaçadas, pela posição na lista.
+    let valores: Vec<(f64, f64)> = props
+        .historico
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            let data = js_sys::Date::parse(&a.em);
+            let x = if data.is_nan() { i as f64 } else { data };
+            (x, a.preco.centavos() as f64)
+        })
+        .collect();
+    let pontos = graficos::posicionar(&valores, AREA);
+
+    html! {
+        <svg
+            class="grafico-historico"
+            role="img"
+            aria-label={i18n.t("detalhe-grafico")}
+            viewBox={format!("0 0 {} {}", AREA.largura, AREA.altura)}
+            width="100%"
+            style="max-width: 40rem; height: auto;"
+        >
+            <polyline points={graficos::polilinha(&pontos)} fill="none" stroke="hsl(217, 71%, 53%)" stroke-width="2" />
+            { for props.historico.iter().zip(&pontos).map(|(a, (x, y))| {
+                let rotulo = i18n.t_args(
+                    "detalhe-ponto",
+                    &fluent_args!["data" => i18n::data_hora(&a.em, i18n.idioma), "preco" => a.preco.to_string()],
+                );
+                html! {
+                    <circle cx={format!("{:.1}", x)} cy={format!("{:.1}", y)} r="3.5" fill="hsl(217, 71%, 53%)">
+                        <title>{ rotulo }</title>
+                    </circle>
+                }
+            })}
+        </svg>
+    }
+}
@@ -0,0 +1,147 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::ListarParams;
+use crate::i18n::{fluent_args, use_i18n};
+use crate::visoes::VisaoSalva;
+
+#[derive(Properties, PartialEq)]
+pub struct SavedViewsMenuProps {
+    pub visoes: Vec<VisaoSalva>,
+    /// Consulta da lista, para marcar a visão aplicada.
+    pub atual: ListarParams,
+    pub on_apply: Callback<ListarParams>,
+    /// Recebe o nome sob o qual a consulta atual deve ser salva.
+    pub on_save: Callback<String>,
+    pub on_remove: Callback<String>,
+    pub on_export: Callback<()>,
+    pub on_import: Callback<web_sys::File>,
+}
+
+#[function_component(SavedViewsMenu)]
+pub fn saved_views_menu(props: &SavedViewsMenuProps) -> Html {
+    let i18n = use_i18n();
+    let aberto = use_state(|| false);
+    let nome = use_state(String::new);
+
+    let alternar = {
+        let aberto = aberto.clone();
+        Callback::from(move |_| aberto.set(!*aberto))
+    };
+
+    let on_nome = {
+        let nome = nome.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            nome.set(input.value());
+        })
+    };
+
+    let on_save = {
+        let nome = nome.clone();
+        let on_save = props.on_save.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if !nome.trim().is_empty() {
+                on_save.emit(nome.trim().to_string());
+                nome.set(String::new());
+            }
+        })
+    };
+
+    let on_export = {
+        let aberto = aberto.clone();
+        let on_export = props.on_export.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            aberto.set(false);
+            on_export.emit(());
+        })
+    };
+
+    let on_import = {
+        let aberto = aberto.clone();
+        let on_import = props.on_import.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Some(arquivo) = input.files().and_then(|f| f.get(0)) {
+                aberto.set(false);
+                on_import.emit(arquivo);
+            }
+            input.set_value("");
+        })
+    };
+
+    let aplicada = props.visoes.iter().find(|v| v.aplicada_em(&props.atual));
+
+    html! {
+        <div class={classes!("dropdown", "is-right", aberto.then_some("is-active"))}>
+            <div class="dropdown-trigger">
+                <button class="button" aria-haspopup="true" onclick={alternar}>
+                    {
+                        aplicada
+                            .map(|v| i18n.t_args("visoes-aplicada", &fluent_args!["nome" => v.nome.as_str()]))
+                            .unwrap_or_else(|| i18n.t("visoes-botao"))
+                    }
+                </button>
+            </div>
+            <div class="dropdown-menu" role="menu" style="min-width: 18rem;">
+                <div class="dropdown-content">
+                    if props.visoes.is_empty() {
+                        <p class="dropdown-item has-text-grey">{ i18n.t("visoes-nenhuma") }</p>
+                    }
+                    { for props.visoes.iter().map(|visao| {
+                        let ativa = visao.aplicada_em(&props.atual);
+                        let aplicar = {
+                            let aberto = aberto.clone();
+                            let consulta = visao.consulta.clone();
+                            props.on_apply.reform(move |e: MouseEvent| {
+                                e.prevent_default();
+                                aberto.set(false);
+                                consulta.clone()
+                            })
+                        };
+                        let remover = {
+                            let nome = visao.nome.clone();
+                            props.on_remove.reform(move |_: MouseEvent| nome.clone())
+                        };
+                        html! {
+                            <div class="dropdown-item is-flex is-align-items-center" key={visao.nome.clone()}>
+                                <a class={classes!("is-flex-grow-1", ativa.then_some("has-text-weight-semibold"))} href="#" onclick={aplicar}>
+                                    { if ativa { format!("✓ {}", visao.nome) } else { visao.nome.clone() } }
+                                </a>
+                                <button class="delete is-small ml-2" aria-label={i18n.t_args("visoes-remover", &fluent_args!["nome" => visao.nome.as_str()])} onclick={remover}></button>
+                            </div>
+                        }
+                    })}
+                    <hr class="dropdown-divider" />
+                    <form class="dropdown-item" onsubmit={on_save}>
+                        <div class="field has-addons">
+                            <div class="control is-expanded">
+                                <input
+                                    class="input is-small"
+                                    type="text"
+                                    placeholder={i18n.t("visoes-nome")}
+                                    aria-label={i18n.t("visoes-nome")}
+                                    value={(*nome).clone()}
+                                    oninput={on_nome}
+                                />
+                            </div>
+                            <div class="control">
+                                <button type="submit" class="button is-small is-info" disabled={nome.trim().is_empty()}>
+                                    { i18n.t("visoes-salvar") }
+                                </button>
+                            </div>
+                        </div>
+                    </form>
+                    <hr class="dropdown-divider" />
+                    <a class="dropdown-item" href="#" onclick={on_export}>{ i18n.t("visoes-exportar") }</a>
+                    <label class="dropdown-item is-clickable">
+                        { i18n.t("visoes-importar") }
+                        <input class="is-hidden" type="file" accept=".json,application/json" aria-label={i18n.t("visoes-importar-rotulo")} onchange={on_import} />
+                    </label>
+                </div>
+            </div>
+        </div>
+    }
+}
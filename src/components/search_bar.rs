@@ -0,0 +1,106 @@
+use gloo_timers::callback::Timeout;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::i18n::use_i18n;
+
+/// Espera padrão depois da última tecla antes de buscar.
+pub const ATRASO_BUSCA_MS: u32 = 300;
+
+#[derive(Properties, PartialEq)]
+pub struct SearchBarProps {
+    /// Busca aplicada no momento; o campo é ressincronizado quando ela muda.
+    #[prop_or_default]
+    pub valor: AttrValue,
+    /// Padrão: "Buscar por ID, nome, descrição ou SKU...", no idioma atual.
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    /// Busca enquanto o usuário digita, depois desta pausa. `0` desliga a busca automática.
+    #[prop_or(ATRASO_BUSCA_MS)]
+    pub atraso_ms: u32,
+    pub on_search: Callback<String>,
+}
+
+#[function_component(SearchBar)]
+pub fn search_bar(props: &SearchBarProps) -> Html {
+    let i18n = use_i18n();
+    let texto = use_state(|| props.valor.to_string());
+    let pendente = use_mut_ref(|| None::<Timeout>);
+    let aplicado = use_mut_ref(|| props.valor.to_string());
+
+    {
+        let texto = texto.clone();
+        let aplicado = aplicado.clone();
+        use_effect_with(props.valor.clone(), move |valor| {
+            texto.set(valor.to_string());
+            *aplicado.borrow_mut() = valor.to_string();
+            || ()
+        });
+    }
+
+    let buscar = {
+        let pendente = pendente.clone();
+        let on_search = props.on_search.clone();
+        Callback::from(move |busca: String| {
+            pendente.borrow_mut().take();
+            on_search.emit(busca);
+        })
+    };
+
+    let oninput = {
+        let texto = texto.clone();
+        let pendente = pendente.clone();
+        let on_search = props.on_search.clone();
+        let atraso_ms = props.atraso_ms;
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let valor = input.value();
+            texto.set(valor.clone());
+
+            if atraso_ms == 0 {
+                return;
+            }
+            let on_search = on_search.clone();
+            let aplicado = aplicado.clone();
+            *pendente.borrow_mut() = Some(Timeout::new(atraso_ms, move || {
+                if *aplicado.borrow() != valor {
+                    on_search.emit(valor);
+                }
+            }));
+        })
+    };
+
+    let onkeydown = {
+        let texto = texto.clone();
+        let buscar = buscar.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                e.prevent_default();
+                buscar.emit((*texto).clone());
+            }
+        })
+    };
+
+    let on_buscar = {
+        let texto = texto.clone();
+        Callback::from(move |_| buscar.emit((*texto).clone()))
+    };
+
+    html! {
+        <div class="field has-addons">
+            <div class="control is-expanded">
+                <input
+                    class="input"
+                    type="text"
+                    placeholder={props.placeholder.clone().unwrap_or_else(|| i18n.t("busca-placeholder").into())}
+                    value={(*texto).clone()}
+                    {oninput}
+                    {onkeydown}
+                />
+            </div>
+            <div class="control">
+                <button class="button is-info" onclick={on_buscar}>{ i18n.t("busca-botao") }</button>
+            </div>
+        </div>
+    }
+}
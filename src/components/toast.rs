@@ -0,0 +1,47 @@
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ToastProps {
+    #[prop_or(AttrValue::from("is-dark"))]
+    pub tipo: AttrValue,
+    /// Tempo em tela antes de `on_close` ser chamado.
+    #[prop_or(5000)]
+    pub duracao_ms: u32,
+    /// Alterar a chave reinicia a contagem, para reaproveitar o toast com outro conteúdo.
+    #[prop_or_default]
+    pub chave: u32,
+    #[prop_or_default]
+    pub acao: Option<AttrValue>,
+    #[prop_or_default]
+    pub on_action: Option<Callback<()>>,
+    pub on_close: Callback<()>,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(Toast)]
+pub fn toast(props: &ToastProps) -> Html {
+    {
+        let on_close = props.on_close.clone();
+        use_effect_with((props.chave, props.duracao_ms), move |(_, duracao_ms)| {
+            let timeout = Timeout::new(*duracao_ms, move || on_close.emit(()));
+            move || drop(timeout)
+        });
+    }
+
+    html! {
+        <div
+            class={classes!("notification", props.tipo.to_string())}
+            style="position: fixed; right: 1.5rem; bottom: 1.5rem; z-index: 50; max-width: 26rem;"
+        >
+            <button class="delete" onclick={props.on_close.reform(|_| ())}></button>
+            <div class="is-flex is-align-items-center">
+                <span class="mr-4">{ props.children.clone() }</span>
+                if let (Some(acao), Some(on_action)) = (&props.acao, &props.on_action) {
+                    <button class="button is-small is-light" onclick={on_action.reform(|_| ())}>{ acao.clone() }</button>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+
+use gloo_net::http::Request;
+use web_sys::RequestCache;
+
+use crate::dinheiro::Moeda;
+
+pub const PERFIL_PADRAO: &str = "dev";
+pub const API_URL_PADRAO: &str = "http://localhost:3000";
+/// Absoluto: um caminho relativo seria resolvido contra a rota atual, como `/itens/5/config.json`.
+const ARQUIVO_CONFIG: &str = "/config.json";
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct Perfil {
+    pub api_url: String,
+}
+
+/// Conteúdo do `config.json` servido ao lado do `index.html`.
+#[derive(Clone, Debug, PartialEq, Default, serde::Deserialize)]
+pub struct ArquivoConfig {
+    #[serde(default)]
+    pub perfil: Option<String>,
+    #[serde(default)]
+    pub api_url: Option<String>,
+    #[serde(default)]
+    pub perfis: BTreeMap<String, Perfil>,
+    /// Moeda dos preços devolvidos pela API, como `"BRL"` ou `"USD"`.
+    #[serde(default)]
+    pub moeda: Option<Moeda>,
+}
+
+/// Valores brutos lidos de cada origem de configuração.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FontesConfig {
+    pub arquivo: Option<ArquivoConfig>,
+    pub meta_perfil: Option<String>,
+    pub meta_api_url: Option<String>,
+    pub env_perfil: Option<String>,
+    pub env_api_url: Option<String>,
+}
+
+/// Configuração efetiva da aplicação: perfil ativo, URL base e moeda da API.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppConfig {
+    pub perfil: String,
+    pub api_url: String,
+    pub moeda: Moeda,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            perfil: PERFIL_PADRAO.to_string(),
+            api_url: API_URL_PADRAO.to_string(),
+            moeda: Moeda::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Precedência (da maior para a menor): `config.json`, tags `<meta>`,
+    /// variáveis de ambiente do build e, por fim, o perfil `dev`.
+    ///
+    /// Uma `api_url` explícita, de qualquer origem, vence a URL do perfil: o `API_URL`
+    /// do build sobrepõe o `perfil` escolhido no `config.json`.
+    pub fn resolver(fontes: &FontesConfig) -> Self {
+        let arquivo = fontes.arquivo.clone().unwrap_or_default();
+
+        let perfil = preenchido(&arquivo.perfil)
+            .or_else(|| preenchido(&fontes.meta_perfil))
+            .or_else(|| preenchido(&fontes.env_perfil))
+            .unwrap_or_else(|| PERFIL_PADRAO.to_string());
+
+        let url_do_perfil = arquivo.perfis.get(&perfil).map(|p| p.api_url.clone()).or_else(|| {
+            (perfil == PERFIL_PADRAO).then(|| API_URL_PADRAO.to_string())
+        });
+
+        let api_url = preenchido(&arquivo.api_url)
+            .or_else(|| preenchido(&fontes.meta_api_url))
+            .or_else(|| preenchido(&fontes.env_api_url))
+            .or(url_do_perfil)
+            .unwrap_or_else(|| API_URL_PADRAO.to_string());
+
+        Self {
+            perfil,
+            api_url,
+            moeda: arquivo.moeda.unwrap_or_default(),
+        }
+    }
+
+    pub async fn carregar() -> Self {
+        let fontes = FontesConfig {
+            arquivo: carregar_arquivo().await,
+            meta_perfil: ler_meta("app-perfil"),
+            meta_api_url: ler_meta("api-url"),
+            env_perfil: option_env!("APP_PERFIL").map(str::to_string),
+            env_api_url: option_env!("API_URL").map(str::to_string),
+        };
+        Self::resolver(&fontes)
+    }
+
+    pub fn classe_indicador(&self) -> &'static str {
+        match self.perfil.as_str() {
+            "prod" => "is-danger",
+            "staging" => "is-warning",
+            _ => "is-light",
+        }
+    }
+}
+
+/// O valor de uma origem, se houver e não estiver em branco; em branco, a próxima origem decide.
+fn preenchido(valor: &Option<String>) -> Option<String> {
+    valor.clone().filter(|v| !v.trim().is_empty())
+}
+
+async fn carregar_arquivo() -> Option<ArquivoConfig> {
+    let resp = Request::get(ARQUIVO_CONFIG)
+        .cache(RequestCache::NoCache)
+        .send()
+        .await
+        .ok()?;
+    if !resp.ok() {
+        return None;
+    }
+    resp.json::<ArquivoConfig>().await.ok()
+}
+
+fn ler_meta(nome: &str) -> Option<String> {
+    web_sys::window()?
+        .document()?
+        .query_selector(&format!("meta[name=\"{}\"]", nome))
+        .ok()??
+        .get_attribute("content")
+        .filter(|v| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arquivo(json: &str) -> Option<ArquivoConfig> {
+        Some(serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn sem_fontes_usa_o_perfil_dev() {
+        assert_eq!(AppConfig::resolver(&FontesConfig::default()), AppConfig::default());
+    }
+
+    #[test]
+    fn api_url_do_build_vence_a_url_do_perfil_do_arquivo() {
+        let fontes = FontesConfig {
+            arquivo: arquivo(r#"{"perfil": "prod", "perfis": {"prod": {"api_url": "https://api.example.com"}}}"#),
+            env_api_url: Some("http://build:3000".into()),
+            ..FontesConfig::default()
+        };
+
+        let config = AppConfig::resolver(&fontes);
+        assert_eq!(config.perfil, "prod");
+        assert_eq!(config.api_url, "http://build:3000");
+    }
+
+    #[test]
+    fn meta_tem_precedencia_sobre_o_build() {
+        let fontes = FontesConfig {
+            arquivo: arquivo(r#"{"perfis": {"staging": {"api_url": "https://staging.example.com"}}}"#),
+            meta_perfil: Some("staging".into()),
+            env_perfil: Some("prod".into()),
+            ..FontesConfig::default()
+        };
+
+        let config = AppConfig::resolver(&fontes);
+        assert_eq!(config.perfil, "staging");
+        assert_eq!(config.api_url, "https://staging.example.com");
+    }
+
+    #[test]
+    fn api_url_do_arquivo_vence_todas_as_outras() {
+        let fontes = FontesConfig {
+            arquivo: arquivo(r#"{"api_url": "https://fixo.example.com"}"#),
+            meta_api_url: Some("https://meta.example.com".into()),
+            env_api_url: Some("https://env.example.com".into()),
+            ..FontesConfig::default()
+        };
+
+        assert_eq!(AppConfig::resolver(&fontes).api_url, "https://fixo.example.com");
+    }
+
+    #[test]
+    fn moeda_vem_do_arquivo_ou_e_o_real() {
+        let fontes = FontesConfig {
+            arquivo: arquivo(r#"{"moeda": "USD"}"#),
+            ..FontesConfig::default()
+        };
+
+        assert_eq!(AppConfig::resolver(&fontes).moeda, Moeda::Usd);
+        assert_eq!(AppConfig::resolver(&FontesConfig::default()).moeda, Moeda::Brl);
+    }
+
+    #[test]
+    fn valores_vazios_sao_ignorados() {
+        let fontes = FontesConfig {
+            meta_perfil: Some("  ".into()),
+            meta_api_url: Some(String::new()),
+            ..FontesConfig::default()
+        };
+
+        assert_eq!(AppConfig::resolver(&fontes), AppConfig::default());
+    }
+
+    #[test]
+    fn valor_vazio_nao_esconde_a_proxima_origem() {
+        let fontes = FontesConfig {
+            arquivo: arquivo(r#"{"perfil": "", "api_url": " ", "perfis": {"staging": {"api_url": "https://staging.example.com"}}}"#),
+            meta_perfil: Some("staging".into()),
+            env_api_url: Some("http://build:3000".into()),
+            ..FontesConfig::default()
+        };
+
+        let config = AppConfig::resolver(&fontes);
+        assert_eq!(config.perfil, "staging");
+        assert_eq!(config.api_url, "http://build:3000");
+
+        let fontes = FontesConfig {
+            meta_api_url: Some(String::new()),
+            ..fontes
+        };
+        assert_eq!(AppConfig::resolver(&fontes).api_url, "http://build:3000");
+    }
+}
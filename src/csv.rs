@@ -0,0 +1,179 @@
+/// Delimitadores reconhecidos, na ordem de preferência em caso de empate.
+pub const DELIMITADORES: [char; 4] = [';', ',', '\t', '|'];
+
+/// Conteúdo de um arquivo CSV já separado em linhas e células.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Planilha {
+    pub delimitador: char,
+    /// Cada linha com o número dela no arquivo (a partir de 1).
+    pub linhas: Vec<(usize, Vec<String>)>,
+}
+
+/// Decodifica o arquivo como UTF-8 ou, se inválido, como Latin-1 (padrão do Excel em pt-BR).
+pub fn decodificar(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    match std::str::from_utf8(bytes) {
+        Ok(texto) => texto.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+/// Escolhe o delimitador mais frequente na primeira linha, fora de aspas.
+pub fn detectar_delimitador(texto: &str) -> char {
+    let primeira = primeira_linha(texto);
+    DELIMITADORES
+        .iter()
+        .copied()
+        .map(|d| (d, contar_fora_de_aspas(primeira, d)))
+        .filter(|&(_, n)| n > 0)
+        .fold(None, |melhor: Option<(char, usize)>, atual| match melhor {
+            Some(m) if m.1 >= atual.1 => Some(m),
+            _ => Some(atual),
+        })
+        .map(|(d, _)| d)
+        .unwrap_or(',')
+}
+
+pub fn ler(texto: &str) -> Planilha {
+    let texto = texto.strip_prefix('\u{feff}').unwrap_or(texto);
+    let delimitador = detectar_delimitador(texto);
+    Planilha {
+        delimitador,
+        linhas: ler_com(texto, delimitador),
+    }
+}
+
+/// Lê células com aspas duplas (`""` escapa uma aspa) e quebras de linha dentro delas.
+/// Linhas em branco são ignoradas.
+pub fn ler_com(texto: &str, delimitador: char) -> Vec<(usize, Vec<String>)> {
+    let mut linhas = Vec::new();
+    let mut celulas = Vec::new();
+    let mut celula = String::new();
+    let mut entre_aspas = false;
+    let mut numero = 1;
+    let mut inicio = 1;
+    let mut chars = texto.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if entre_aspas && chars.peek() == Some(&'"') => {
+                chars.next();
+                celula.push('"');
+            }
+            '"' if entre_aspas => entre_aspas = false,
+            '"' if celula.trim().is_empty() => {
+                celula.clear();
+                entre_aspas = true;
+            }
+            '\n' if entre_aspas => {
+                numero += 1;
+                celula.push('\n');
+            }
+            '\r' if !entre_aspas => {}
+            '\n' => {
+                celulas.push(std::mem::take(&mut celula));
+                empurrar(&mut linhas, inicio, std::mem::take(&mut celulas));
+                numero += 1;
+                inicio = numero;
+            }
+            c if c == delimitador && !entre_aspas => celulas.push(std::mem::take(&mut celula)),
+            c => celula.push(c),
+        }
+    }
+    celulas.push(celula);
+    empurrar(&mut linhas, inicio, celulas);
+    linhas
+}
+
+/// Monta uma linha de CSV, com aspas onde o conteúdo exigir.
+pub fn escrever_linha<S: AsRef<str>>(celulas: &[S], delimitador: char) -> String {
+    celulas
+        .iter()
+        .map(|c| {
+            let c = c.as_ref();
+            if c.contains(delimitador) || c.contains('"') || c.contains('\n') || c.contains('\r') {
+                format!("\"{}\"", c.replace('"', "\"\""))
+            } else {
+                c.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(&delimitador.to_string())
+}
+
+fn empurrar(linhas: &mut Vec<(usize, Vec<String>)>, numero: usize, celulas: Vec<String>) {
+    let celulas: Vec<String> = celulas.into_iter().map(|c| c.trim().to_string()).collect();
+    if celulas.iter().any(|c| !c.is_empty()) {
+        linhas.push((numero, celulas));
+    }
+}
+
+fn primeira_linha(texto: &str) -> &str {
+    texto.lines().find(|l| !l.trim().is_empty()).unwrap_or("")
+}
+
+fn contar_fora_de_aspas(linha: &str, delimitador: char) -> usize {
+    let mut entre_aspas = false;
+    linha
+        .chars()
+        .filter(|&c| {
+            if c == '"' {
+                entre_aspas = !entre_aspas;
+            }
+            c == delimitador && !entre_aspas
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn celulas(planilha: &Planilha) -> Vec<Vec<&str>> {
+        planilha
+            .linhas
+            .iter()
+            .map(|(_, l)| l.iter().map(String::as_str).collect())
+            .collect()
+    }
+
+    #[test]
+    fn detecta_ponto_e_virgula_do_excel_em_pt_br() {
+        let planilha = ler("nome;preço\r\nCafé;19,90\r\n\r\nChá;9,5\r\n");
+
+        assert_eq!(planilha.delimitador, ';');
+        assert_eq!(celulas(&planilha), vec![vec!["nome", "preço"], vec!["Café", "19,90"], vec!["Chá", "9,5"]]);
+        assert_eq!(planilha.linhas[2].0, 4);
+    }
+
+    #[test]
+    fn aspas_protegem_delimitador_e_quebra_de_linha() {
+        let planilha = ler("nome,preco\n\"Queijo, minas\",27.8\n\"Pão \"\"caseiro\"\"\nintegral\",8.99\n");
+
+        assert_eq!(planilha.delimitador, ',');
+        assert_eq!(celulas(&planilha)[1], vec!["Queijo, minas", "27.8"]);
+        assert_eq!(celulas(&planilha)[2], vec!["Pão \"caseiro\"\nintegral", "8.99"]);
+    }
+
+    #[test]
+    fn tabulacao_e_bom() {
+        let planilha = ler("\u{feff}nome\tpreco\nMel\t30");
+        assert_eq!(planilha.delimitador, '\t');
+        assert_eq!(celulas(&planilha)[0], vec!["nome", "preco"]);
+    }
+
+    #[test]
+    fn latin1_quando_nao_e_utf8() {
+        assert_eq!(decodificar(b"Caf\xe9;1"), "Café;1");
+        assert_eq!(decodificar("Café".as_bytes()), "Café");
+    }
+
+    #[test]
+    fn escrever_linha_usa_aspas_so_quando_precisa() {
+        assert_eq!(escrever_linha(&["Café", "19,90"], ';'), "Café;19,90");
+        assert_eq!(
+            escrever_linha(&["Queijo; minas", "Pão \"caseiro\""], ';'),
+            "\"Queijo; minas\";\"Pão \"\"caseiro\"\"\""
+        );
+    }
+}
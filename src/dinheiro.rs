@@ -0,0 +1,359 @@
+//! Valores monetários em centavos: formatação e leitura conforme o idioma e a moeda configurada.
+
+use std::cell::Cell;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Neg, Sub};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::i18n::{self, Idioma};
+
+/// Moeda dos preços da API, definida no `config.json`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Moeda {
+    #[default]
+    Brl,
+    Usd,
+    Eur,
+}
+
+impl Moeda {
+    /// Código ISO 4217.
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            Moeda::Brl => "BRL",
+            Moeda::Usd => "USD",
+            Moeda::Eur => "EUR",
+        }
+    }
+
+    pub fn simbolo(&self, idioma: Idioma) -> &'static str {
+        match (self, idioma) {
+            (Moeda::Brl, _) => "R$",
+            (Moeda::Usd, Idioma::PtBr) => "US$",
+            (Moeda::Usd, Idioma::EnUs) => "$",
+            (Moeda::Eur, _) => "€",
+        }
+    }
+}
+
+thread_local! {
+    static MOEDA: Cell<Moeda> = const { Cell::new(Moeda::Brl) };
+}
+
+/// Moeda usada pelo `Display` de `Dinheiro`.
+pub fn moeda() -> Moeda {
+    MOEDA.get()
+}
+
+pub fn definir_moeda(moeda: Moeda) {
+    MOEDA.set(moeda);
+}
+
+/// `(milhar, decimal)` de cada idioma.
+fn separadores(idioma: Idioma) -> (char, char) {
+    match idioma {
+        Idioma::PtBr => ('.', ','),
+        Idioma::EnUs => (',', '.'),
+    }
+}
+
+/// Quantia exata em centavos. Na API trafega como número decimal, como `19.9`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dinheiro(i64);
+
+impl Dinheiro {
+    pub const ZERO: Dinheiro = Dinheiro(0);
+
+    pub const fn de_centavos(centavos: i64) -> Self {
+        Self(centavos)
+    }
+
+    pub fn centavos(self) -> i64 {
+        self.0
+    }
+
+    /// Arredonda para o centavo mais próximo.
+    pub fn de_decimal(valor: f64) -> Self {
+        Self((valor * 100.0).round() as i64)
+    }
+
+    pub fn decimal(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// Número com ponto decimal e sem separador de milhar, como `1234.50`: o formato das URLs.
+    pub fn canonico(self) -> String {
+        let sinal = if self.negativo() { "-" } else { "" };
+        format!("{}{}.{:02}", sinal, (self.0 / 100).unsigned_abs(), (self.0 % 100).unsigned_abs())
+    }
+
+    /// Lê o formato de [`Dinheiro::canonico`], aceitando também `5` e `10.5`.
+    pub fn de_canonico(texto: &str) -> Option<Self> {
+        let (negativo, texto) = match texto.strip_prefix('-') {
+            Some(resto) => (true, resto),
+            None => (false, texto),
+        };
+        let (inteiro, fracao) = texto.split_once('.').unwrap_or((texto, ""));
+        let so_digitos = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if inteiro.is_empty() || fracao.len() > 2 || !so_digitos(inteiro) || !so_digitos(fracao) {
+            return None;
+        }
+        let centavos = inteiro
+            .parse::<i64>()
+            .ok()?
+            .checked_mul(100)?
+            .checked_add(format!("{:0<2}", fracao).parse().ok()?)?;
+        Some(Self(if negativo { -centavos } else { centavos }))
+    }
+
+    pub fn negativo(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Aplica o percentual (`10.0` para +10%), arredondando para o centavo.
+    pub fn mais_percentual(self, percentual: f64) -> Self {
+        Self((self.0 as f64 * (1.0 + percentual / 100.0)).round() as i64)
+    }
+
+    /// Número com os separadores do idioma e sem símbolo, como `1.234,56`.
+    pub fn numero(self, idioma: Idioma) -> String {
+        let (milhar, decimal) = separadores(idioma);
+        let inteiro = (self.0 / 100).unsigned_abs().to_string();
+        let mut agrupado = String::new();
+        for (i, c) in inteiro.chars().enumerate() {
+            if i > 0 && (inteiro.len() - i).is_multiple_of(3) {
+                agrupado.push(milhar);
+            }
+            agrupado.push(c);
+        }
+        let sinal = if self.negativo() { "-" } else { "" };
+        format!("{}{}{}{:02}", sinal, agrupado, decimal, (self.0 % 100).unsigned_abs())
+    }
+
+    /// Valor com o símbolo da moeda na posição usual do idioma: `R$ 1.234,56` ou `R$1,234.56`.
+    pub fn formatar(self, moeda: Moeda, idioma: Idioma) -> String {
+        let sinal = if self.negativo() { "-" } else { "" };
+        let numero = self.0.checked_abs().map(Self).unwrap_or(self).numero(idioma);
+        match idioma {
+            Idioma::PtBr => format!("{}{} {}", sinal, moeda.simbolo(idioma), numero),
+            Idioma::EnUs => format!("{}{}{}", sinal, moeda.simbolo(idioma), numero),
+        }
+    }
+
+    /// Lê um valor digitado, com ou sem símbolo e separador de milhar.
+    ///
+    /// Com os dois separadores, o último é o decimal (`1.234,56`, `1,234.56`). Com um só, ele é
+    /// decimal se vier seguido de até dois dígitos; `1.234` segue a convenção do idioma.
+    pub fn ler(texto: &str, idioma: Idioma) -> Option<Self> {
+        let (_, limpo) = sem_simbolo(texto);
+        let (milhar_do_idioma, _) = separadores(idioma);
+        let decimal = match (limpo.rfind(','), limpo.rfind('.')) {
+            (Some(v), Some(p)) => if v > p { ',' } else { '.' },
+            (Some(i), None) | (None, Some(i)) => {
+                let separador = limpo.as_bytes()[i] as char;
+                let casas = limpo.len() - i - 1;
+                // Sem decimal, o separador é o de milhar e o decimal é o outro.
+                if limpo.matches(separador).count() > 1 || casas == 3 && separador == milhar_do_idioma {
+                    if separador == ',' { '.' } else { ',' }
+                } else {
+                    separador
+                }
+            }
+            (None, None) => '.',
+        };
+        Self::ler_com_decimal(texto, decimal)
+    }
+
+    /// Como [`Dinheiro::ler`], com o separador decimal já conhecido: o outro (`.` ou `,`) é o de milhar.
+    pub fn ler_com_decimal(texto: &str, decimal: char) -> Option<Self> {
+        let (negativo, texto) = sem_simbolo(texto);
+        if texto.is_empty() || !texto.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ',') {
+            return None;
+        }
+
+        let (inteiro, fracao) = texto.rsplit_once(decimal).unwrap_or((&texto, ""));
+        if fracao.len() > 2 || !fracao.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let milhar = if decimal == ',' { '.' } else { ',' };
+        let grupos: Vec<&str> = inteiro.split(milhar).collect();
+        let agrupado_certo = grupos.len() == 1
+            || (1..=3).contains(&grupos[0].len()) && grupos[1..].iter().all(|g| g.len() == 3);
+        if !agrupado_certo || !grupos.iter().all(|g| g.chars().all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+        let inteiro = grupos.concat();
+        if inteiro.is_empty() && fracao.is_empty() {
+            return None;
+        }
+        let reais: i64 = if inteiro.is_empty() { 0 } else { inteiro.parse().ok()? };
+        let centavos: i64 = format!("{:0<2}", fracao).parse().ok()?;
+        let total = reais.checked_mul(100)?.checked_add(centavos)?;
+        Some(Self(if negativo { -total } else { total }))
+    }
+}
+
+/// Sinal e texto sem o símbolo da moeda (`R$`, `US$`, `$`, `€`) nem espaços.
+fn sem_simbolo(texto: &str) -> (bool, String) {
+    let texto: String = texto
+        .trim()
+        .trim_start_matches(['R', 'U', 'S', '$', '€'])
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    match texto.strip_prefix('-') {
+        Some(resto) => (true, resto.trim_start_matches(['R', 'U', 'S', '$', '€']).to_string()),
+        None => (false, texto),
+    }
+}
+
+/// Máscara do campo de preço: só dígitos e separadores, no máximo duas casas após o decimal do idioma.
+pub fn mascara(texto: &str, idioma: Idioma) -> String {
+    let (_, decimal) = separadores(idioma);
+    let mut saida = String::new();
+    let mut casas = None::<usize>;
+    for c in texto.chars() {
+        match (c, casas) {
+            ('0'..='9', Some(n)) if n < 2 => {
+                saida.push(c);
+                casas = Some(n + 1);
+            }
+            ('0'..='9', None) => saida.push(c),
+            ('.' | ',', None) => {
+                saida.push(c);
+                if c == decimal {
+                    casas = Some(0);
+                }
+            }
+            _ => {}
+        }
+    }
+    saida
+}
+
+impl fmt::Display for Dinheiro {
+    /// Na moeda configurada e no idioma atual.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.formatar(moeda(), i18n::atual()))
+    }
+}
+
+impl Add for Dinheiro {
+    type Output = Dinheiro;
+
+    fn add(self, outro: Dinheiro) -> Dinheiro {
+        Dinheiro(self.0 + outro.0)
+    }
+}
+
+impl Sub for Dinheiro {
+    type Output = Dinheiro;
+
+    fn sub(self, outro: Dinheiro) -> Dinheiro {
+        Dinheiro(self.0 - outro.0)
+    }
+}
+
+impl Neg for Dinheiro {
+    type Output = Dinheiro;
+
+    fn neg(self) -> Dinheiro {
+        Dinheiro(-self.0)
+    }
+}
+
+impl Sum for Dinheiro {
+    fn sum<I: Iterator<Item = Dinheiro>>(iter: I) -> Dinheiro {
+        iter.fold(Dinheiro::ZERO, Add::add)
+    }
+}
+
+impl Serialize for Dinheiro {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.decimal())
+    }
+}
+
+impl<'de> Deserialize<'de> for Dinheiro {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Dinheiro::de_decimal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ler(texto: &str, idioma: Idioma) -> Option<i64> {
+        Dinheiro::ler(texto, idioma).map(Dinheiro::centavos)
+    }
+
+    #[test]
+    fn soma_sem_erro_de_ponto_flutuante() {
+        let soma: Dinheiro = [0.1, 0.2].into_iter().map(Dinheiro::de_decimal).sum();
+        assert_eq!(soma, Dinheiro::de_centavos(30));
+        assert_eq!(soma.numero(Idioma::EnUs), "0.30");
+        assert_eq!(serde_json::to_string(&soma).unwrap(), "0.3");
+    }
+
+    #[test]
+    fn formata_conforme_o_idioma_e_a_moeda() {
+        let valor = Dinheiro::de_centavos(123_456_789);
+        assert_eq!(valor.formatar(Moeda::Brl, Idioma::PtBr), "R$ 1.234.567,89");
+        assert_eq!(valor.formatar(Moeda::Brl, Idioma::EnUs), "R$1,234,567.89");
+        assert_eq!(valor.formatar(Moeda::Usd, Idioma::PtBr), "US$ 1.234.567,89");
+        assert_eq!(valor.formatar(Moeda::Usd, Idioma::EnUs), "$1,234,567.89");
+        assert_eq!(Dinheiro::de_centavos(-5).formatar(Moeda::Eur, Idioma::PtBr), "-€ 0,05");
+        assert_eq!(Dinheiro::de_centavos(99_900).to_string(), "R$ 999,00");
+    }
+
+    #[test]
+    fn le_os_formatos_dos_dois_idiomas() {
+        assert_eq!(ler("49,90", Idioma::PtBr), Some(4990));
+        assert_eq!(ler("49.90", Idioma::PtBr), Some(4990));
+        assert_eq!(ler("R$ 1.234,5", Idioma::PtBr), Some(123_450));
+        assert_eq!(ler("$1,234.56", Idioma::EnUs), Some(123_456));
+        assert_eq!(ler("1.000.000", Idioma::PtBr), Some(100_000_000));
+        assert_eq!(ler("-10", Idioma::PtBr), Some(-1000));
+        assert_eq!(ler(",5", Idioma::PtBr), Some(50));
+    }
+
+    #[test]
+    fn separador_seguido_de_tres_digitos_segue_o_idioma() {
+        assert_eq!(ler("1.234", Idioma::PtBr), Some(123_400));
+        assert_eq!(ler("1,234", Idioma::EnUs), Some(123_400));
+        assert_eq!(ler("1.234", Idioma::EnUs), None);
+        assert_eq!(ler("1,234", Idioma::PtBr), None);
+    }
+
+    #[test]
+    fn rejeita_textos_que_nao_sao_valores() {
+        for texto in ["", "abc", "1,2,3", "12,345", "1.2.34", "-", "1e3"] {
+            assert_eq!(ler(texto, Idioma::PtBr), None, "{}", texto);
+        }
+    }
+
+    #[test]
+    fn formato_canonico_ida_e_volta() {
+        assert_eq!(Dinheiro::de_centavos(123_450).canonico(), "1234.50");
+        assert_eq!(Dinheiro::de_centavos(-5).canonico(), "-0.05");
+        for centavos in [0, 5, 1050, -99_999, 123_456_789] {
+            let valor = Dinheiro::de_centavos(centavos);
+            assert_eq!(Dinheiro::de_canonico(&valor.canonico()), Some(valor));
+        }
+        assert_eq!(Dinheiro::de_canonico("10.5"), Some(Dinheiro::de_centavos(1050)));
+        for texto in ["", "1,5", "0.125", "1e3", ".5", "NaN"] {
+            assert_eq!(Dinheiro::de_canonico(texto), None, "{}", texto);
+        }
+    }
+
+    #[test]
+    fn mascara_limita_as_casas_decimais() {
+        assert_eq!(mascara("R$ 1.234,567", Idioma::PtBr), "1.234,56");
+        assert_eq!(mascara("49.90x", Idioma::EnUs), "49.90");
+        assert_eq!(mascara("-12", Idioma::PtBr), "12");
+    }
+}
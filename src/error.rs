@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::i18n::{self, fluent_args};
+
+/// Mensagem de validação associada a um campo do formulário.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErroCampo {
+    pub campo: String,
+    pub mensagem: String,
+}
+
+impl ErroCampo {
+    pub fn new(campo: impl Into<String>, mensagem: impl Into<String>) -> Self {
+        Self {
+            campo: campo.into(),
+            mensagem: mensagem.into(),
+        }
+    }
+}
+
+/// Corpo de erro devolvido pela API. Aceita `mensagem`/`erro`/`error`/`message`
+/// e um mapa de campos com uma ou várias mensagens cada.
+#[derive(Clone, Debug, PartialEq, Default, serde::Deserialize)]
+pub struct ErroServidor {
+    #[serde(default, alias = "erro", alias = "error", alias = "message")]
+    pub mensagem: Option<String>,
+    #[serde(default, alias = "erros", alias = "errors", deserialize_with = "deserializar_campos")]
+    pub campos: Vec<ErroCampo>,
+}
+
+impl ErroServidor {
+    pub fn from_corpo(corpo: &str) -> Option<Self> {
+        let corpo = corpo.trim();
+        if corpo.is_empty() {
+            return None;
+        }
+        match serde_json::from_str::<ErroServidor>(corpo) {
+            Ok(erro) => Some(erro),
+            Err(_) => Some(ErroServidor {
+                mensagem: Some(corpo.to_string()),
+                campos: Vec::new(),
+            }),
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum UmaOuVarias {
+    Uma(String),
+    Varias(Vec<String>),
+}
+
+fn deserializar_campos<'de, D>(deserializer: D) -> Result<Vec<ErroCampo>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mapa: BTreeMap<String, UmaOuVarias> = serde::Deserialize::deserialize(deserializer)?;
+    let mut campos = Vec::new();
+    for (campo, mensagens) in mapa {
+        match mensagens {
+            UmaOuVarias::Uma(m) => campos.push(ErroCampo::new(campo, m)),
+            UmaOuVarias::Varias(ms) => {
+                campos.extend(ms.into_iter().map(|m| ErroCampo::new(campo.clone(), m)))
+            }
+        }
+    }
+    Ok(campos)
+}
+
+/// Falhas possíveis ao conversar com a API de itens.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ApiError {
+    /// A requisição não chegou a uma resposta (rede, CORS, URL inválida).
+    Rede(String),
+    /// A API respondeu com status de erro.
+    Http {
+        status: u16,
+        erro: Option<ErroServidor>,
+    },
+    /// Dados rejeitados, com as mensagens de cada campo.
+    Validacao(Vec<ErroCampo>),
+    /// A resposta de sucesso não pôde ser interpretada.
+    Resposta(String),
+}
+
+impl ApiError {
+    pub fn from_status(status: u16, corpo: &str) -> Self {
+        let erro = ErroServidor::from_corpo(corpo);
+        match erro {
+            Some(e) if matches!(status, 400 | 422) && !e.campos.is_empty() => {
+                ApiError::Validacao(e.campos)
+            }
+            erro => ApiError::Http { status, erro },
+        }
+    }
+
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ApiError::Http { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    pub fn erros_campos(&self) -> &[ErroCampo] {
+        match self {
+            ApiError::Validacao(campos) => campos,
+            _ => &[],
+        }
+    }
+
+    pub fn erro_do_campo(&self, campo: &str) -> Option<&str> {
+        self.erros_campos()
+            .iter()
+            .find(|e| e.campo == campo)
+            .map(|e| e.mensagem.as_str())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Rede(msg) => f.write_str(&i18n::t_args("erro-rede", &fluent_args!["detalhe" => msg.as_str()])),
+            ApiError::Http { status, erro } => {
+                let args = fluent_args!["status" => *status];
+                let mensagem = erro.as_ref().and_then(|e| e.mensagem.as_deref());
+                match (status, mensagem) {
+                    // Itens, categorias e histórico devolvem 404: só a API sabe o que faltou.
+                    (404, Some(msg)) => return f.write_str(msg),
+                    (404, None) => f.write_str(&i18n::t_args("erro-nao-encontrado", &args))?,
+                    (401 | 403, _) => f.write_str(&i18n::t_args("erro-acesso-negado", &args))?,
+                    (500..=599, _) => f.write_str(&i18n::t_args("erro-interno", &args))?,
+                    _ => f.write_str(&i18n::t_args("erro-status", &args))?,
+                }
+                match mensagem {
+                    Some(msg) => write!(f, ": {}", msg),
+                    None => Ok(()),
+                }
+            }
+            ApiError::Validacao(_) => f.write_str(&i18n::t("erro-validacao")),
+            ApiError::Resposta(msg) => f.write_str(&i18n::t_args("erro-resposta", &fluent_args!["detalhe" => msg.as_str()])),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erro_400_com_campos_vira_validacao() {
+        let corpo = r#"{"erro": "dados inválidos", "campos": {"nome": "obrigatório", "preco": ["deve ser positivo", "máximo 2 casas"]}}"#;
+        let erro = ApiError::from_status(422, corpo);
+
+        assert_eq!(erro.erro_do_campo("nome"), Some("obrigatório"));
+        assert_eq!(erro.erros_campos().len(), 3);
+        assert_eq!(erro.status(), None);
+    }
+
+    #[test]
+    fn erro_500_preserva_status_e_mensagem() {
+        let erro = ApiError::from_status(500, r#"{"error": "banco indisponível"}"#);
+
+        assert_eq!(erro.status(), Some(500));
+        assert_eq!(erro.to_string(), "erro interno na API (status 500): banco indisponível");
+    }
+
+    #[test]
+    fn corpo_que_nao_e_json_vira_mensagem() {
+        let erro = ApiError::from_status(404, "Not Found");
+
+        assert_eq!(
+            erro,
+            ApiError::Http {
+                status: 404,
+                erro: Some(ErroServidor {
+                    mensagem: Some("Not Found".into()),
+                    campos: Vec::new(),
+                }),
+            }
+        );
+        assert_eq!(erro.to_string(), "Not Found");
+    }
+
+    #[test]
+    fn erro_404_usa_a_mensagem_da_api() {
+        let categorias = ApiError::from_status(404, r#"{"erro": "Categoria não encontrada"}"#);
+        assert_eq!(categorias.to_string(), "Categoria não encontrada");
+
+        let sem_corpo = ApiError::from_status(404, "");
+        assert_eq!(sem_corpo.to_string(), "recurso não encontrado (status 404)");
+    }
+
+    #[test]
+    fn corpo_vazio_nao_tem_erro_do_servidor() {
+        assert_eq!(ApiError::from_status(503, ""), ApiError::Http { status: 503, erro: None });
+    }
+}
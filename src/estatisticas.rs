@@ -0,0 +1,145 @@
+//! Números do painel, calculados no navegador a partir de todos os itens do catálogo.
+
+use crate::dinheiro::Dinheiro;
+use crate::models::Item;
+
+/// Quantidade de faixas do histograma de preços.
+pub const FAIXAS_HISTOGRAMA: usize = 8;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Estatisticas {
+    pub total: usize,
+    pub media: Dinheiro,
+    pub mediana: Dinheiro,
+    pub minimo: Dinheiro,
+    pub maximo: Dinheiro,
+}
+
+impl Estatisticas {
+    /// `None` para um catálogo vazio.
+    pub fn calcular(itens: &[Item]) -> Option<Self> {
+        let mut centavos: Vec<i64> = itens.iter().map(|i| i.preco.centavos()).collect();
+        centavos.sort_unstable();
+        let total = centavos.len();
+        let (&minimo, &maximo) = (centavos.first()?, centavos.last()?);
+
+        let soma: i64 = centavos.iter().sum();
+        let meio = total / 2;
+        let mediana = if total.is_multiple_of(2) {
+            dividir(centavos[meio - 1] + centavos[meio], 2)
+        } else {
+            centavos[meio]
+        };
+
+        Some(Self {
+            total,
+            media: Dinheiro::de_centavos(dividir(soma, total as i64)),
+            mediana: Dinheiro::de_centavos(mediana),
+            minimo: Dinheiro::de_centavos(minimo),
+            maximo: Dinheiro::de_centavos(maximo),
+        })
+    }
+}
+
+/// Divisão arredondada para o centavo mais próximo.
+fn dividir(soma: i64, n: i64) -> i64 {
+    (soma as f64 / n as f64).round() as i64
+}
+
+/// Faixa de preço do histograma; `ate` só é incluído na última.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Faixa {
+    pub de: Dinheiro,
+    pub ate: Dinheiro,
+    pub quantidade: usize,
+}
+
+/// Distribui os preços em até `faixas` intervalos de mesma largura, do menor ao maior preço.
+pub fn histograma(itens: &[Item], faixas: usize) -> Vec<Faixa> {
+    let Some(estatisticas) = Estatisticas::calcular(itens) else {
+        return Vec::new();
+    };
+    let (minimo, maximo) = (estatisticas.minimo.centavos(), estatisticas.maximo.centavos());
+    let faixas = faixas.max(1) as i64;
+    let amplitude = maximo - minimo;
+    let largura = ((amplitude + faixas - 1) / faixas).max(1);
+    let quantidade = (amplitude / largura + 1).min(faixas);
+
+    let mut resultado: Vec<Faixa> = (0..quantidade)
+        .map(|i| Faixa {
+            de: Dinheiro::de_centavos(minimo + i * largura),
+            ate: Dinheiro::de_centavos(if i == quantidade - 1 { maximo } else { minimo + (i + 1) * largura }),
+            quantidade: 0,
+        })
+        .collect();
+    for item in itens {
+        let i = ((item.preco.centavos() - minimo) / largura).min(quantidade - 1);
+        resultado[i as usize].quantidade += 1;
+    }
+    resultado
+}
+
+/// Os `n` itens incluídos por último: pela data de criação e, sem ela, pelo maior id.
+pub fn recentes(itens: &[Item], n: usize) -> Vec<Item> {
+    let mut ordenados = itens.to_vec();
+    ordenados.sort_by(|a, b| (&b.criado_em, b.id).cmp(&(&a.criado_em, a.id)));
+    ordenados.truncate(n);
+    ordenados
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: i32, centavos: i64) -> Item {
+        Item {
+            id,
+            nome: format!("Item {}", id),
+            preco: Dinheiro::de_centavos(centavos),
+            ..Item::default()
+        }
+    }
+
+    fn itens(precos: &[i64]) -> Vec<Item> {
+        precos.iter().zip(1..).map(|(&p, id)| item(id, p)).collect()
+    }
+
+    #[test]
+    fn media_mediana_e_extremos_em_centavos() {
+        let e = Estatisticas::calcular(&itens(&[1000, 250, 300, 101])).unwrap();
+        assert_eq!(e.total, 4);
+        assert_eq!(e.media.centavos(), 413);
+        assert_eq!(e.mediana.centavos(), 275);
+        assert_eq!((e.minimo.centavos(), e.maximo.centavos()), (101, 1000));
+
+        let impar = Estatisticas::calcular(&itens(&[500, 100, 300])).unwrap();
+        assert_eq!(impar.mediana.centavos(), 300);
+        assert_eq!(Estatisticas::calcular(&[]), None);
+    }
+
+    #[test]
+    fn histograma_cobre_do_menor_ao_maior_preco() {
+        let faixas = histograma(&itens(&[100, 150, 200, 490, 500]), 4);
+        let resumo: Vec<(i64, i64, usize)> =
+            faixas.iter().map(|f| (f.de.centavos(), f.ate.centavos(), f.quantidade)).collect();
+        assert_eq!(resumo, vec![(100, 200, 2), (200, 300, 1), (300, 400, 0), (400, 500, 2)]);
+    }
+
+    #[test]
+    fn histograma_de_precos_iguais_tem_uma_faixa() {
+        let faixas = histograma(&itens(&[990, 990]), FAIXAS_HISTOGRAMA);
+        assert_eq!(faixas.len(), 1);
+        assert_eq!(faixas[0].quantidade, 2);
+        assert!(histograma(&[], FAIXAS_HISTOGRAMA).is_empty());
+    }
+
+    #[test]
+    fn recentes_pela_data_de_criacao_ou_pelo_id() {
+        let mut lista = itens(&[100, 200, 300, 400]);
+        lista[0].criado_em = Some("2025-03-01T00:00:00Z".into());
+        lista[2].criado_em = Some("2025-01-01T00:00:00Z".into());
+
+        let ids: Vec<i32> = recentes(&lista, 3).iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![1, 3, 4]);
+    }
+}
@@ -0,0 +1,237 @@
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+
+use crate::csv;
+use crate::i18n::{self, Idioma};
+use crate::models::Item;
+use crate::xlsx::{self, Celula};
+
+/// Tempo até liberar a URL do arquivo: revogada logo após o clique, o download pode falhar.
+const REVOGAR_APOS_MS: u32 = 60_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Formato {
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl Formato {
+    pub const TODOS: [Formato; 3] = [Formato::Csv, Formato::Json, Formato::Xlsx];
+
+    pub fn rotulo(&self) -> &'static str {
+        match self {
+            Formato::Csv => "CSV",
+            Formato::Json => "JSON",
+            Formato::Xlsx => "Excel (XLSX)",
+        }
+    }
+
+    pub fn extensao(&self) -> &'static str {
+        match self {
+            Formato::Csv => "csv",
+            Formato::Json => "json",
+            Formato::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn mime(&self) -> &'static str {
+        match self {
+            Formato::Csv => "text/csv;charset=utf-8",
+            Formato::Json => "application/json",
+            Formato::Xlsx => xlsx::MIME,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Escopo {
+    PaginaAtual,
+    /// Todas as páginas da busca atual, na ordenação atual.
+    Todas,
+}
+
+pub fn gerar(itens: &[Item], formato: Formato) -> Vec<u8> {
+    match formato {
+        Formato::Csv => para_csv(itens).into_bytes(),
+        Formato::Json => serde_json::to_vec_pretty(itens).unwrap_or_default(),
+        Formato::Xlsx => para_xlsx(itens),
+    }
+}
+
+/// CSV no padrão do Excel em pt-BR: BOM UTF-8, `;` e vírgula decimal.
+/// Pode ser reimportado pela tela de importação.
+pub fn para_csv(itens: &[Item]) -> String {
+    let mut texto = String::from('\u{feff}');
+    texto.push_str(&csv::escrever_linha(&cabecalho(), ';'));
+    texto.push_str("\r\n");
+    for item in itens {
+        // Vírgula decimal e sem separador de milhar, como o Excel em português espera.
+        let preco = item.preco.numero(Idioma::PtBr).replace('.', "");
+        let estoque = item.estoque.map(|e| e.to_string()).unwrap_or_default();
+        let [descricao, categoria, sku, ativo, criado_em, atualizado_em] = textos(item);
+        let linha = [item.id.to_string(), item.nome.clone(), preco, descricao, categoria, sku, estoque, ativo, criado_em, atualizado_em];
+        texto.push_str(&csv::escrever_linha(&linha, ';'));
+        texto.push_str("\r\n");
+    }
+    texto
+}
+
+pub fn para_xlsx(itens: &[Item]) -> Vec<u8> {
+    let mut linhas = vec![cabecalho().map(Celula::Texto).to_vec()];
+    linhas.extend(itens.iter().map(|item| {
+        let [descricao, categoria, sku, ativo, criado_em, atualizado_em] = textos(item).map(Celula::Texto);
+        let estoque = item.estoque.map_or(Celula::Texto(String::new()), |e| Celula::Inteiro(e as i64));
+        vec![
+            Celula::Inteiro(item.id as i64),
+            Celula::Texto(item.nome.clone()),
+            Celula::Decimal(item.preco.decimal()),
+            descricao,
+            categoria,
+            sku,
+            estoque,
+            ativo,
+            criado_em,
+            atualizado_em,
+        ]
+    }));
+    xlsx::planilha(&i18n::t("exportar-planilha"), &linhas)
+}
+
+/// Todos os campos do `Item`, na ordem das colunas exportadas.
+fn cabecalho() -> [String; 10] {
+    [
+        "coluna-id",
+        "coluna-nome",
+        "coluna-preco",
+        "coluna-descricao",
+        "coluna-categoria",
+        "coluna-sku",
+        "coluna-estoque",
+        "coluna-ativo",
+        "detalhe-criado-em",
+        "detalhe-atualizado-em",
+    ]
+    .map(i18n::t)
+}
+
+/// Campos de texto do item, vazios quando a API não os informa.
+fn textos(item: &Item) -> [String; 6] {
+    let texto = |v: &Option<String>| v.clone().unwrap_or_default();
+    [
+        texto(&item.descricao),
+        texto(&item.categoria),
+        texto(&item.sku),
+        i18n::t(if item.ativo { "sim" } else { "nao" }),
+        texto(&item.criado_em),
+        texto(&item.atualizado_em),
+    ]
+}
+
+/// Nome do arquivo baixado, como `itens-2024-05-01.csv`.
+pub fn nome_arquivo(formato: Formato) -> String {
+    let hoje: String = js_sys::Date::new_0().to_iso_string().into();
+    format!("itens-{}.{}", &hoje[..10], formato.extensao())
+}
+
+/// Oferece os bytes para download no navegador.
+pub fn baixar(nome: &str, bytes: &[u8], mime: &str) -> Result<(), String> {
+    let erro = |e: wasm_bindgen::JsValue| format!("{:?}", e);
+    let partes = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let opcoes = web_sys::BlobPropertyBag::new();
+    opcoes.set_type(mime);
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&partes, &opcoes).map_err(erro)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(erro)?;
+
+    let document = web_sys::window().and_then(|w| w.document()).ok_or_else(|| i18n::t("exportar-sem-documento"))?;
+    let link: web_sys::HtmlAnchorElement = document.create_element("a").map_err(erro)?.unchecked_into();
+    link.set_href(&url);
+    link.set_download(nome);
+    link.click();
+
+    Timeout::new(REVOGAR_APOS_MS, move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dinheiro::Dinheiro;
+
+    fn itens() -> Vec<Item> {
+        vec![
+            Item {
+                id: 1,
+                nome: "Café; torrado".into(),
+                preco: Dinheiro::de_centavos(123_450),
+                ..Item::default()
+            },
+            Item {
+                id: 2,
+                nome: "Chá".into(),
+                preco: Dinheiro::de_centavos(900),
+                descricao: Some("Folhas secas".into()),
+                categoria: Some("Bebidas".into()),
+                sku: Some("CHA-01".into()),
+                estoque: Some(12),
+                ativo: false,
+                criado_em: Some("2024-05-01T10:00:00Z".into()),
+                ..Item::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn csv_em_formato_brasileiro() {
+        assert_eq!(
+            para_csv(&itens()),
+            "\u{feff}ID;Nome;Preço;Descrição;Categoria;SKU;Estoque;Ativo;Criado em;Atualizado em\r\n\
+             1;\"Café; torrado\";1234,50;;;;;Sim;;\r\n\
+             2;Chá;9,00;Folhas secas;Bebidas;CHA-01;12;Não;2024-05-01T10:00:00Z;\r\n"
+        );
+    }
+
+    #[test]
+    fn csv_exportado_pode_ser_reimportado() {
+        use crate::importacao::{Mapeamento, validar_linhas};
+
+        let planilha = csv::ler(&para_csv(&itens()));
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+        let importados: Vec<_> = linhas.into_iter().map(|l| l.resultado.unwrap()).collect();
+
+        assert_eq!(importados[0].nome, "Café; torrado");
+        assert_eq!(importados[0].preco, Dinheiro::de_centavos(123_450));
+        assert_eq!(importados[1].preco, Dinheiro::de_centavos(900));
+    }
+
+    #[test]
+    fn xlsx_tem_todas_as_colunas() {
+        let texto = String::from_utf8_lossy(&para_xlsx(&itens())).into_owned();
+        assert!(texto.contains(r#"<c r="J1" t="inlineStr"><is><t xml:space="preserve">Atualizado em</t></is></c>"#));
+        assert!(texto.contains(r#"<c r="F3" t="inlineStr"><is><t xml:space="preserve">CHA-01</t></is></c>"#));
+        assert!(texto.contains(r#"<c r="G3"><v>12</v></c>"#));
+        assert!(texto.contains(r#"<c r="H3" t="inlineStr"><is><t xml:space="preserve">Não</t></is></c>"#));
+    }
+
+    #[test]
+    fn json_usa_os_campos_do_item() {
+        let json: serde_json::Value = serde_json::from_slice(&gerar(&itens(), Formato::Json)).unwrap();
+        assert_eq!(
+            json[1],
+            serde_json::json!({
+                "id": 2,
+                "nome": "Chá",
+                "preco": 9.0,
+                "descricao": "Folhas secas",
+                "categoria": "Bebidas",
+                "sku": "CHA-01",
+                "estoque": 12,
+                "ativo": false,
+                "criado_em": "2024-05-01T10:00:00Z",
+            })
+        );
+    }
+}
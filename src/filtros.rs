@@ -0,0 +1,195 @@
+use crate::api::ListarParams;
+use crate::dinheiro::Dinheiro;
+use crate::i18n::{self, fluent_args};
+
+/// Como o texto da busca é comparado com o nome do item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModoNome {
+    #[default]
+    Contem,
+    ComecaCom,
+    Exato,
+    Regex,
+}
+
+impl ModoNome {
+    pub const TODOS: [ModoNome; 4] = [ModoNome::Contem, ModoNome::ComecaCom, ModoNome::Exato, ModoNome::Regex];
+
+    pub fn rotulo(&self) -> String {
+        i18n::t(match self {
+            ModoNome::Contem => "modo-contem",
+            ModoNome::ComecaCom => "modo-comeca-com",
+            ModoNome::Exato => "modo-exato",
+            ModoNome::Regex => "modo-regex",
+        })
+    }
+
+    /// Valor usado na query string (`modo=comeca_com`).
+    pub fn valor(&self) -> &'static str {
+        match self {
+            ModoNome::Contem => "contem",
+            ModoNome::ComecaCom => "comeca_com",
+            ModoNome::Exato => "exato",
+            ModoNome::Regex => "regex",
+        }
+    }
+
+    pub fn de_valor(valor: &str) -> Option<Self> {
+        Self::TODOS.into_iter().find(|m| m.valor() == valor)
+    }
+}
+
+/// Filtros do painel avançado, aplicados junto com a busca, a ordenação e a paginação.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Filtros {
+    pub preco_min: Option<Dinheiro>,
+    pub preco_max: Option<Dinheiro>,
+    pub id_min: Option<i32>,
+    pub id_max: Option<i32>,
+    pub modo: ModoNome,
+}
+
+/// Um filtro ativo, que pode ser removido isoladamente pela sua etiqueta.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CampoFiltro {
+    PrecoMin,
+    PrecoMax,
+    IdMin,
+    IdMax,
+    Modo,
+}
+
+impl Filtros {
+    pub fn de_consulta(consulta: &ListarParams) -> Self {
+        Self {
+            preco_min: consulta.preco_min,
+            preco_max: consulta.preco_max,
+            id_min: consulta.id_min,
+            id_max: consulta.id_max,
+            modo: consulta.modo,
+        }
+    }
+
+    pub fn ativos(&self) -> Vec<CampoFiltro> {
+        [
+            (self.preco_min.is_some(), CampoFiltro::PrecoMin),
+            (self.preco_max.is_some(), CampoFiltro::PrecoMax),
+            (self.id_min.is_some(), CampoFiltro::IdMin),
+            (self.id_max.is_some(), CampoFiltro::IdMax),
+            (self.modo != ModoNome::Contem, CampoFiltro::Modo),
+        ]
+        .into_iter()
+        .filter_map(|(ativo, campo)| ativo.then_some(campo))
+        .collect()
+    }
+
+    /// Texto da etiqueta do filtro, como `Preço ≥ R$ 10,00`.
+    pub fn rotulo(&self, campo: CampoFiltro) -> String {
+        let preco = |v: Option<Dinheiro>| v.unwrap_or_default().to_string();
+        let (id, args) = match campo {
+            CampoFiltro::PrecoMin => ("filtro-preco-min", fluent_args!["valor" => preco(self.preco_min)]),
+            CampoFiltro::PrecoMax => ("filtro-preco-max", fluent_args!["valor" => preco(self.preco_max)]),
+            CampoFiltro::IdMin => ("filtro-id-min", fluent_args!["valor" => self.id_min.unwrap_or_default()]),
+            CampoFiltro::IdMax => ("filtro-id-max", fluent_args!["valor" => self.id_max.unwrap_or_default()]),
+            CampoFiltro::Modo => ("filtro-modo", fluent_args!["modo" => self.modo.rotulo()]),
+        };
+        i18n::t_args(id, &args)
+    }
+
+    pub fn sem(mut self, campo: CampoFiltro) -> Self {
+        match campo {
+            CampoFiltro::PrecoMin => self.preco_min = None,
+            CampoFiltro::PrecoMax => self.preco_max = None,
+            CampoFiltro::IdMin => self.id_min = None,
+            CampoFiltro::IdMax => self.id_max = None,
+            CampoFiltro::Modo => self.modo = ModoNome::Contem,
+        }
+        self
+    }
+
+    /// Lê os campos do painel; vazios ficam sem filtro. Devolve a primeira mensagem de erro.
+    pub fn ler(preco_min: &str, preco_max: &str, id_min: &str, id_max: &str, modo: ModoNome) -> Result<Self, String> {
+        let invalido = |rotulo: &str| i18n::t_args("filtros-invalido", &fluent_args!["campo" => i18n::t(rotulo)]);
+        let preco = |texto: &str, rotulo: &str| -> Result<Option<Dinheiro>, String> {
+            if texto.trim().is_empty() {
+                return Ok(None);
+            }
+            match Dinheiro::ler(texto, i18n::atual()) {
+                Some(v) if !v.negativo() => Ok(Some(v)),
+                _ => Err(invalido(rotulo)),
+            }
+        };
+        let id = |texto: &str, rotulo: &str| -> Result<Option<i32>, String> {
+            let texto = texto.trim();
+            if texto.is_empty() {
+                return Ok(None);
+            }
+            texto.parse::<i32>().map(Some).map_err(|_| invalido(rotulo))
+        };
+
+        let filtros = Self {
+            preco_min: preco(preco_min, "filtros-preco-min")?,
+            preco_max: preco(preco_max, "filtros-preco-max")?,
+            id_min: id(id_min, "filtros-id-min")?,
+            id_max: id(id_max, "filtros-id-max")?,
+            modo,
+        };
+        if matches!((filtros.preco_min, filtros.preco_max), (Some(min), Some(max)) if min > max) {
+            return Err(i18n::t("filtros-preco-invertido"));
+        }
+        if matches!((filtros.id_min, filtros.id_max), (Some(min), Some(max)) if min > max) {
+            return Err(i18n::t("filtros-id-invertido"));
+        }
+        Ok(filtros)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn campos_vazios_nao_filtram() {
+        assert_eq!(Filtros::ler("", " ", "", "", ModoNome::Contem), Ok(Filtros::default()));
+        assert!(Filtros::default().ativos().is_empty());
+    }
+
+    #[test]
+    fn le_os_campos_e_gera_as_etiquetas() {
+        let filtros = Filtros::ler("10,5", "50", "3", "", ModoNome::ComecaCom).unwrap();
+
+        assert_eq!(
+            filtros.ativos(),
+            vec![CampoFiltro::PrecoMin, CampoFiltro::PrecoMax, CampoFiltro::IdMin, CampoFiltro::Modo]
+        );
+        let rotulos: Vec<String> = filtros.ativos().into_iter().map(|c| filtros.rotulo(c)).collect();
+        assert_eq!(rotulos, vec!["Preço ≥ R$ 10,50", "Preço ≤ R$ 50,00", "ID ≥ 3", "Nome começa com"]);
+
+        let sem_minimo = filtros.sem(CampoFiltro::PrecoMin).sem(CampoFiltro::Modo);
+        assert_eq!(sem_minimo.preco_min, None);
+        assert_eq!(sem_minimo.modo, ModoNome::Contem);
+        assert_eq!(sem_minimo.preco_max, Some(Dinheiro::de_centavos(5000)));
+    }
+
+    #[test]
+    fn rejeita_valores_invalidos_e_intervalos_invertidos() {
+        assert_eq!(Filtros::ler("abc", "", "", "", ModoNome::Contem), Err("Preço mínimo inválido".into()));
+        assert_eq!(Filtros::ler("", "-1", "", "", ModoNome::Contem), Err("Preço máximo inválido".into()));
+        assert_eq!(Filtros::ler("", "", "1.5", "", ModoNome::Contem), Err("ID inicial inválido".into()));
+        assert_eq!(
+            Filtros::ler("20", "10", "", "", ModoNome::Contem),
+            Err("O preço mínimo é maior que o máximo".into())
+        );
+        assert_eq!(Filtros::ler("", "", "9", "2", ModoNome::Contem), Err("O ID inicial é maior que o final".into()));
+    }
+
+    #[test]
+    fn modo_pelo_valor_da_query() {
+        assert_eq!(ModoNome::de_valor("comeca_com"), Some(ModoNome::ComecaCom));
+        assert_eq!(ModoNome::de_valor("xyz"), None);
+        for modo in ModoNome::TODOS {
+            assert_eq!(serde_json::to_value(modo).unwrap(), serde_json::json!(modo.valor()));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Geometria dos gráficos em SVG: converte séries de valores em coordenadas do `viewBox`.
+
+/// Tamanho do `viewBox` e a margem livre em volta do desenho.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Area {
+    pub largura: f64,
+    pub altura: f64,
+    pub margem: f64,
+}
+
+fn escala(valor: f64, (min, max): (f64, f64), inicio: f64, tamanho: f64) -> f64 {
+    if max > min {
+        inicio + (valor - min) / (max - min) * tamanho
+    } else {
+        inicio + tamanho / 2.0
+    }
+}
+
+/// Posição de cada ponto `(x, y)` na área; o menor `y` fica embaixo.
+/// Um ponto só, ou uma série constante, fica no meio do eixo correspondente.
+pub fn posicionar(pontos: &[(f64, f64)], area: Area) -> Vec<(f64, f64)> {
+    let limites = |valores: Vec<f64>| {
+        let min = valores.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = valores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (min, max)
+    };
+    let x_limites = limites(pontos.iter().map(|p| p.0).collect());
+    let y_limites = limites(pontos.iter().map(|p| p.1).collect());
+    let largura = area.largura - 2.0 * area.margem;
+    let altura = area.altura - 2.0 * area.margem;
+
+    pontos
+        .iter()
+        .map(|&(x, y)| {
+            let x = escala(x, x_limites, area.margem, largura);
+            let y = area.altura - escala(y, y_limites, area.margem, altura);
+            (x, y)
+        })
+        .collect()
+}
+
+/// Valor do atributo `points` de uma `<polyline>`.
+pub fn polilinha(pontos: &[(f64, f64)]) -> String {
+    pontos
+        .iter()
+        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Retângulo de uma barra, em coordenadas do `viewBox`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Barra {
+    pub x: f64,
+    pub y: f64,
+    pub largura: f64,
+    pub altura: f64,
+}
+
+/// Barras lado a lado, com `espaco` entre elas; a maior ocupa a altura útil da área.
+pub fn barras(valores: &[f64], espaco: f64, area: Area) -> Vec<Barra> {
+    if valores.is_empty() {
+        return Vec::new();
+    }
+    let maior = valores.iter().copied().fold(0.0, f64::max);
+    let util = area.altura - 2.0 * area.margem;
+    let passo = (area.largura - 2.0 * area.margem) / valores.len() as f64;
+
+    valores
+        .iter()
+        .enumerate()
+        .map(|(i, &valor)| {
+            let altura = if maior > 0.0 { valor / maior * util } else { 0.0 };
+            Barra {
+                x: area.margem + i as f64 * passo + espaco / 2.0,
+                y: area.altura - area.margem - altura,
+                largura: (passo - espaco).max(0.0),
+                altura,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AREA: Area = Area {
+        largura: 100.0,
+        altura: 50.0,
+        margem: 5.0,
+    };
+
+    #[test]
+    fn pontos_ocupam_a_area_sem_a_margem() {
+        let pontos = posicionar(&[(0.0, 10.0), (5.0, 30.0), (10.0, 20.0)], AREA);
+        assert_eq!(pontos, vec![(5.0, 45.0), (50.0, 5.0), (95.0, 25.0)]);
+        assert_eq!(polilinha(&pontos), "5.0,45.0 50.0,5.0 95.0,25.0");
+    }
+
+    #[test]
+    fn serie_constante_fica_no_meio() {
+        assert_eq!(posicionar(&[(3.0, 7.0)], AREA), vec![(50.0, 25.0)]);
+        assert_eq!(posicionar(&[(1.0, 2.0), (2.0, 2.0)], AREA), vec![(5.0, 25.0), (95.0, 25.0)]);
+        assert!(posicionar(&[], AREA).is_empty());
+    }
+
+    #[test]
+    fn barras_proporcionais_ao_maior_valor() {
+        let barras = barras(&[2.0, 0.0, 4.0], 2.0, Area { largura: 70.0, ..AREA });
+        let alturas: Vec<f64> = barras.iter().map(|b| b.altura).collect();
+        assert_eq!(alturas, vec![20.0, 0.0, 40.0]);
+        assert_eq!(barras[2], Barra { x: 46.0, y: 5.0, largura: 18.0, altura: 40.0 });
+    }
+}
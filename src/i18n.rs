@@ -0,0 +1,206 @@
+//! Textos da interface: catálogos Fluent em `locales/`, idioma atual e detecção pelo navegador.
+
+use std::cell::Cell;
+
+use fluent::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+use yew::prelude::*;
+
+pub use fluent::fluent_args;
+
+const CHAVE_STORAGE: &str = "rust-app-sample:idioma";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Idioma {
+    #[default]
+    PtBr,
+    EnUs,
+}
+
+impl Idioma {
+    pub const TODOS: [Idioma; 2] = [Idioma::PtBr, Idioma::EnUs];
+
+    /// Etiqueta BCP 47, usada no `localStorage` e no atributo `lang`.
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            Idioma::PtBr => "pt-BR",
+            Idioma::EnUs => "en-US",
+        }
+    }
+
+    /// Nome do idioma nele mesmo, para o seletor.
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Idioma::PtBr => "Português",
+            Idioma::EnUs => "English",
+        }
+    }
+
+    /// Idioma suportado mais próximo de uma etiqueta como `en-GB` ou `pt`.
+    pub fn de_codigo(codigo: &str) -> Option<Self> {
+        let lingua = codigo.split(['-', '_']).next()?.to_lowercase();
+        match lingua.as_str() {
+            "pt" => Some(Idioma::PtBr),
+            "en" => Some(Idioma::EnUs),
+            _ => None,
+        }
+    }
+
+    fn fonte(&self) -> &'static str {
+        match self {
+            Idioma::PtBr => include_str!("../locales/pt-BR/main.ftl"),
+            Idioma::EnUs => include_str!("../locales/en-US/main.ftl"),
+        }
+    }
+
+    fn catalogo(&self) -> FluentBundle<FluentResource> {
+        let recurso = FluentResource::try_new(self.fonte().to_string()).expect("catálogo Fluent inválido");
+        let lingua: LanguageIdentifier = self.codigo().parse().expect("código de idioma inválido");
+        let mut bundle = FluentBundle::new(vec![lingua]);
+        // Sem os caracteres de isolamento bidirecional em volta dos argumentos.
+        bundle.set_use_isolating(false);
+        bundle.add_resource(recurso).expect("mensagem repetida no catálogo");
+        bundle
+    }
+}
+
+thread_local! {
+    static ATUAL: Cell<Idioma> = const { Cell::new(Idioma::PtBr) };
+    static CATALOGOS: Vec<FluentBundle<FluentResource>> = Idioma::TODOS.iter().map(Idioma::catalogo).collect();
+}
+
+fn formatar(idioma: Idioma, id: &str, args: Option<&FluentArgs>) -> String {
+    CATALOGOS.with(|catalogos| {
+        let bundle = &catalogos[idioma as usize];
+        match bundle.get_message(id).and_then(|m| m.value()) {
+            Some(padrao) => bundle.format_pattern(padrao, args, &mut Vec::new()).into_owned(),
+            None => id.to_string(),
+        }
+    })
+}
+
+/// Texto no idioma atual, para código fora dos componentes (erros, resumos, rótulos).
+pub fn t(id: &str) -> String {
+    formatar(ATUAL.get(), id, None)
+}
+
+pub fn t_args(id: &str, args: &FluentArgs) -> String {
+    formatar(ATUAL.get(), id, Some(args))
+}
+
+pub fn atual() -> Idioma {
+    ATUAL.get()
+}
+
+/// Data e hora ISO 8601 no fuso e no formato do navegador para o idioma; o texto original se não for uma data.
+pub fn data_hora(iso: &str, idioma: Idioma) -> String {
+    let data = js_sys::Date::new(&iso.into());
+    if data.get_time().is_nan() {
+        return iso.to_string();
+    }
+    data.to_locale_string(idioma.codigo(), &wasm_bindgen::JsValue::UNDEFINED).into()
+}
+
+/// Troca o idioma usado por `t` e marca o documento com ele.
+pub fn definir(idioma: Idioma) {
+    ATUAL.set(idioma);
+    if let Some(raiz) = web_sys::window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+        let _ = raiz.set_attribute("lang", idioma.codigo());
+    }
+}
+
+/// Idioma escolhido antes ou, na primeira visita, o do navegador.
+pub fn detectar() -> Idioma {
+    let Some(window) = web_sys::window() else {
+        return Idioma::default();
+    };
+    let salvo = window
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|s| s.get_item(CHAVE_STORAGE).ok().flatten());
+    salvo
+        .or_else(|| window.navigator().language())
+        .and_then(|codigo| Idioma::de_codigo(&codigo))
+        .unwrap_or_default()
+}
+
+pub fn gravar(idioma: Idioma) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.set_item(CHAVE_STORAGE, idioma.codigo());
+    }
+}
+
+/// Idioma da interface e a ação de trocá-lo, compartilhados por contexto.
+#[derive(Clone, PartialEq)]
+pub struct I18n {
+    pub idioma: Idioma,
+    pub mudar: Callback<Idioma>,
+}
+
+impl I18n {
+    pub fn t(&self, id: &str) -> String {
+        formatar(self.idioma, id, None)
+    }
+
+    pub fn t_args(&self, id: &str, args: &FluentArgs) -> String {
+        formatar(self.idioma, id, Some(args))
+    }
+}
+
+/// Componentes que usam o hook são renderizados de novo quando o idioma muda.
+#[hook]
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>().expect("use_i18n chamado fora de um ContextProvider<I18n>")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn ids(idioma: Idioma) -> BTreeSet<&'static str> {
+        idioma
+            .fonte()
+            .lines()
+            .filter(|l| l.starts_with(|c: char| c.is_ascii_lowercase()))
+            .filter_map(|l| l.split_once(" ="))
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    #[test]
+    fn catalogos_tem_as_mesmas_mensagens() {
+        assert_eq!(ids(Idioma::PtBr), ids(Idioma::EnUs));
+    }
+
+    #[test]
+    fn detecta_o_idioma_pela_etiqueta() {
+        assert_eq!(Idioma::de_codigo("en-GB"), Some(Idioma::EnUs));
+        assert_eq!(Idioma::de_codigo("pt"), Some(Idioma::PtBr));
+        assert_eq!(Idioma::de_codigo("PT_pt"), Some(Idioma::PtBr));
+        assert_eq!(Idioma::de_codigo("fr-FR"), None);
+    }
+
+    #[test]
+    fn plurais_seguem_as_regras_de_cada_idioma() {
+        let total = |idioma, n: i64| formatar(idioma, "lista-total", Some(&fluent_args!["n" => n]));
+
+        assert_eq!(total(Idioma::PtBr, 0), "Nenhum item");
+        assert_eq!(total(Idioma::PtBr, 1), "1 item");
+        assert_eq!(total(Idioma::PtBr, 25), "25 itens");
+        assert_eq!(total(Idioma::EnUs, 0), "No items");
+        assert_eq!(total(Idioma::EnUs, 1), "1 item");
+        assert_eq!(total(Idioma::EnUs, 25), "25 items");
+    }
+
+    #[test]
+    fn usa_o_idioma_atual_e_devolve_o_id_sem_traducao() {
+        assert_eq!(t("lista-titulo"), "Itens");
+        ATUAL.set(Idioma::EnUs);
+        assert_eq!(t("lista-titulo"), "Items");
+        ATUAL.set(Idioma::PtBr);
+        assert_eq!(t("mensagem-inexistente"), "mensagem-inexistente");
+    }
+}
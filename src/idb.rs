@@ -0,0 +1,157 @@
+//! Acesso mínimo ao IndexedDB: valores JSON guardados por chave em duas lojas.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use js_sys::Promise;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransaction, IdbTransactionMode};
+
+use crate::i18n::{self, fluent_args};
+
+const BANCO: &str = "rust-app-sample";
+const VERSAO: u32 = 1;
+
+/// Páginas de `ListarResponse` já buscadas, pela chave da consulta.
+pub const PAGINAS: &str = "paginas";
+/// Alterações feitas sem conexão, esperando para serem enviadas.
+pub const FILA: &str = "fila";
+
+async fn concluir(req: &IdbRequest) -> Result<JsValue, String> {
+    let promessa = Promise::new(&mut |resolve, reject| {
+        let sucesso = req.clone();
+        req.set_onsuccess(Some(
+            Closure::once_into_js(move |_: JsValue| {
+                let _ = resolve.call1(&JsValue::NULL, &sucesso.result().unwrap_or(JsValue::UNDEFINED));
+            })
+            .unchecked_ref(),
+        ));
+        let falha = req.clone();
+        req.set_onerror(Some(
+            Closure::once_into_js(move |_: JsValue| {
+                let erro = falha.error().ok().flatten().map(|e| e.message()).unwrap_or_default();
+                let _ = reject.call1(&JsValue::NULL, &erro.into());
+            })
+            .unchecked_ref(),
+        ));
+    });
+    JsFuture::from(promessa)
+        .await
+        .map_err(|e| e.as_string().unwrap_or_else(|| i18n::t("idb-falha")))
+}
+
+async fn abrir() -> Result<IdbDatabase, String> {
+    let fabrica = web_sys::window()
+        .and_then(|w| w.indexed_db().ok().flatten())
+        .ok_or_else(|| i18n::t("idb-indisponivel"))?;
+    let req: IdbOpenDbRequest = fabrica.open_with_u32(BANCO, VERSAO).map_err(|_| i18n::t("idb-indisponivel"))?;
+
+    let criar = req.clone();
+    req.set_onupgradeneeded(Some(
+        Closure::once_into_js(move |_: JsValue| {
+            if let Ok(banco) = criar.result().map(|r| r.unchecked_into::<IdbDatabase>()) {
+                for loja in [PAGINAS, FILA] {
+                    if !banco.object_store_names().contains(loja) {
+                        let _ = banco.create_object_store(loja);
+                    }
+                }
+            }
+        })
+        .unchecked_ref(),
+    ));
+
+    Ok(concluir(&req).await?.unchecked_into())
+}
+
+async fn executar<F>(loja: &str, modo: IdbTransactionMode, operacao: F) -> Result<JsValue, String>
+where
+    F: FnOnce(&web_sys::IdbObjectStore) -> Result<IdbRequest, JsValue>,
+{
+    let banco = abrir().await?;
+    let req = banco
+        .transaction_with_str_and_mode(loja, modo)
+        .and_then(|t| t.object_store(loja))
+        .and_then(|s| operacao(&s))
+        .map_err(|_| i18n::t_args("idb-falha-loja", &fluent_args!["loja" => loja]))?;
+    let resultado = concluir(&req).await;
+    banco.close();
+    resultado
+}
+
+pub async fn ler<T: DeserializeOwned>(loja: &str, chave: &str) -> Result<Option<T>, String> {
+    let valor = executar(loja, IdbTransactionMode::Readonly, |s| s.get(&chave.into())).await?;
+    match valor.as_string() {
+        Some(json) => serde_json::from_str(&json).map(Some).map_err(|e| e.to_string()),
+        None => Ok(None),
+    }
+}
+
+pub async fn gravar<T: Serialize>(loja: &str, chave: &str, valor: &T) -> Result<(), String> {
+    let json = serde_json::to_string(valor).map_err(|e| e.to_string())?;
+    executar(loja, IdbTransactionMode::Readwrite, |s| s.put_with_key(&json.into(), &chave.into())).await?;
+    Ok(())
+}
+
+/// Lê e grava o valor numa só transação, para que duas alterações próximas não partam da mesma cópia.
+///
+/// A gravação sai no próprio `onsuccess` da leitura: depois de um `await`, a transação já pode ter terminado.
+pub async fn atualizar<T, F>(loja: &str, chave: &str, alterar: F) -> Result<T, String>
+where
+    T: Serialize + DeserializeOwned + 'static,
+    F: FnOnce(Option<T>) -> T + 'static,
+{
+    let banco = abrir().await?;
+    let falha_loja = || i18n::t_args("idb-falha-loja", &fluent_args!["loja" => loja]);
+    let transacao = banco.transaction_with_str_and_mode(loja, IdbTransactionMode::Readwrite).map_err(|_| falha_loja())?;
+    let objetos = transacao.object_store(loja).map_err(|_| falha_loja())?;
+    let leitura = objetos.get(&chave.into()).map_err(|_| falha_loja())?;
+
+    let novo = Rc::new(RefCell::new(None::<T>));
+    let gravado = novo.clone();
+    let lido = leitura.clone();
+    let chave = chave.to_string();
+    leitura.set_onsuccess(Some(
+        Closure::once_into_js(move |_: JsValue| {
+            let atual = lido.result().ok().and_then(|v| v.as_string()).and_then(|json| serde_json::from_str(&json).ok());
+            let valor = alterar(atual);
+            if let Ok(json) = serde_json::to_string(&valor) {
+                let _ = objetos.put_with_key(&json.into(), &chave.into());
+            }
+            *gravado.borrow_mut() = Some(valor);
+        })
+        .unchecked_ref(),
+    ));
+
+    let resultado = terminar(&transacao).await;
+    banco.close();
+    resultado?;
+    novo.take().ok_or_else(|| i18n::t("idb-falha"))
+}
+
+async fn terminar(transacao: &IdbTransaction) -> Result<(), String> {
+    let promessa = Promise::new(&mut |resolve, reject| {
+        transacao.set_oncomplete(Some(
+            Closure::once_into_js(move |_: JsValue| {
+                let _ = resolve.call0(&JsValue::NULL);
+            })
+            .unchecked_ref(),
+        ));
+        // Um erro numa requisição aborta a transação, então `onabort` cobre os dois casos.
+        transacao.set_onabort(Some(
+            Closure::once_into_js(move |_: JsValue| {
+                let _ = reject.call0(&JsValue::NULL);
+            })
+            .unchecked_ref(),
+        ));
+    });
+    JsFuture::from(promessa).await.map(|_| ()).map_err(|_| i18n::t("idb-falha"))
+}
+
+pub async fn limpar(loja: &str) -> Result<(), String> {
+    executar(loja, IdbTransactionMode::Readwrite, |s| s.clear()).await?;
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use crate::components::validar_item;
+use crate::csv::Planilha;
+use crate::dinheiro::Dinheiro;
+use crate::error::ErroCampo;
+use crate::i18n::{self, fluent_args};
+use crate::models::{Item, NovoItem};
+
+const CABECALHOS_NOME: [&str; 5] = ["nome", "name", "produto", "item", "descricao"];
+const CABECALHOS_PRECO: [&str; 4] = ["preco", "price", "valor", "preco unitario"];
+
+/// Quais colunas da planilha viram `nome` e `preco`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mapeamento {
+    pub nome: usize,
+    pub preco: usize,
+    /// A primeira linha traz os nomes das colunas e não é importada.
+    pub cabecalho: bool,
+}
+
+impl Mapeamento {
+    /// Procura cabeçalhos conhecidos na primeira linha; sem eles, usa as duas primeiras colunas.
+    pub fn detectar(planilha: &Planilha) -> Self {
+        let primeira: Vec<String> = planilha
+            .linhas
+            .first()
+            .map(|(_, l)| l.iter().map(|c| normalizar_cabecalho(c)).collect())
+            .unwrap_or_default();
+        let achar = |nomes: &[&str]| primeira.iter().position(|c| nomes.contains(&c.as_str()));
+
+        match (achar(&CABECALHOS_NOME), achar(&CABECALHOS_PRECO)) {
+            (None, None) => Self {
+                nome: 0,
+                preco: 1,
+                cabecalho: false,
+            },
+            (nome, preco) => {
+                let nome = nome.unwrap_or(if preco == Some(0) { 1 } else { 0 });
+                let preco = preco.unwrap_or(if nome == 1 { 0 } else { 1 });
+                Self {
+                    nome,
+                    preco,
+                    cabecalho: true,
+                }
+            }
+        }
+    }
+}
+
+/// Títulos das colunas para o mapeamento: o cabeçalho ou "Coluna N".
+pub fn colunas(planilha: &Planilha, mapeamento: &Mapeamento) -> Vec<String> {
+    let largura = planilha.linhas.iter().map(|(_, l)| l.len()).max().unwrap_or(0);
+    let cabecalho = planilha.linhas.first().filter(|_| mapeamento.cabecalho);
+    (0..largura)
+        .map(|i| match cabecalho.and_then(|(_, l)| l.get(i)).filter(|c| !c.is_empty()) {
+            Some(titulo) => titulo.clone(),
+            None => i18n::t_args("importar-coluna", &fluent_args!["n" => i + 1]),
+        })
+        .collect()
+}
+
+/// Uma linha do arquivo pronta para a pré-visualização.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinhaImportacao {
+    pub numero: usize,
+    pub nome: String,
+    pub preco: String,
+    pub resultado: Result<NovoItem, Vec<ErroCampo>>,
+}
+
+pub fn validar_linhas(planilha: &Planilha, mapeamento: &Mapeamento) -> Vec<LinhaImportacao> {
+    let dados = planilha.linhas.get(mapeamento.cabecalho as usize..).unwrap_or_default();
+    let precos: Vec<&str> = dados
+        .iter()
+        .filter_map(|(_, l)| l.get(mapeamento.preco).map(String::as_str))
+        .collect();
+    // Só a coluna de preços decide o separador: `;` como delimitador não implica vírgula decimal.
+    let decimal = if usa_virgula_decimal(&precos) { ',' } else { '.' };
+
+    dados
+        .iter()
+        .map(|(numero, celulas)| {
+            let celula = |i: usize| celulas.get(i).cloned().unwrap_or_default();
+            let nome = celula(mapeamento.nome);
+            let preco = celula(mapeamento.preco);
+            let resultado = validar_item(&nome, &preco, |texto| Dinheiro::ler_com_decimal(texto, decimal));
+            LinhaImportacao {
+                numero: *numero,
+                nome,
+                preco,
+                resultado,
+            }
+        })
+        .collect()
+}
+
+/// Verdadeiro se a maioria dos preços que indicam o separador usa vírgula decimal.
+///
+/// Indicam o separador os que têm os dois (`1.234,56`) ou um só seguido de uma ou duas casas (`9,5`);
+/// `1,000` pode ser milhar e não conta.
+pub fn usa_virgula_decimal(precos: &[&str]) -> bool {
+    let decimal = |p: &str| match (p.rfind(','), p.rfind('.')) {
+        (Some(virgula), Some(ponto)) => Some(if virgula > ponto { ',' } else { '.' }),
+        (Some(i), None) | (None, Some(i)) => (1..=2).contains(&(p.trim_end().len() - i - 1)).then(|| p.as_bytes()[i] as char),
+        (None, None) => None,
+    };
+    let (virgulas, pontos) = precos.iter().filter_map(|p| decimal(p)).fold((0, 0), |(v, p), d| if d == ',' { (v + 1, p) } else { (v, p + 1) });
+    virgulas > pontos
+}
+
+/// Resultado final da importação.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct RelatorioImportacao {
+    pub criados: Vec<Item>,
+    /// Linha do arquivo, nome e motivo de cada falha.
+    pub falhas: Vec<(usize, String, String)>,
+    /// Linhas com erro de validação, que não foram enviadas.
+    pub ignoradas: usize,
+}
+
+impl RelatorioImportacao {
+    pub fn resumo(&self) -> String {
+        let total = self.criados.len() + self.falhas.len();
+        let args = fluent_args!["criados" => self.criados.len(), "total" => total, "falhas" => self.falhas.len()];
+        let mut resumo = i18n::t_args(if self.falhas.is_empty() { "importar-resumo" } else { "importar-resumo-falhas" }, &args);
+        if self.ignoradas > 0 {
+            resumo.push(' ');
+            resumo.push_str(&i18n::t_args("importar-ignoradas", &fluent_args!["n" => self.ignoradas]));
+        }
+        resumo
+    }
+}
+
+fn normalizar_cabecalho(texto: &str) -> String {
+    texto
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' => 'a',
+            'é' | 'ê' => 'e',
+            'í' => 'i',
+            'ó' | 'ô' | 'õ' => 'o',
+            'ú' => 'u',
+            'ç' => 'c',
+            '_' => ' ',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::csv;
+
+    #[test]
+    fn detecta_cabecalho_em_qualquer_ordem() {
+        let planilha = csv::ler("Código;Preço;Descrição\n1;10;Café");
+        assert_eq!(
+            Mapeamento::detectar(&planilha),
+            Mapeamento {
+                nome: 2,
+                preco: 1,
+                cabecalho: true,
+            }
+        );
+        assert_eq!(colunas(&planilha, &Mapeamento::detectar(&planilha)), vec!["Código", "Preço", "Descrição"]);
+    }
+
+    #[test]
+    fn sem_cabecalho_usa_as_duas_primeiras_colunas() {
+        let planilha = csv::ler("Café,19.90\nChá,9.5");
+        let mapeamento = Mapeamento::detectar(&planilha);
+
+        assert!(!mapeamento.cabecalho);
+        assert_eq!(validar_linhas(&planilha, &mapeamento).len(), 2);
+        assert_eq!(colunas(&planilha, &mapeamento), vec!["Coluna 1", "Coluna 2"]);
+    }
+
+    #[test]
+    fn precos_em_formato_brasileiro() {
+        let planilha = csv::ler("nome;preco\nCafé;R$ 1.234,56\nChá;9,5\nMel;30");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+
+        let precos: Vec<i64> = linhas.iter().map(|l| l.resultado.as_ref().unwrap().preco.centavos()).collect();
+        assert_eq!(precos, vec![123_456, 950, 3000]);
+        assert_eq!(linhas[0].numero, 2);
+    }
+
+    #[test]
+    fn virgula_decimal_detectada_mesmo_com_outro_delimitador() {
+        let planilha = csv::ler("nome\tpreco\nCafé\t19,90\nPão\t1.000,00");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+        assert_eq!(linhas[1].resultado.as_ref().unwrap().preco.centavos(), 100_000);
+
+        let planilha = csv::ler("nome,preco\nCafé,\"1,234.50\"");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+        assert_eq!(linhas[0].resultado.as_ref().unwrap().preco.centavos(), 123_450);
+    }
+
+    #[test]
+    fn ponto_decimal_com_delimitador_ponto_e_virgula() {
+        let planilha = csv::ler("nome;preco\nCafé;19.90\nChá;R$ 1,234.50");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+
+        let precos: Vec<i64> = linhas.iter().map(|l| l.resultado.as_ref().unwrap().preco.centavos()).collect();
+        assert_eq!(precos, vec![1990, 123_450]);
+    }
+
+    #[test]
+    fn virgula_decimal_com_delimitador_virgula() {
+        let planilha = csv::ler("nome,preco\nCafé,\"19,90\"\nChá,\"1.234,5\"");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+
+        let precos: Vec<i64> = linhas.iter().map(|l| l.resultado.as_ref().unwrap().preco.centavos()).collect();
+        assert_eq!(precos, vec![1990, 123_450]);
+    }
+
+    #[test]
+    fn mais_de_duas_casas_decimais_e_rejeitado() {
+        let planilha = csv::ler("nome,preco\nCafé,\"10,125\"\nChá,\"9,50\"");
+        assert!(validar_linhas(&planilha, &Mapeamento::detectar(&planilha))[0].resultado.is_err());
+
+        let planilha = csv::ler("nome,preco\nCafé,0.125\nChá,9");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+        assert!(linhas[0].resultado.is_err());
+        assert_eq!(linhas[1].resultado.as_ref().unwrap().preco.centavos(), 900);
+
+        assert_eq!(Dinheiro::ler_com_decimal("0,125", ','), None);
+        assert_eq!(Dinheiro::ler_com_decimal("1.5", ','), None);
+        assert_eq!(Dinheiro::ler_com_decimal("-2,50", ','), Some(Dinheiro::de_centavos(-250)));
+    }
+
+    #[test]
+    fn milhar_com_virgula_nao_muda_o_separador_da_coluna() {
+        let planilha = csv::ler("nome,preco\nCafé,\"1,000\"\nChá,9.50\nMel,12.00");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+
+        let precos: Vec<i64> = linhas.iter().map(|l| l.resultado.as_ref().unwrap().preco.centavos()).collect();
+        assert_eq!(precos, vec![100_000, 950, 1200]);
+    }
+
+    #[test]
+    fn precos_com_simbolo_de_outras_moedas() {
+        let planilha = csv::ler("nome;preco\nCafé;$9.50\nChá;US$ 1,234.50\nMel;€12");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+        let precos: Vec<i64> = linhas.iter().map(|l| l.resultado.as_ref().unwrap().preco.centavos()).collect();
+        assert_eq!(precos, vec![950, 123_450, 1200]);
+
+        let planilha = csv::ler("nome;preco\nCafé;€9,50");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+        assert_eq!(linhas[0].resultado.as_ref().unwrap().preco.centavos(), 950);
+    }
+
+    #[test]
+    fn erros_por_linha() {
+        let planilha = csv::ler("nome;preco\n;10\nChá;abc\nMel;-1\nPão");
+        let linhas = validar_linhas(&planilha, &Mapeamento::detectar(&planilha));
+
+        let erros: Vec<Vec<&str>> = linhas
+            .iter()
+            .map(|l| match &l.resultado {
+                Ok(_) => Vec::new(),
+                Err(erros) => erros.iter().map(|e| e.mensagem.as_str()).collect(),
+            })
+            .collect();
+        assert_eq!(
+            erros,
+            vec![
+                vec!["Informe o nome do produto"],
+                vec!["Preço inválido"],
+                vec!["Preço inválido"],
+                vec!["Informe o preço"],
+            ]
+        );
+    }
+}
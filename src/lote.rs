@@ -0,0 +1,118 @@
+use crate::dinheiro::Dinheiro;
+use crate::i18n::{self, fluent_args};
+use crate::models::Item;
+
+/// Alteração de preço aplicada a vários itens de uma vez.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AjustePreco {
+    /// Soma o valor (negativo para reduzir).
+    Valor(Dinheiro),
+    /// Aplica o percentual, por exemplo `10.0` para +10% ou `-5.0` para -5%.
+    Percentual(f64),
+}
+
+impl AjustePreco {
+    /// Novo preço arredondado em centavos, ou erro se ficaria negativo.
+    pub fn aplicar(&self, preco: Dinheiro) -> Result<Dinheiro, String> {
+        let novo = match self {
+            AjustePreco::Valor(v) => preco + *v,
+            AjustePreco::Percentual(p) => preco.mais_percentual(*p),
+        };
+        if novo.negativo() {
+            return Err(i18n::t_args("lote-preco-negativo", &fluent_args!["valor" => novo.to_string()]));
+        }
+        Ok(novo)
+    }
+
+    /// Interpreta o texto digitado: o valor no formato do idioma atual, o percentual com vírgula ou ponto.
+    pub fn parse(texto: &str, percentual: bool) -> Option<Self> {
+        if percentual {
+            let valor: f64 = texto.trim().replace(',', ".").parse().ok()?;
+            (valor.is_finite() && valor != 0.0).then_some(AjustePreco::Percentual(valor))
+        } else {
+            let valor = Dinheiro::ler(texto, i18n::atual())?;
+            (valor != Dinheiro::ZERO).then_some(AjustePreco::Valor(valor))
+        }
+    }
+}
+
+/// Resultado de uma operação em lote: o que deu certo e o motivo de cada falha.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ResultadoLote {
+    pub descricao: String,
+    pub sucessos: Vec<Item>,
+    pub falhas: Vec<(Item, String)>,
+}
+
+impl ResultadoLote {
+    pub fn new(descricao: impl Into<String>) -> Self {
+        Self {
+            descricao: descricao.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.sucessos.len() + self.falhas.len()
+    }
+
+    pub fn resumo(&self) -> String {
+        let args = fluent_args![
+            "descricao" => self.descricao.as_str(),
+            "feitos" => self.sucessos.len(),
+            "total" => self.total(),
+            "falhas" => self.falhas.len(),
+        ];
+        i18n::t_args(if self.falhas.is_empty() { "lote-resumo" } else { "lote-resumo-falhas" }, &args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reais(valor: f64) -> Dinheiro {
+        Dinheiro::de_decimal(valor)
+    }
+
+    #[test]
+    fn ajuste_por_valor_e_percentual() {
+        assert_eq!(AjustePreco::Valor(reais(2.5)).aplicar(reais(10.0)), Ok(reais(12.5)));
+        assert_eq!(AjustePreco::Percentual(10.0).aplicar(reais(19.9)), Ok(reais(21.89)));
+        assert_eq!(AjustePreco::Percentual(-50.0).aplicar(reais(10.0)), Ok(reais(5.0)));
+        assert_eq!(
+            AjustePreco::Valor(reais(-20.0)).aplicar(reais(10.0)),
+            Err("o preço ficaria negativo (-R$ 10,00)".into())
+        );
+    }
+
+    #[test]
+    fn ajuste_por_valor_nao_acumula_erro_de_ponto_flutuante() {
+        let preco = AjustePreco::Valor(reais(0.1)).aplicar(reais(0.2)).unwrap();
+        assert_eq!(preco.centavos(), 30);
+    }
+
+    #[test]
+    fn parse_aceita_virgula_e_rejeita_zero() {
+        assert_eq!(AjustePreco::parse(" -1,50 ", false), Some(AjustePreco::Valor(reais(-1.5))));
+        assert_eq!(AjustePreco::parse("15", true), Some(AjustePreco::Percentual(15.0)));
+        assert_eq!(AjustePreco::parse("0", true), None);
+        assert_eq!(AjustePreco::parse("abc", false), None);
+    }
+
+    #[test]
+    fn resumo_conta_as_falhas() {
+        let item = Item {
+            id: 1,
+            nome: "Café".into(),
+            preco: reais(1.0),
+            ..Item::default()
+        };
+        let mut resultado = ResultadoLote::new("Remoção");
+        resultado.sucessos.push(item.clone());
+        assert_eq!(resultado.resumo(), "Remoção: 1 de 1 itens concluídos.");
+
+        resultado.falhas.push((item, "item não encontrado".into()));
+        assert_eq!(resultado.resumo(), "Remoção: 1 de 2 itens concluídos, 1 com falha.");
+    }
+}
@@ -1,3 +1,4 @@
 fn main() {
+    rust_app_sample::offline::registrar_service_worker();
     yew::Renderer::<rust_app_sample::App>::new().render();
 }
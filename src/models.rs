@@ -0,0 +1,155 @@
+use crate::dinheiro::Dinheiro;
+
+fn ativo_padrao() -> bool {
+    true
+}
+
+/// Os campos além de `id`, `nome` e `preco` têm valor padrão, para aceitar versões antigas da API.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Item {
+    pub id: i32,
+    pub nome: String,
+    pub preco: Dinheiro,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categoria: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    /// Quantidade em estoque; `None` quando a API não informa.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estoque: Option<i32>,
+    #[serde(default = "ativo_padrao")]
+    pub ativo: bool,
+    /// Data e hora em ISO 8601 (UTC), preenchidas pela API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub criado_em: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atualizado_em: Option<String>,
+}
+
+impl Item {
+    /// Item incluído na lista antes da resposta da API, com id negativo provisório.
+    pub fn provisorio(&self) -> bool {
+        self.id < 0
+    }
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        NovoItem::default().com_id(0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct NovoItem {
+    pub nome: String,
+    pub preco: Dinheiro,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub categoria: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estoque: Option<i32>,
+    #[serde(default = "ativo_padrao")]
+    pub ativo: bool,
+}
+
+impl NovoItem {
+    /// O item como ficará na lista, antes ou no lugar da resposta da API.
+    pub fn com_id(&self, id: i32) -> Item {
+        Item {
+            id,
+            nome: self.nome.clone(),
+            preco: self.preco,
+            descricao: self.descricao.clone(),
+            categoria: self.categoria.clone(),
+            sku: self.sku.clone(),
+            estoque: self.estoque,
+            ativo: self.ativo,
+            criado_em: None,
+            atualizado_em: None,
+        }
+    }
+}
+
+impl Default for NovoItem {
+    fn default() -> Self {
+        Self {
+            nome: String::new(),
+            preco: Dinheiro::ZERO,
+            descricao: None,
+            categoria: None,
+            sku: None,
+            estoque: None,
+            ativo: true,
+        }
+    }
+}
+
+impl From<&Item> for NovoItem {
+    fn from(item: &Item) -> Self {
+        Self {
+            nome: item.nome.clone(),
+            preco: item.preco,
+            descricao: item.descricao.clone(),
+            categoria: item.categoria.clone(),
+            sku: item.sku.clone(),
+            estoque: item.estoque,
+            ativo: item.ativo,
+        }
+    }
+}
+
+/// Entrada de `GET /itens/:id/historico`: o preço que passou a valer em `em`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AlteracaoPreco {
+    pub em: String,
+    pub preco: Dinheiro,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, Default)]
+pub struct ListarResponse {
+    pub itens: Vec<Item>,
+    pub total: i64,
+    pub pagina: i64,
+    pub por_pagina: i64,
+    pub total_paginas: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_de_api_antiga_recebe_os_valores_padrao() {
+        let item: Item = serde_json::from_str(r#"{"id": 1, "nome": "Café", "preco": 19.9}"#).unwrap();
+
+        assert_eq!(
+            item,
+            Item {
+                id: 1,
+                nome: "Café".into(),
+                preco: Dinheiro::de_centavos(1990),
+                ..Item::default()
+            }
+        );
+        assert!(item.ativo);
+    }
+
+    #[test]
+    fn campos_vazios_nao_sao_enviados() {
+        let novo = NovoItem {
+            nome: "Café".into(),
+            categoria: Some("Mercearia".into()),
+            estoque: Some(0),
+            ..NovoItem::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&novo).unwrap(),
+            serde_json::json!({ "nome": "Café", "preco": 0.0, "categoria": "Mercearia", "estoque": 0, "ativo": true })
+        );
+    }
+}
@@ -0,0 +1,486 @@
+//! Uso sem conexão: cópia das páginas já buscadas e fila das alterações feitas enquanto a API
+//! não responde, reenviada quando a conexão volta.
+
+use crate::api::{ItensClient, ListarParams};
+use crate::error::ApiError;
+use crate::i18n::{self, fluent_args};
+use crate::idb;
+use crate::models::{Item, ListarResponse, NovoItem};
+
+const CHAVE_FILA: &str = "pendentes";
+
+/// Alteração guardada na fila. `original` é o item como o usuário o viu, para detectar conflitos.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "tipo", rename_all = "snake_case")]
+pub enum Operacao {
+    Criar { provisorio: i32, novo: NovoItem },
+    Atualizar { original: Item, novo: NovoItem },
+    Remover { original: Item },
+}
+
+impl Operacao {
+    pub fn descricao(&self) -> String {
+        match self {
+            Operacao::Criar { novo, .. } => i18n::t_args("offline-inclusao", &fluent_args!["nome" => novo.nome.as_str()]),
+            Operacao::Atualizar { original, .. } => {
+                i18n::t_args("offline-alteracao", &fluent_args!["nome" => original.nome.as_str()])
+            }
+            Operacao::Remover { original } => i18n::t_args("offline-remocao", &fluent_args!["nome" => original.nome.as_str()]),
+        }
+    }
+
+    /// Item a que a operação se refere: o id provisório na inclusão, o id da API nas demais.
+    /// A fila tem no máximo uma operação por chave.
+    pub fn chave(&self) -> i32 {
+        match self {
+            Operacao::Criar { provisorio, .. } => *provisorio,
+            Operacao::Atualizar { original, .. } | Operacao::Remover { original } => original.id,
+        }
+    }
+
+    /// A mesma intenção, agora a partir de `item`, o estado que a API devolveu.
+    fn a_partir_de(self, item: Item) -> Self {
+        match self {
+            Operacao::Criar { novo, .. } | Operacao::Atualizar { novo, .. } => Operacao::Atualizar { original: item, novo },
+            Operacao::Remover { .. } => Operacao::Remover { original: item },
+        }
+    }
+
+    /// Motivo para não aplicar a operação, dado o item como está agora na API (`None` se foi removido).
+    pub fn conflito(&self, atual: Option<&Item>) -> Option<String> {
+        let alterado = |atual: &Item| {
+            let args = fluent_args!["nome" => atual.nome.as_str(), "preco" => atual.preco.to_string()];
+            i18n::t_args("offline-alterado-por-outro", &args)
+        };
+        match (self, atual) {
+            (Operacao::Criar { .. }, _) | (Operacao::Remover { .. }, None) => None,
+            (Operacao::Atualizar { .. }, None) => Some(i18n::t("offline-removido-por-outro")),
+            (Operacao::Atualizar { original, novo }, Some(atual)) => {
+                (atual != original && NovoItem::from(atual) != *novo).then(|| alterado(atual))
+            }
+            (Operacao::Remover { original }, Some(atual)) => (atual != original).then(|| alterado(atual)),
+        }
+    }
+}
+
+/// Operação da fila que não foi aplicada, com o motivo.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conflito {
+    pub operacao: Operacao,
+    pub motivo: String,
+}
+
+impl Conflito {
+    pub fn mensagem(&self) -> String {
+        format!("{}: {}", self.operacao.descricao(), self.motivo)
+    }
+}
+
+/// Resultado de uma tentativa de enviar a fila.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Sincronizacao {
+    pub enviadas: usize,
+    pub conflitos: Vec<Conflito>,
+    /// Operações que continuam na fila, à espera da conexão.
+    pub pendentes: usize,
+}
+
+/// Desfecho de uma alteração: aplicada na API ou guardada na fila (com o tamanho da fila).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Envio<T> {
+    Feito(T),
+    NaFila(usize),
+}
+
+/// Chave da página no cache: a consulta normalizada, em JSON.
+pub fn chave_pagina(consulta: &ListarParams) -> String {
+    serde_json::to_string(&consulta.clone().normalizado()).unwrap_or_default()
+}
+
+/// Acrescenta a operação à fila, juntando-a às pendentes do mesmo item.
+pub fn enfileirar(fila: &[Operacao], operacao: Operacao) -> Vec<Operacao> {
+    let mut fila = fila.to_vec();
+    match operacao {
+        Operacao::Atualizar { original, novo } if original.provisorio() => {
+            for op in fila.iter_mut() {
+                match op {
+                    Operacao::Criar { provisorio, novo: criado } if *provisorio == original.id => *criado = novo.clone(),
+                    _ => {}
+                }
+            }
+        }
+        Operacao::Remover { original } if original.provisorio() => {
+            fila.retain(|op| !matches!(op, Operacao::Criar { provisorio, .. } if *provisorio == original.id));
+        }
+        Operacao::Atualizar { original, novo } => {
+            let existente = fila
+                .iter_mut()
+                .find(|op| matches!(op, Operacao::Atualizar { original: o, .. } if o.id == original.id));
+            match existente {
+                Some(Operacao::Atualizar { novo: pendente, .. }) => *pendente = novo,
+                _ => fila.push(Operacao::Atualizar { original, novo }),
+            }
+        }
+        Operacao::Remover { original } => {
+            // A remoção compara com o item como estava antes da primeira alteração pendente.
+            let original = fila
+                .iter()
+                .find_map(|op| match op {
+                    Operacao::Atualizar { original: o, .. } if o.id == original.id => Some(o.clone()),
+                    _ => None,
+                })
+                .unwrap_or(original);
+            fila.retain(|op| !matches!(op, Operacao::Atualizar { original: o, .. } if o.id == original.id));
+            fila.push(Operacao::Remover { original });
+        }
+        criar => fila.push(criar),
+    }
+    fila
+}
+
+/// Operação já tratada por uma sincronização. `devolvido` é o item que a API devolveu ao incluir
+/// ou alterar; conflitos e remoções não têm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Processada {
+    pub operacao: Operacao,
+    pub devolvido: Option<Item>,
+}
+
+/// Tira da fila atual as operações processadas. As que o usuário mudou durante o envio continuam,
+/// mas passam a partir do item devolvido pela API, para não incluir duas vezes nem gerar conflito
+/// com a própria alteração.
+pub fn descontar(fila: &[Operacao], processadas: &[Processada]) -> Vec<Operacao> {
+    let mut restantes = fila.to_vec();
+    for Processada { operacao, devolvido } in processadas {
+        let posicao = restantes.iter().position(|op| op.chave() == operacao.chave());
+        match (posicao, devolvido) {
+            (Some(i), _) if restantes[i] == *operacao => {
+                restantes.remove(i);
+            }
+            (Some(i), Some(item)) => restantes[i] = restantes[i].clone().a_partir_de(item.clone()),
+            // O item provisório foi removido enquanto a inclusão era enviada.
+            (None, Some(item)) if matches!(operacao, Operacao::Criar { .. }) => {
+                restantes.push(Operacao::Remover { original: item.clone() });
+            }
+            _ => {}
+        }
+    }
+    restantes
+}
+
+/// Aplica as alterações pendentes a uma página salva, para que ela mostre o que o usuário já fez.
+pub fn aplicar_pendentes(mut pagina: ListarResponse, fila: &[Operacao]) -> ListarResponse {
+    for operacao in fila {
+        match operacao {
+            Operacao::Criar { provisorio, novo } if pagina.pagina <= 1 => {
+                pagina.itens.insert(0, novo.com_id(*provisorio));
+                pagina.total += 1;
+            }
+            Operacao::Criar { .. } => pagina.total += 1,
+            Operacao::Atualizar { original, novo } => {
+                if let Some(item) = pagina.itens.iter_mut().find(|i| i.id == original.id) {
+                    *item = novo.com_id(item.id);
+                }
+            }
+            Operacao::Remover { original } => {
+                let antes = pagina.itens.len();
+                pagina.itens.retain(|i| i.id != original.id);
+                if pagina.itens.len() < antes {
+                    pagina.total = (pagina.total - 1).max(0);
+                }
+            }
+        }
+    }
+    if pagina.por_pagina > 0 {
+        pagina.itens.truncate(pagina.por_pagina as usize);
+    }
+    pagina
+}
+
+pub async fn fila() -> Vec<Operacao> {
+    idb::ler(idb::FILA, CHAVE_FILA).await.ok().flatten().unwrap_or_default()
+}
+
+pub async fn guardar_pagina(consulta: &ListarParams, pagina: &ListarResponse) {
+    let _ = idb::gravar(idb::PAGINAS, &chave_pagina(consulta), pagina).await;
+}
+
+/// Página salva da consulta, com as alterações pendentes aplicadas.
+pub async fn pagina_salva(consulta: &ListarParams) -> Option<ListarResponse> {
+    let pagina = idb::ler(idb::PAGINAS, &chave_pagina(consulta)).await.ok().flatten()?;
+    Some(aplicar_pendentes(pagina, &fila().await))
+}
+
+/// Apaga as páginas salvas e a fila.
+pub async fn limpar() {
+    limpar_paginas().await;
+    let _ = idb::limpar(idb::FILA).await;
+}
+
+/// Apaga só as páginas salvas; a fila continua esperando para ser enviada.
+pub async fn limpar_paginas() {
+    let _ = idb::limpar(idb::PAGINAS).await;
+}
+
+/// Sem conexão, guarda a operação na fila; qualquer outro resultado é devolvido como veio.
+async fn ou_na_fila<T>(resultado: Result<T, ApiError>, operacao: Operacao) -> Result<Envio<T>, ApiError> {
+    match resultado {
+        Err(ApiError::Rede(msg)) => {
+            let fila = idb::atualizar(idb::FILA, CHAVE_FILA, move |fila: Option<Vec<Operacao>>| enfileirar(&fila.unwrap_or_default(), operacao)).await;
+            match fila {
+                Ok(fila) => Ok(Envio::NaFila(fila.len())),
+                Err(_) => Err(ApiError::Rede(msg)),
+            }
+        }
+        outro => outro.map(Envio::Feito),
+    }
+}
+
+pub async fn criar(client: &ItensClient, provisorio: i32, novo: &NovoItem) -> Result<Envio<Item>, ApiError> {
+    let resultado = client.create(novo).await;
+    ou_na_fila(resultado, Operacao::Criar { provisorio, novo: novo.clone() }).await
+}
+
+pub async fn atualizar(client: &ItensClient, original: &Item, novo: &NovoItem) -> Result<Envio<Item>, ApiError> {
+    let resultado = client.update(original.id, novo).await;
+    ou_na_fila(resultado, Operacao::Atualizar { original: original.clone(), novo: novo.clone() }).await
+}
+
+pub async fn remover(client: &ItensClient, original: &Item) -> Result<Envio<()>, ApiError> {
+    let resultado = client.delete(original.id).await;
+    ou_na_fila(resultado, Operacao::Remover { original: original.clone() }).await
+}
+
+enum Falha {
+    /// Sem conexão ou sem sessão válida: a operação fica na fila para depois.
+    Adiada,
+    Conflito(String),
+}
+
+impl From<ApiError> for Falha {
+    fn from(e: ApiError) -> Self {
+        match e {
+            ApiError::Rede(_) => Falha::Adiada,
+            e if e.status() == Some(401) => Falha::Adiada,
+            e if !e.erros_campos().is_empty() => {
+                Falha::Conflito(e.erros_campos().iter().map(|c| c.mensagem.as_str()).collect::<Vec<_>>().join("; "))
+            }
+            e => Falha::Conflito(e.to_string()),
+        }
+    }
+}
+
+async fn item_atual(client: &ItensClient, id: i32) -> Result<Option<Item>, ApiError> {
+    match client.get(id).await {
+        Ok(item) => Ok(Some(item)),
+        Err(e) if e.status() == Some(404) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Envia a operação; devolve o item resultante da inclusão ou da alteração.
+async fn enviar(client: &ItensClient, operacao: &Operacao) -> Result<Option<Item>, Falha> {
+    match operacao {
+        Operacao::Criar { novo, .. } => Ok(Some(client.create(novo).await?)),
+        Operacao::Atualizar { original, novo } => {
+            let atual = item_atual(client, original.id).await?;
+            if let Some(motivo) = operacao.conflito(atual.as_ref()) {
+                return Err(Falha::Conflito(motivo));
+            }
+            Ok(Some(client.update(original.id, novo).await?))
+        }
+        Operacao::Remover { original } => {
+            let atual = item_atual(client, original.id).await?;
+            if let Some(motivo) = operacao.conflito(atual.as_ref()) {
+                return Err(Falha::Conflito(motivo));
+            }
+            if atual.is_some() {
+                client.delete(original.id).await?;
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Envia a fila na ordem em que foi montada. Para na primeira falha de rede (ou 401), deixando o
+/// resto para a próxima tentativa; operações em conflito saem da fila e são relatadas.
+pub async fn sincronizar(client: &ItensClient) -> Sincronizacao {
+    let fila = fila().await;
+    let mut resultado = Sincronizacao::default();
+    let mut processadas = Vec::new();
+
+    for operacao in &fila {
+        let devolvido = match enviar(client, operacao).await {
+            Ok(devolvido) => {
+                resultado.enviadas += 1;
+                devolvido
+            }
+            Err(Falha::Adiada) => break,
+            Err(Falha::Conflito(motivo)) => {
+                resultado.conflitos.push(Conflito {
+                    operacao: operacao.clone(),
+                    motivo,
+                });
+                None
+            }
+        };
+        processadas.push(Processada {
+            operacao: operacao.clone(),
+            devolvido,
+        });
+    }
+
+    let restantes = if processadas.is_empty() {
+        fila
+    } else {
+        // A fila pode ter mudado durante o envio: relê e tira só o que foi processado.
+        match idb::atualizar(idb::FILA, CHAVE_FILA, move |fila: Option<Vec<Operacao>>| descontar(&fila.unwrap_or_default(), &processadas)).await {
+            Ok(restantes) => restantes,
+            Err(_) => self::fila().await,
+        }
+    };
+    resultado.pendentes = restantes.len();
+    resultado
+}
+
+/// Registra o service worker (`sw.js`), que guarda a aplicação para abrir sem conexão.
+pub fn registrar_service_worker() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let navigator = window.navigator();
+    if !js_sys::Reflect::has(&navigator, &"serviceWorker".into()).unwrap_or(false) {
+        return;
+    }
+    let registro = navigator.service_worker().register("/sw.js");
+    wasm_bindgen_futures::spawn_local(async move {
+        let _ = wasm_bindgen_futures::JsFuture::from(registro).await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dinheiro::Dinheiro;
+
+    fn item(id: i32, nome: &str, preco: f64) -> Item {
+        Item { id, nome: nome.into(), preco: Dinheiro::de_decimal(preco), ..Item::default() }
+    }
+
+    fn novo(nome: &str, preco: f64) -> NovoItem {
+        NovoItem { nome: nome.into(), preco: Dinheiro::de_decimal(preco), ..NovoItem::default() }
+    }
+
+    #[test]
+    fn alteracoes_do_mesmo_item_sao_juntadas() {
+        let original = item(3, "Café", 10.0);
+        let fila = enfileirar(&[], Operacao::Atualizar { original: original.clone(), novo: novo("Café moído", 10.0) });
+        let fila = enfileirar(&fila, Operacao::Atualizar { original: item(3, "Café moído", 10.0), novo: novo("Café moído", 12.0) });
+        assert_eq!(fila, vec![Operacao::Atualizar { original: original.clone(), novo: novo("Café moído", 12.0) }]);
+
+        let fila = enfileirar(&fila, Operacao::Remover { original: item(3, "Café moído", 12.0) });
+        assert_eq!(fila, vec![Operacao::Remover { original }]);
+    }
+
+    #[test]
+    fn item_ainda_nao_enviado_e_alterado_ou_descartado_na_fila() {
+        let fila = enfileirar(&[], Operacao::Criar { provisorio: -1, novo: novo("Chá", 5.0) });
+        let fila = enfileirar(&fila, Operacao::Atualizar { original: item(-1, "Chá", 5.0), novo: novo("Chá verde", 6.0) });
+        assert_eq!(fila, vec![Operacao::Criar { provisorio: -1, novo: novo("Chá verde", 6.0) }]);
+
+        assert!(enfileirar(&fila, Operacao::Remover { original: item(-1, "Chá verde", 6.0) }).is_empty());
+    }
+
+    #[test]
+    fn conflito_quando_outra_pessoa_alterou_ou_removeu() {
+        let original = item(3, "Café", 10.0);
+        let atualizar = Operacao::Atualizar { original: original.clone(), novo: novo("Café moído", 10.0) };
+        let remover = Operacao::Remover { original: original.clone() };
+
+        assert_eq!(atualizar.conflito(Some(&original)), None);
+        assert_eq!(atualizar.conflito(Some(&item(3, "Café moído", 10.0))), None);
+        assert_eq!(atualizar.conflito(None).as_deref(), Some("o item foi removido por outra pessoa"));
+        assert_eq!(
+            atualizar.conflito(Some(&item(3, "Café", 11.0))).as_deref(),
+            Some("o item foi alterado por outra pessoa (agora \"Café\" · R$ 11,00)")
+        );
+
+        assert_eq!(remover.conflito(None), None);
+        assert!(remover.conflito(Some(&item(3, "Café especial", 10.0))).is_some());
+        assert_eq!(
+            Conflito { operacao: remover, motivo: "x".into() }.mensagem(),
+            "Remoção de \"Café\": x"
+        );
+    }
+
+    #[test]
+    fn processadas_saem_da_fila_pela_chave() {
+        let criar = Operacao::Criar { provisorio: -1, novo: novo("Chá", 5.0) };
+        let atualizar = Operacao::Atualizar { original: item(3, "Café", 10.0), novo: novo("Café moído", 10.0) };
+        let remover = Operacao::Remover { original: item(4, "Mel", 20.0) };
+        let processadas = vec![
+            Processada { operacao: criar.clone(), devolvido: Some(item(9, "Chá", 5.0)) },
+            Processada { operacao: atualizar.clone(), devolvido: Some(item(3, "Café moído", 10.0)) },
+        ];
+
+        // Uma remoção incluída no início enquanto as outras eram enviadas não é descartada.
+        let fila = vec![remover.clone(), criar, atualizar];
+        assert_eq!(descontar(&fila, &processadas), vec![remover]);
+    }
+
+    #[test]
+    fn alteradas_durante_o_envio_continuam_a_partir_da_resposta() {
+        let criar = Operacao::Criar { provisorio: -1, novo: novo("Chá", 5.0) };
+        let atualizar = Operacao::Atualizar { original: item(3, "Café", 10.0), novo: novo("Café moído", 10.0) };
+        let processadas = vec![
+            Processada { operacao: criar.clone(), devolvido: Some(item(9, "Chá", 5.0)) },
+            Processada { operacao: atualizar.clone(), devolvido: Some(item(3, "Café moído", 10.0)) },
+        ];
+
+        let fila = enfileirar(&[criar.clone(), atualizar], Operacao::Atualizar { original: item(-1, "Chá", 5.0), novo: novo("Chá verde", 6.0) });
+        let fila = enfileirar(&fila, Operacao::Atualizar { original: item(3, "Café moído", 10.0), novo: novo("Café moído", 12.0) });
+        assert_eq!(
+            descontar(&fila, &processadas),
+            vec![
+                Operacao::Atualizar { original: item(9, "Chá", 5.0), novo: novo("Chá verde", 6.0) },
+                Operacao::Atualizar { original: item(3, "Café moído", 10.0), novo: novo("Café moído", 12.0) },
+            ]
+        );
+
+        // O item provisório removido durante a inclusão é removido da API depois.
+        let fila = enfileirar(&[criar], Operacao::Remover { original: item(-1, "Chá", 5.0) });
+        assert_eq!(
+            descontar(&fila, &processadas[..1]),
+            vec![Operacao::Remover { original: item(9, "Chá", 5.0) }]
+        );
+    }
+
+    #[test]
+    fn pagina_salva_mostra_as_alteracoes_pendentes() {
+        let pagina = ListarResponse {
+            itens: vec![item(1, "A", 1.0), item(2, "B", 2.0), item(3, "C", 3.0)],
+            total: 7,
+            pagina: 1,
+            por_pagina: 3,
+            total_paginas: 3,
+        };
+        let fila = vec![
+            Operacao::Remover { original: item(2, "B", 2.0) },
+            Operacao::Atualizar { original: item(3, "C", 3.0), novo: novo("C2", 4.0) },
+            Operacao::Criar { provisorio: -1, novo: novo("D", 5.0) },
+        ];
+
+        let pagina = aplicar_pendentes(pagina, &fila);
+        assert_eq!(pagina.itens, vec![item(-1, "D", 5.0), item(1, "A", 1.0), item(3, "C2", 4.0)]);
+        assert_eq!(pagina.total, 7);
+    }
+
+    #[test]
+    fn chave_da_pagina_ignora_valores_invalidos() {
+        let consulta = ListarParams::default().com_busca("café");
+        let invalida = ListarParams { ordem: "xyz".into(), ..consulta.clone() };
+
+        assert_eq!(chave_pagina(&consulta), chave_pagina(&invalida));
+        assert_ne!(chave_pagina(&consulta), chave_pagina(&consulta.na_pagina(2)));
+    }
+}
@@ -0,0 +1,165 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::auth::{Permissao, use_autenticacao};
+use crate::components::{ColunaOpcional, Notification, PriceHistoryChart};
+use crate::error::ApiError;
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::models::{AlteracaoPreco, Item};
+use crate::pages::use_client;
+use crate::routes::Route;
+use crate::store::use_items_store;
+
+#[derive(Properties, PartialEq)]
+pub struct DetalheItemProps {
+    pub id: i32,
+}
+
+/// Todos os campos do item, as datas de criação e alteração e o histórico de preços.
+#[function_component(DetalheItem)]
+pub fn detalhe_item(props: &DetalheItemProps) -> Html {
+    let i18n = use_i18n();
+    let store = use_items_store();
+    let client = use_client();
+    let auth = use_autenticacao();
+    let navigator = use_navigator().unwrap();
+
+    let item = use_state(|| None::<Item>);
+    let historico = use_state(|| None::<Result<Vec<AlteracaoPreco>, String>>);
+    let carregando = use_state(|| false);
+    let erro = use_state(|| None::<String>);
+
+    {
+        let store = store.clone();
+        let item = item.clone();
+        let historico = historico.clone();
+        let carregando = carregando.clone();
+        let erro = erro.clone();
+
+        use_effect_with(props.id, move |id| {
+            let id = *id;
+            item.set(None);
+            historico.set(None);
+            erro.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                carregando.set(true);
+
+                // Sem conexão, mostra a cópia que está na lista.
+                let carregado = match client.get(id).await {
+                    Err(ApiError::Rede(msg)) => store.itens.iter().find(|i| i.id == id).cloned().ok_or(ApiError::Rede(msg)),
+                    outro => outro,
+                };
+                match carregado {
+                    Ok(carregado) => {
+                        item.set(Some(carregado));
+                        historico.set(Some(client.historico(id).await.map_err(|e| e.to_string())));
+                    }
+                    Err(e) => erro.set(Some(i18n::t_args("form-erro-carregar", &fluent_args!["erro" => e.to_string()]))),
+                }
+
+                carregando.set(false);
+            });
+            || ()
+        });
+    }
+
+    let voltar = {
+        let consulta = store.consulta.clone();
+        let navigator = navigator.clone();
+        Callback::from(move |_: MouseEvent| {
+            let _ = navigator.push_with_query(&Route::Itens, &consulta);
+        })
+    };
+    let editar = {
+        let id = props.id;
+        Callback::from(move |_: MouseEvent| navigator.push(&Route::Editar { id }))
+    };
+
+    let data = |valor: &Option<String>| match valor {
+        Some(iso) => i18n::data_hora(iso, i18n.idioma),
+        None => "—".to_string(),
+    };
+
+    html! {
+        <section class="section">
+            <div class="container">
+                if let Some(erro) = &*erro {
+                    <Notification tipo="is-danger">{ erro }</Notification>
+                }
+                if *carregando && item.is_none() {
+                    <p>{ i18n.t("carregando") }</p>
+                }
+                if let Some(item) = &*item {
+                    <h1 class="title">{ &item.nome }</h1>
+                    <div class="box">
+                        <table class="table is-fullwidth">
+                            <tbody>
+                                <tr><th>{ i18n.t("coluna-id") }</th><td>{ item.id }</td></tr>
+                                <tr><th>{ i18n.t("coluna-nome") }</th><td>{ &item.nome }</td></tr>
+                                <tr><th>{ i18n.t("coluna-preco") }</th><td>{ item.preco.to_string() }</td></tr>
+                                { for ColunaOpcional::TODAS.iter().map(|c| html! {
+                                    <tr><th>{ c.rotulo() }</th><td>{ c.texto(item) }</td></tr>
+                                })}
+                                <tr><th>{ i18n.t("detalhe-criado-em") }</th><td>{ data(&item.criado_em) }</td></tr>
+                                <tr><th>{ i18n.t("detalhe-atualizado-em") }</th><td>{ data(&item.atualizado_em) }</td></tr>
+                            </tbody>
+                        </table>
+                    </div>
+
+                    <div class="box">
+                        <h2 class="subtitle">{ i18n.t("detalhe-historico") }</h2>
+                        { match &*historico {
+                            None => html! { <p>{ i18n.t("carregando") }</p> },
+                            Some(Err(e)) => html! {
+                                <Notification tipo="is-warning">
+                                    { i18n.t_args("detalhe-historico-indisponivel", &fluent_args!["erro" => e.as_str()]) }
+                                </Notification>
+                            },
+                            Some(Ok(lista)) if lista.is_empty() => html! { <p>{ i18n.t("detalhe-sem-historico") }</p> },
+                            Some(Ok(lista)) => html! {
+                                <>
+                                    <PriceHistoryChart historico={lista.clone()} />
+                                    <table class="table is-fullwidth is-narrow">
+                                        <thead>
+                                            <tr>
+                                                <th>{ i18n.t("detalhe-data") }</th>
+                                                <th class="has-text-right">{ i18n.t("coluna-preco") }</th>
+                                                <th class="has-text-right">{ i18n.t("detalhe-variacao") }</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            { for lista.iter().enumerate().rev().map(|(i, a)| html! {
+                                                <tr>
+                                                    <td>{ i18n::data_hora(&a.em, i18n.idioma) }</td>
+                                                    <td class="has-text-right">{ a.preco.to_string() }</td>
+                                                    <td class="has-text-right">{ variacao(lista, i) }</td>
+                                                </tr>
+                                            })}
+                                        </tbody>
+                                    </table>
+                                </>
+                            },
+                        }}
+                    </div>
+                }
+
+                <div class="buttons">
+                    <button class="button" onclick={voltar}>{ i18n.t("voltar-para-lista") }</button>
+                    if item.is_some() && auth.pode(Permissao::Editar) {
+                        <button class="button is-link" onclick={editar}>{ i18n.t("form-editar") }</button>
+                    }
+                </div>
+            </div>
+        </section>
+    }
+}
+
+/// Diferença para o preço anterior, com sinal; `—` na primeira entrada.
+fn variacao(lista: &[AlteracaoPreco], i: usize) -> String {
+    match i.checked_sub(1).map(|anterior| lista[i].preco - lista[anterior].preco) {
+        Some(diferenca) if !diferenca.negativo() => format!("+{}", diferenca),
+        Some(diferenca) => diferenca.to_string(),
+        None => "—".to_string(),
+    }
+}
@@ -0,0 +1,169 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::components::ItemForm;
+use crate::error::{ApiError, ErroCampo};
+use crate::i18n::{self, fluent_args};
+use crate::models::{Item, NovoItem};
+use crate::offline::{self, Envio};
+use crate::pages::use_client;
+use crate::routes::Route;
+use crate::store::{ItemsAction, use_items_store};
+
+#[derive(Properties, PartialEq)]
+pub struct FormularioItemProps {
+    /// `None` para inclusão; o item é carregado da API ao editar.
+    #[prop_or_default]
+    pub id: Option<i32>,
+}
+
+#[function_component(FormularioItem)]
+pub fn formulario_item(props: &FormularioItemProps) -> Html {
+    let store = use_items_store();
+    let client = use_client();
+    let navigator = use_navigator().unwrap();
+
+    let inicial = use_state(|| None::<NovoItem>);
+    let original = use_state(|| None::<Item>);
+    let carregando = use_state(|| false);
+    let erro = use_state(|| None::<String>);
+    let erros_campos = use_state(Vec::<ErroCampo>::new);
+    let categorias = use_state(Vec::<String>::new);
+
+    {
+        let client = client.clone();
+        let categorias = categorias.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(lista) = client.categorias().await {
+                    categorias.set(lista);
+                }
+            });
+            || ()
+        });
+    }
+
+    {
+        let client = client.clone();
+        let store = store.clone();
+        let inicial = inicial.clone();
+        let original = original.clone();
+        let carregando = carregando.clone();
+        let erro = erro.clone();
+        let erros_campos = erros_campos.clone();
+
+        use_effect_with(props.id, move |id| {
+            inicial.set(None);
+            original.set(None);
+            erro.set(None);
+            erros_campos.set(Vec::new());
+
+            if let Some(id) = *id {
+                wasm_bindgen_futures::spawn_local(async move {
+                    carregando.set(true);
+
+                    // Sem conexão, edita a cópia que está na lista.
+                    let carregado = match client.get(id).await {
+                        Err(ApiError::Rede(msg)) => store.itens.iter().find(|i| i.id == id).cloned().ok_or(ApiError::Rede(msg)),
+                        outro => outro,
+                    };
+                    match carregado {
+                        Ok(item) => {
+                            inicial.set(Some(NovoItem::from(&item)));
+                            original.set(Some(item));
+                        }
+                        Err(e) => erro.set(Some(i18n::t_args("form-erro-carregar", &fluent_args!["erro" => e.to_string()]))),
+                    }
+
+                    carregando.set(false);
+                });
+            }
+            || ()
+        });
+    }
+
+    let ir_para_lista = {
+        let navigator = navigator.clone();
+        let consulta = store.consulta.clone();
+        Callback::from(move |_: ()| {
+            let _ = navigator.push_with_query(&Route::Itens, &consulta);
+        })
+    };
+
+    let salvar = {
+        let editar_id = props.id;
+        let store = store.clone();
+        let original = original.clone();
+        let carregando = carregando.clone();
+        let erro = erro.clone();
+        let erros_campos = erros_campos.clone();
+        let ir_para_lista = ir_para_lista.clone();
+        Callback::from(move |item: NovoItem| {
+            let client = client.clone();
+            let store = store.clone();
+            let original = (*original).clone();
+            let carregando = carregando.clone();
+            let erro = erro.clone();
+            let erros_campos = erros_campos.clone();
+            let ir_para_lista = ir_para_lista.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                carregando.set(true);
+                erro.set(None);
+                erros_campos.set(Vec::new());
+
+                // A lista muda antes da resposta; o formulário continua aberto para mostrar os erros da API.
+                let resultado = match editar_id {
+                    Some(id) => {
+                        let original = original.unwrap_or_else(|| item.com_id(id));
+                        store.dispatch(ItemsAction::Updated(item.com_id(id)));
+                        offline::atualizar(&client, &original, &item).await.map_err(|e| (e, ItemsAction::Updated(original))).map(|envio| match envio {
+                            Envio::Feito(atualizado) => ItemsAction::Updated(atualizado),
+                            Envio::NaFila(pendentes) => ItemsAction::Queued(pendentes),
+                        })
+                    }
+                    None => {
+                        let provisorio = store.id_provisorio();
+                        store.dispatch(ItemsAction::Created(item.com_id(provisorio)));
+                        offline::criar(&client, provisorio, &item).await.map_err(|e| (e, ItemsAction::Deleted(provisorio))).map(|envio| match envio {
+                            Envio::Feito(criado) => ItemsAction::Reconciled { provisorio, item: criado },
+                            Envio::NaFila(pendentes) => ItemsAction::Queued(pendentes),
+                        })
+                    }
+                };
+
+                carregando.set(false);
+
+                match resultado {
+                    Ok(acao) => {
+                        store.dispatch(acao);
+                        ir_para_lista.emit(());
+                    }
+                    Err((e, desfazer)) => {
+                        store.dispatch(desfazer);
+                        let id = if editar_id.is_some() { "form-erro-atualizar" } else { "form-erro-criar" };
+                        erros_campos.set(e.erros_campos().to_vec());
+                        erro.set(Some(i18n::t_args(id, &fluent_args!["erro" => e.to_string()])));
+                    }
+                }
+            });
+        })
+    };
+
+    html! {
+        <section class="section">
+            <div class="container">
+                <ItemForm
+                    id={props.id}
+                    inicial={(*inicial).clone()}
+                    categorias={(*categorias).clone()}
+                    carregando={*carregando}
+                    erro={(*erro).clone().map(AttrValue::from)}
+                    erros={(*erros_campos).clone()}
+                    on_submit={salvar}
+                    on_cancel={ir_para_lista}
+                />
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,320 @@
+use futures::future::join_all;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::components::Notification;
+use crate::csv::{self, Planilha};
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::importacao::{self, LinhaImportacao, Mapeamento, RelatorioImportacao};
+use crate::pages::use_client;
+use crate::routes::Route;
+use crate::store::{ItemsAction, use_items_store};
+
+/// Quantidade de `POST /itens` enviados em paralelo.
+const TAMANHO_LOTE: usize = 5;
+const LIMITE_PREVIA: usize = 100;
+
+#[function_component(ImportacaoItens)]
+pub fn importacao_itens() -> Html {
+    let store = use_items_store();
+    let client = use_client();
+    let i18n = use_i18n();
+    let navigator = use_navigator().unwrap();
+
+    let arquivo = use_state(|| None::<String>);
+    let planilha = use_state(|| None::<Planilha>);
+    let mapeamento = use_state(|| None::<Mapeamento>);
+    let erro = use_state(|| None::<String>);
+    let progresso = use_state(|| None::<(usize, usize)>);
+    let relatorio = use_state(|| None::<RelatorioImportacao>);
+
+    let linhas: Vec<LinhaImportacao> = match (&*planilha, &*mapeamento) {
+        (Some(p), Some(m)) => importacao::validar_linhas(p, m),
+        _ => Vec::new(),
+    };
+    let validas = linhas.iter().filter(|l| l.resultado.is_ok()).count();
+
+    let on_file = {
+        let arquivo = arquivo.clone();
+        let planilha = planilha.clone();
+        let mapeamento = mapeamento.clone();
+        let erro = erro.clone();
+        let relatorio = relatorio.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|f| f.get(0)) else {
+                return;
+            };
+            let arquivo = arquivo.clone();
+            let planilha = planilha.clone();
+            let mapeamento = mapeamento.clone();
+            let erro = erro.clone();
+            relatorio.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let bytes = match JsFuture::from(file.array_buffer()).await {
+                    Ok(buffer) => js_sys::Uint8Array::new(&buffer).to_vec(),
+                    Err(_) => {
+                        erro.set(Some(i18n::t("importar-erro-ler")));
+                        return;
+                    }
+                };
+                let lida = csv::ler(&csv::decodificar(&bytes));
+
+                if lida.linhas.is_empty() {
+                    erro.set(Some(i18n::t("importar-vazio")));
+                    planilha.set(None);
+                } else {
+                    erro.set(None);
+                    mapeamento.set(Some(Mapeamento::detectar(&lida)));
+                    planilha.set(Some(lida));
+                }
+                arquivo.set(Some(file.name()));
+            });
+        })
+    };
+
+    let mudar_coluna = |nome: bool| {
+        let mapeamento = mapeamento.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let (Some(atual), Ok(coluna)) = (*mapeamento, select.value().parse()) {
+                let novo = if nome {
+                    Mapeamento { nome: coluna, ..atual }
+                } else {
+                    Mapeamento { preco: coluna, ..atual }
+                };
+                mapeamento.set(Some(novo));
+            }
+        })
+    };
+
+    let on_toggle_cabecalho = {
+        let mapeamento = mapeamento.clone();
+        Callback::from(move |_: Event| {
+            if let Some(atual) = *mapeamento {
+                mapeamento.set(Some(Mapeamento {
+                    cabecalho: !atual.cabecalho,
+                    ..atual
+                }));
+            }
+        })
+    };
+
+    let on_import = {
+        let store = store.clone();
+        let progresso = progresso.clone();
+        let relatorio = relatorio.clone();
+        let linhas = linhas.clone();
+        Callback::from(move |_| {
+            let client = client.clone();
+            let store = store.clone();
+            let progresso = progresso.clone();
+            let relatorio = relatorio.clone();
+            let mut resultado = RelatorioImportacao::default();
+            let validas: Vec<_> = linhas
+                .iter()
+                .filter_map(|l| match &l.resultado {
+                    Ok(novo) => Some((l.numero, novo.clone())),
+                    Err(_) => {
+                        resultado.ignoradas += 1;
+                        None
+                    }
+                })
+                .collect();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let total = validas.len();
+                progresso.set(Some((0, total)));
+
+                for (i, lote) in validas.chunks(TAMANHO_LOTE).enumerate() {
+                    let respostas = join_all(lote.iter().map(|(_, novo)| client.create(novo))).await;
+                    for ((numero, novo), resposta) in lote.iter().zip(respostas) {
+                        match resposta {
+                            Ok(item) => resultado.criados.push(item),
+                            Err(e) => resultado.falhas.push((*numero, novo.nome.clone(), e.to_string())),
+                        }
+                    }
+                    progresso.set(Some(((i * TAMANHO_LOTE + lote.len()).min(total), total)));
+                }
+
+                if !resultado.criados.is_empty() {
+                    store.dispatch(ItemsAction::CreatedMany(resultado.criados.clone()));
+                }
+                progresso.set(None);
+                relatorio.set(Some(resultado));
+            });
+        })
+    };
+
+    let on_reset = {
+        let arquivo = arquivo.clone();
+        let planilha = planilha.clone();
+        let mapeamento = mapeamento.clone();
+        let relatorio = relatorio.clone();
+        Callback::from(move |_| {
+            arquivo.set(None);
+            planilha.set(None);
+            mapeamento.set(None);
+            relatorio.set(None);
+        })
+    };
+
+    let ir_para_lista = {
+        let consulta = store.consulta.clone();
+        Callback::from(move |_| {
+            let _ = navigator.push_with_query(&Route::Itens, &consulta);
+        })
+    };
+
+    let seletor_coluna = |id: &str, selecionada: usize, onchange: Callback<Event>| {
+        let rotulo = i18n.t(id);
+        let titulos = match (&*planilha, &*mapeamento) {
+            (Some(p), Some(m)) => importacao::colunas(p, m),
+            _ => Vec::new(),
+        };
+        html! {
+            <div class="field">
+                <label class="label">{ rotulo.clone() }</label>
+                <div class="control">
+                    <div class="select">
+                        <select aria-label={rotulo} {onchange}>
+                            { for titulos.iter().enumerate().map(|(i, titulo)| html! {
+                                <option value={i.to_string()} selected={i == selecionada}>{ titulo }</option>
+                            })}
+                        </select>
+                    </div>
+                </div>
+            </div>
+        }
+    };
+
+    html! {
+        <section class="section">
+            <div class="container">
+                <h1 class="title">{ i18n.t("importar-titulo") }</h1>
+                <p class="subtitle">{ i18n.t("importar-subtitulo") }</p>
+
+                if let Some(msg) = &*erro {
+                    <Notification tipo="is-danger">{ msg }</Notification>
+                }
+
+                if let Some(res) = &*relatorio {
+                    <Notification tipo={if res.falhas.is_empty() { "is-success" } else { "is-warning" }}>
+                        <p>{ res.resumo() }</p>
+                        if !res.falhas.is_empty() {
+                            <ul class="mt-2">
+                                { for res.falhas.iter().map(|(numero, nome, motivo)| html! {
+                                    <li key={*numero}>{ i18n.t_args("importar-falha-linha", &fluent_args!["linha" => *numero, "nome" => nome.as_str(), "motivo" => motivo.as_str()]) }</li>
+                                })}
+                            </ul>
+                        }
+                    </Notification>
+                    <div class="buttons">
+                        <button class="button is-primary" onclick={ir_para_lista.clone()}>{ i18n.t("voltar-para-lista") }</button>
+                        <button class="button" onclick={on_reset.clone()}>{ i18n.t("importar-outro") }</button>
+                    </div>
+                }
+
+                if let Some((feitos, total)) = *progresso {
+                    <div class="box">
+                        <p class="mb-2">{ i18n.t_args("importar-progresso", &fluent_args!["feitos" => feitos, "total" => total]) }</p>
+                        <progress class="progress is-primary" value={feitos.to_string()} max={total.to_string()}></progress>
+                    </div>
+                } else if relatorio.is_none() {
+                    <div class="box">
+                        <div class="file has-name is-fullwidth">
+                            <label class="file-label">
+                                <input class="file-input" type="file" accept=".csv,.txt,text/csv" onchange={on_file} />
+                                <span class="file-cta">
+                                    <span class="file-label">{ i18n.t("importar-escolher") }</span>
+                                </span>
+                                <span class="file-name">{ (*arquivo).clone().unwrap_or_else(|| i18n.t("importar-nenhum-arquivo")) }</span>
+                            </label>
+                        </div>
+                        <p class="help">
+                            { i18n.t("importar-ajuda") }
+                        </p>
+                    </div>
+
+                    if let (Some(p), Some(m)) = (&*planilha, *mapeamento) {
+                        <div class="box">
+                            <div class="columns is-vcentered">
+                                <div class="column">{ seletor_coluna("importar-coluna-nome", m.nome, mudar_coluna(true)) }</div>
+                                <div class="column">{ seletor_coluna("importar-coluna-preco", m.preco, mudar_coluna(false)) }</div>
+                                <div class="column">
+                                    <label class="checkbox">
+                                        <input type="checkbox" checked={m.cabecalho} onchange={on_toggle_cabecalho} />
+                                        { " " }{ i18n.t("importar-cabecalho") }
+                                    </label>
+                                    <p class="help">{ i18n.t_args("importar-separador", &fluent_args!["separador" => nome_delimitador(p.delimitador)]) }</p>
+                                </div>
+                            </div>
+
+                            <p class="mb-3">
+                                <span class="tag is-success is-light mr-2">{ i18n.t_args("importar-validas", &fluent_args!["n" => validas]) }</span>
+                                <span class="tag is-danger is-light">{ i18n.t_args("importar-com-erro", &fluent_args!["n" => linhas.len() - validas]) }</span>
+                            </p>
+
+                            <div class="table-container">
+                                <table class="table is-fullwidth is-narrow">
+                                    <thead>
+                                        <tr>
+                                            <th>{ i18n.t("importar-linha") }</th>
+                                            <th>{ i18n.t("coluna-nome") }</th>
+                                            <th class="has-text-right">{ i18n.t("coluna-preco") }</th>
+                                            <th>{ i18n.t("importar-situacao") }</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        { for linhas.iter().take(LIMITE_PREVIA).map(linha_previa) }
+                                    </tbody>
+                                </table>
+                            </div>
+                            if linhas.len() > LIMITE_PREVIA {
+                                <p class="help">{ i18n.t_args("importar-mais-linhas", &fluent_args!["n" => linhas.len() - LIMITE_PREVIA]) }</p>
+                            }
+
+                            <div class="buttons mt-4">
+                                <button class="button is-primary" disabled={validas == 0} onclick={on_import}>
+                                    { i18n.t_args("importar-botao", &fluent_args!["n" => validas]) }
+                                </button>
+                                <button class="button" onclick={ir_para_lista}>{ i18n.t("cancelar") }</button>
+                            </div>
+                        </div>
+                    }
+                }
+            </div>
+        </section>
+    }
+}
+
+fn linha_previa(linha: &LinhaImportacao) -> Html {
+    let (classe, situacao) = match &linha.resultado {
+        Ok(novo) => (None, i18n::t_args("importar-ok", &fluent_args!["valor" => novo.preco.to_string()])),
+        Err(erros) => (
+            Some("has-background-danger-light"),
+            erros.iter().map(|e| e.mensagem.as_str()).collect::<Vec<_>>().join("; "),
+        ),
+    };
+
+    html! {
+        <tr key={linha.numero} class={classes!(classe)}>
+            <td>{ linha.numero }</td>
+            <td>{ &linha.nome }</td>
+            <td class="has-text-right">{ &linha.preco }</td>
+            <td class={classes!(classe.map(|_| "has-text-danger"))}>{ situacao }</td>
+        </tr>
+    }
+}
+
+fn nome_delimitador(delimitador: char) -> String {
+    i18n::t(match delimitador {
+        ';' => "separador-ponto-e-virgula",
+        '\t' => "separador-tabulacao",
+        '|' => "separador-barra",
+        _ => "separador-virgula",
+    })
+}
@@ -0,0 +1,750 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::ListarParams;
+use crate::auth::{Permissao, use_autenticacao};
+use crate::components::{
+    BulkActionBar, CampoEditavel, CategorySelect, ColumnsMenu, ColunaOpcional, ConfirmModal, EdicaoCelula,
+    EstadoCelula, ExportMenu, FilterChips, FilterPanel, ItemTable, Notification, PageSizeSelect, Pagination,
+    SavedViewsMenu, SearchBar, Toast, validar_formulario,
+};
+use crate::exportacao::{self, Escopo, Formato};
+use crate::filtros::Filtros;
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::lote::{AjustePreco, ResultadoLote};
+use crate::models::{Item, NovoItem};
+use crate::offline::{self, Envio};
+use crate::pages::use_client;
+use crate::routes::Route;
+use crate::store::{ItemsAction, use_items_store};
+use crate::visoes::{self, VisaoSalva};
+
+/// Tempo em que a remoção ainda pode ser desfeita.
+const DURACAO_DESFAZER_MS: u32 = 6000;
+
+const CHAVE_COLUNAS: &str = "rust-app-sample:colunas";
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Colunas opcionais escolhidas da última vez; nenhuma na primeira visita.
+fn carregar_colunas() -> Vec<ColunaOpcional> {
+    storage()
+        .and_then(|s| s.get_item(CHAVE_COLUNAS).ok()?)
+        .and_then(|texto| serde_json::from_str(&texto).ok())
+        .unwrap_or_default()
+}
+
+fn gravar_colunas(colunas: &[ColunaOpcional]) {
+    if let Some(storage) = storage() {
+        let _ = storage.set_item(CHAVE_COLUNAS, &serde_json::to_string(colunas).unwrap_or_default());
+    }
+}
+
+/// Situação das células editadas na tabela; ausente quando não há nada pendente.
+#[derive(Default, PartialEq)]
+struct EstadosCelulas(BTreeMap<(i32, CampoEditavel), EstadoCelula>);
+
+impl Reducible for EstadosCelulas {
+    type Action = ((i32, CampoEditavel), Option<EstadoCelula>);
+
+    fn reduce(self: Rc<Self>, (chave, estado): Self::Action) -> Rc<Self> {
+        let mut mapa = self.0.clone();
+        match estado {
+            Some(estado) => mapa.insert(chave, estado),
+            None => mapa.remove(&chave),
+        };
+        Rc::new(Self(mapa))
+    }
+}
+
+#[function_component(ListaItens)]
+pub fn lista_itens() -> Html {
+    let store = use_items_store();
+    let i18n = use_i18n();
+    let client = use_client();
+    let auth = use_autenticacao();
+    let navigator = use_navigator().unwrap();
+    let location = use_location();
+
+    let consulta_url = location
+        .and_then(|l| l.query::<ListarParams>().ok())
+        .unwrap_or_default()
+        .normalizado();
+
+    {
+        let store = store.clone();
+        use_effect_with(consulta_url.clone(), move |consulta_url| {
+            store.dispatch(ItemsAction::Restore(consulta_url.clone()));
+            || ()
+        });
+    }
+
+    {
+        let navigator = navigator.clone();
+        let consulta_url = consulta_url.clone();
+        let montado = use_mut_ref(|| false);
+        use_effect_with(store.consulta.clone(), move |consulta| {
+            if *montado.borrow() && *consulta != consulta_url {
+                // A busca muda a cada pausa na digitação: troca a entrada atual em vez de empilhar textos parciais.
+                let so_a_busca = consulta.com_busca(&consulta_url.busca) == consulta_url.com_busca(&consulta_url.busca);
+                let _ = if so_a_busca {
+                    navigator.replace_with_query(&Route::Itens, consulta)
+                } else {
+                    navigator.push_with_query(&Route::Itens, consulta)
+                };
+            }
+            *montado.borrow_mut() = true;
+            || ()
+        });
+    }
+
+    let on_search = {
+        let store = store.clone();
+        Callback::from(move |busca: String| store.dispatch(ItemsAction::Search(busca)))
+    };
+
+    // Uma API sem `/categorias` apenas deixa o seletor de fora.
+    let categorias = use_state(Vec::<String>::new);
+    {
+        let client = client.clone();
+        let categorias = categorias.clone();
+        use_effect_with(store.revisao, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(lista) = client.categorias().await {
+                    categorias.set(lista);
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_category = {
+        let store = store.clone();
+        Callback::from(move |categoria: String| store.dispatch(ItemsAction::Category(categoria)))
+    };
+
+    let colunas = use_state(carregar_colunas);
+
+    let on_change_colunas = {
+        let colunas = colunas.clone();
+        Callback::from(move |novas: Vec<ColunaOpcional>| {
+            gravar_colunas(&novas);
+            colunas.set(novas);
+        })
+    };
+
+    let on_filter = {
+        let store = store.clone();
+        Callback::from(move |filtros: Filtros| store.dispatch(ItemsAction::Filter(filtros)))
+    };
+
+    let on_change_por_pagina = {
+        let store = store.clone();
+        Callback::from(move |n: i64| store.dispatch(ItemsAction::PageSize(n)))
+    };
+
+    let on_sort = {
+        let store = store.clone();
+        Callback::from(move |coluna: String| store.dispatch(ItemsAction::Sort(coluna)))
+    };
+
+    let on_page = {
+        let store = store.clone();
+        Callback::from(move |p: i64| store.dispatch(ItemsAction::Paginate(p)))
+    };
+
+    let on_edit = {
+        let navigator = navigator.clone();
+        Callback::from(move |item: Item| navigator.push(&Route::Editar { id: item.id }))
+    };
+
+    let confirmando = use_state(|| None::<Item>);
+    let removido = use_state(|| None::<Item>);
+    let chave_toast = use_state(|| 0u32);
+
+    let on_delete = {
+        let confirmando = confirmando.clone();
+        Callback::from(move |item: Item| confirmando.set(Some(item)))
+    };
+
+    let on_cancel_delete = {
+        let confirmando = confirmando.clone();
+        Callback::from(move |_| confirmando.set(None))
+    };
+
+    let on_confirm_delete = {
+        let client = client.clone();
+        let store = store.clone();
+        let confirmando = confirmando.clone();
+        let removido = removido.clone();
+        let chave_toast = chave_toast.clone();
+        Callback::from(move |_| {
+            let Some(item) = (*confirmando).clone() else {
+                return;
+            };
+            let client = client.clone();
+            let store = store.clone();
+            let removido = removido.clone();
+            let chave_toast = chave_toast.clone();
+            let posicao = store.posicao(item.id).unwrap_or(0);
+            confirmando.set(None);
+            store.dispatch(ItemsAction::Deleted(item.id));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match offline::remover(&client, &item).await {
+                    Ok(Envio::Feito(())) => {
+                        chave_toast.set(*chave_toast + 1);
+                        removido.set(Some(item));
+                    }
+                    Ok(Envio::NaFila(pendentes)) => store.dispatch(ItemsAction::Queued(pendentes)),
+                    Err(e) => {
+                        store.dispatch(ItemsAction::Restored(vec![(posicao, item)]));
+                        store.dispatch(ItemsAction::Failed(i18n::t_args("lista-erro-remover", &fluent_args!["erro" => e.to_string()])));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_close_toast = {
+        let removido = removido.clone();
+        Callback::from(move |_| removido.set(None))
+    };
+
+    let on_undo = {
+        let client = client.clone();
+        let store = store.clone();
+        let removido = removido.clone();
+        Callback::from(move |_| {
+            let Some(item) = (*removido).clone() else {
+                return;
+            };
+            let client = client.clone();
+            let store = store.clone();
+            let provisorio = store.id_provisorio();
+            removido.set(None);
+            store.dispatch(ItemsAction::Created(Item { id: provisorio, ..item.clone() }));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match offline::criar(&client, provisorio, &NovoItem::from(&item)).await {
+                    Ok(Envio::Feito(criado)) => store.dispatch(ItemsAction::Reconciled { provisorio, item: criado }),
+                    Ok(Envio::NaFila(pendentes)) => store.dispatch(ItemsAction::Queued(pendentes)),
+                    Err(e) => {
+                        store.dispatch(ItemsAction::Deleted(provisorio));
+                        store.dispatch(ItemsAction::Failed(i18n::t_args("lista-erro-desfazer", &fluent_args!["erro" => e.to_string()])));
+                    }
+                }
+            });
+        })
+    };
+
+    let progresso = use_state(|| None::<(usize, usize)>);
+    let resultado = use_state(|| None::<ResultadoLote>);
+    let confirmando_lote = use_state(|| false);
+    let carregando_todos = use_state(|| false);
+
+    let on_select = {
+        let store = store.clone();
+        Callback::from(move |item: Item| store.dispatch(ItemsAction::ToggleSelected(item)))
+    };
+
+    let on_select_page = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(ItemsAction::TogglePage))
+    };
+
+    let on_clear_selection = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(ItemsAction::ClearSelection))
+    };
+
+    let on_select_all = {
+        let client = client.clone();
+        let store = store.clone();
+        let carregando_todos = carregando_todos.clone();
+        Callback::from(move |_| {
+            let client = client.clone();
+            let store = store.clone();
+            let carregando_todos = carregando_todos.clone();
+            let consulta = store.consulta.clone();
+            carregando_todos.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match client.list_all(&consulta).await {
+                    Ok(itens) => store.dispatch(ItemsAction::SelectAll(itens)),
+                    Err(e) => store.dispatch(ItemsAction::Failed(i18n::t_args("lista-erro-selecionar-busca", &fluent_args!["erro" => e.to_string()]))),
+                }
+                carregando_todos.set(false);
+            });
+        })
+    };
+
+    let on_bulk_delete = {
+        let confirmando_lote = confirmando_lote.clone();
+        Callback::from(move |_| confirmando_lote.set(true))
+    };
+
+    let on_cancel_bulk_delete = {
+        let confirmando_lote = confirmando_lote.clone();
+        Callback::from(move |_| confirmando_lote.set(false))
+    };
+
+    let on_confirm_bulk_delete = {
+        let client = client.clone();
+        let store = store.clone();
+        let progresso = progresso.clone();
+        let resultado = resultado.clone();
+        let confirmando_lote = confirmando_lote.clone();
+        Callback::from(move |_| {
+            let client = client.clone();
+            let store = store.clone();
+            let progresso = progresso.clone();
+            let resultado = resultado.clone();
+            let itens: Vec<Item> = store.selecionados.values().cloned().collect();
+            confirmando_lote.set(false);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let res = em_lote(&i18n::t("lote-remocao"), itens, &progresso, |item| {
+                    let client = client.clone();
+                    async move {
+                        client.delete(item.id).await.map_err(|e| e.to_string())?;
+                        Ok(item)
+                    }
+                })
+                .await;
+                store.dispatch(ItemsAction::DeletedMany(res.sucessos.iter().map(|i| i.id).collect()));
+                resultado.set(Some(res));
+            });
+        })
+    };
+
+    let on_bulk_adjust = {
+        let client = client.clone();
+        let store = store.clone();
+        let progresso = progresso.clone();
+        let resultado = resultado.clone();
+        Callback::from(move |ajuste: AjustePreco| {
+            let client = client.clone();
+            let store = store.clone();
+            let progresso = progresso.clone();
+            let resultado = resultado.clone();
+            let itens: Vec<Item> = store.selecionados.values().cloned().collect();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let res = em_lote(&i18n::t("lote-ajuste"), itens, &progresso, |item| {
+                    let client = client.clone();
+                    async move {
+                        let preco = ajuste.aplicar(item.preco)?;
+                        let novo = NovoItem { preco, ..NovoItem::from(&item) };
+                        client.update(item.id, &novo).await.map_err(|e| e.to_string())
+                    }
+                })
+                .await;
+                store.dispatch(ItemsAction::UpdatedMany(res.sucessos.clone()));
+                resultado.set(Some(res));
+            });
+        })
+    };
+
+    let on_close_resultado = {
+        let resultado = resultado.clone();
+        Callback::from(move |_| resultado.set(None))
+    };
+
+    let estados_celulas = use_reducer(EstadosCelulas::default);
+
+    let on_cell_edit = {
+        let client = client.clone();
+        let store = store.clone();
+        let estados_celulas = estados_celulas.dispatcher();
+        Callback::from(move |edicao: EdicaoCelula| {
+            let EdicaoCelula { item, campo, valor } = edicao;
+            let chave = (item.id, campo);
+            let validado = match campo {
+                CampoEditavel::Nome => validar_formulario(&valor, &item.preco.numero(i18n::atual())),
+                CampoEditavel::Preco => validar_formulario(&item.nome, &valor),
+            };
+            // Os demais campos do item não são editados na tabela e seguem como estão.
+            let novo = match validado {
+                Ok(editado) => NovoItem { nome: editado.nome, preco: editado.preco, ..NovoItem::from(&item) },
+                Err(erros) => {
+                    let msg = erros.into_iter().map(|e| e.mensagem).collect::<Vec<_>>().join("; ");
+                    estados_celulas.dispatch((chave, Some(EstadoCelula::Erro(msg))));
+                    return;
+                }
+            };
+
+            let client = client.clone();
+            let store = store.clone();
+            let estados_celulas = estados_celulas.clone();
+            estados_celulas.dispatch((chave, Some(EstadoCelula::Salvando)));
+            store.dispatch(ItemsAction::Updated(novo.com_id(item.id)));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match offline::atualizar(&client, &item, &novo).await {
+                    Ok(Envio::Feito(atualizado)) => {
+                        estados_celulas.dispatch((chave, None));
+                        store.dispatch(ItemsAction::Updated(atualizado));
+                    }
+                    Ok(Envio::NaFila(pendentes)) => {
+                        estados_celulas.dispatch((chave, None));
+                        store.dispatch(ItemsAction::Queued(pendentes));
+                    }
+                    Err(e) => {
+                        let msg = e.erro_do_campo(campo.nome_api()).map(String::from).unwrap_or_else(|| e.to_string());
+                        estados_celulas.dispatch((chave, Some(EstadoCelula::Erro(msg))));
+                        store.dispatch(ItemsAction::Updated(item));
+                    }
+                }
+            });
+        })
+    };
+
+    let exportando = use_state(|| false);
+
+    let on_export = {
+        let store = store.clone();
+        let exportando = exportando.clone();
+        Callback::from(move |(formato, escopo): (Formato, Escopo)| {
+            let client = client.clone();
+            let store = store.clone();
+            let exportando = exportando.clone();
+            let consulta = store.consulta.clone();
+            let pagina = store.itens.clone();
+            exportando.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let itens = match escopo {
+                    Escopo::PaginaAtual => Ok(pagina),
+                    Escopo::Todas => client.list_all(&consulta).await.map_err(|e| e.to_string()),
+                };
+                let baixado = itens.and_then(|itens| {
+                    let bytes = exportacao::gerar(&itens, formato);
+                    exportacao::baixar(&exportacao::nome_arquivo(formato), &bytes, formato.mime())
+                });
+                if let Err(e) = baixado {
+                    store.dispatch(ItemsAction::Failed(i18n::t_args("lista-erro-exportar", &fluent_args!["erro" => e.to_string()])));
+                }
+                exportando.set(false);
+            });
+        })
+    };
+
+    let filtros = Filtros::de_consulta(&store.consulta);
+
+    let visoes_salvas = use_state(visoes::carregar);
+    let aviso_visoes = use_state(|| None::<(&'static str, String)>);
+
+    let atualizar_visoes = {
+        let visoes_salvas = visoes_salvas.clone();
+        Callback::from(move |novas: Vec<VisaoSalva>| {
+            visoes::gravar(&novas);
+            visoes_salvas.set(novas);
+        })
+    };
+
+    let on_apply_visao = {
+        let store = store.clone();
+        Callback::from(move |consulta: ListarParams| store.dispatch(ItemsAction::Restore(consulta)))
+    };
+
+    let on_save_visao = {
+        let visoes_salvas = visoes_salvas.clone();
+        let atualizar_visoes = atualizar_visoes.clone();
+        let aviso_visoes = aviso_visoes.clone();
+        let consulta = store.consulta.clone();
+        Callback::from(move |nome: String| {
+            atualizar_visoes.emit(visoes::salvar(&visoes_salvas, VisaoSalva::new(&nome, &consulta)));
+            aviso_visoes.set(Some(("is-success", i18n::t_args("visoes-salva", &fluent_args!["nome" => nome.as_str()]))));
+        })
+    };
+
+    let on_remove_visao = {
+        let visoes_salvas = visoes_salvas.clone();
+        let atualizar_visoes = atualizar_visoes.clone();
+        Callback::from(move |nome: String| atualizar_visoes.emit(visoes::remover(&visoes_salvas, &nome)))
+    };
+
+    let on_export_visoes = {
+        let visoes_salvas = visoes_salvas.clone();
+        let aviso_visoes = aviso_visoes.clone();
+        Callback::from(move |_| {
+            let json = visoes::para_json(&visoes_salvas);
+            if let Err(e) = exportacao::baixar("visoes.json", json.as_bytes(), "application/json") {
+                aviso_visoes.set(Some(("is-danger", i18n::t_args("visoes-erro-exportar", &fluent_args!["erro" => e.to_string()]))));
+            }
+        })
+    };
+
+    let on_import_visoes = {
+        let visoes_salvas = visoes_salvas.clone();
+        let aviso_visoes = aviso_visoes.clone();
+        Callback::from(move |arquivo: web_sys::File| {
+            let existentes = (*visoes_salvas).clone();
+            let atualizar_visoes = atualizar_visoes.clone();
+            let aviso_visoes = aviso_visoes.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let texto = wasm_bindgen_futures::JsFuture::from(arquivo.text())
+                    .await
+                    .ok()
+                    .and_then(|t| t.as_string())
+                    .unwrap_or_default();
+                match visoes::importar_json(&existentes, &texto) {
+                    Ok((novas, quantidade)) => {
+                        atualizar_visoes.emit(novas);
+                        aviso_visoes.set(Some(("is-success", i18n::t_args("visoes-importadas", &fluent_args!["n" => quantidade]))));
+                    }
+                    Err(e) => aviso_visoes.set(Some(("is-danger", e))),
+                }
+            });
+        })
+    };
+
+    let on_close_aviso_visoes = {
+        let aviso_visoes = aviso_visoes.clone();
+        Callback::from(move |_| aviso_visoes.set(None))
+    };
+
+    let on_sync = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(ItemsAction::Sync))
+    };
+
+    let on_close_conflitos = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(ItemsAction::DismissConflicts))
+    };
+
+    let go_to_novo = {
+        let navigator = navigator.clone();
+        Callback::from(move |_| navigator.push(&Route::Novo))
+    };
+
+    let go_to_importar = {
+        let navigator = navigator.clone();
+        Callback::from(move |_| navigator.push(&Route::Importar))
+    };
+
+    let (pode_criar, pode_editar, pode_remover) =
+        (auth.pode(Permissao::Criar), auth.pode(Permissao::Editar), auth.pode(Permissao::Remover));
+    let pode_selecionar = pode_editar || pode_remover;
+
+    html! {
+        <section class="section">
+            <div class="container">
+                <div class="level">
+                    <div class="level-left">
+                        <div>
+                            <h1 class="title">{ i18n.t("lista-titulo") }</h1>
+                            <p class="subtitle">{ i18n.t("lista-subtitulo") }</p>
+                        </div>
+                    </div>
+                    <div class="level-right">
+                        <div class="buttons">
+                            <SavedViewsMenu
+                                visoes={(*visoes_salvas).clone()}
+                                atual={store.consulta.clone()}
+                                on_apply={on_apply_visao}
+                                on_save={on_save_visao}
+                                on_remove={on_remove_visao}
+                                on_export={on_export_visoes}
+                                on_import={on_import_visoes}
+                            />
+                            <ColumnsMenu colunas={(*colunas).clone()} on_change={on_change_colunas} />
+                            <ExportMenu carregando={*exportando} {on_export} />
+                            if pode_criar {
+                                <button class="button" onclick={go_to_importar}>{ i18n.t("lista-importar-csv") }</button>
+                                <button class="button is-primary" onclick={go_to_novo}>{ i18n.t("lista-incluir-novo") }</button>
+                            }
+                        </div>
+                    </div>
+                </div>
+
+                if store.carregando {
+                    <Notification tipo="is-info">{ i18n.t("carregando") }</Notification>
+                }
+
+                if let Some(msg) = &store.erro {
+                    <Notification tipo="is-danger">{ msg }</Notification>
+                }
+
+                if store.offline {
+                    <Notification tipo="is-warning">
+                        { i18n.t("lista-sem-conexao") }
+                    </Notification>
+                }
+
+                if store.pendentes > 0 {
+                    <Notification tipo="is-warning">
+                        <div class="level">
+                            <div class="level-left">
+                                { i18n.t_args("lista-pendentes", &fluent_args!["n" => store.pendentes]) }
+                            </div>
+                            <div class="level-right">
+                                <button class="button is-small" onclick={on_sync}>{ i18n.t("lista-sincronizar") }</button>
+                            </div>
+                        </div>
+                    </Notification>
+                }
+
+                if !store.conflitos.is_empty() {
+                    <Notification tipo="is-danger" on_close={on_close_conflitos}>
+                        <p>{ i18n.t("lista-conflitos") }</p>
+                        <ul class="mt-2">
+                            { for store.conflitos.iter().map(|c| html! { <li>{ c.mensagem() }</li> }) }
+                        </ul>
+                    </Notification>
+                }
+
+                if let Some((tipo, msg)) = &*aviso_visoes {
+                    <Notification tipo={*tipo} on_close={on_close_aviso_visoes}>{ msg }</Notification>
+                }
+
+                if let Some(res) = &*resultado {
+                    <Notification tipo={if res.falhas.is_empty() { "is-success" } else { "is-warning" }} on_close={on_close_resultado}>
+                        <p>{ res.resumo() }</p>
+                        if !res.falhas.is_empty() {
+                            <ul class="mt-2">
+                                { for res.falhas.iter().map(|(item, erro)| html! {
+                                    <li key={item.id}>{ format!("#{} {}: {}", item.id, item.nome, erro) }</li>
+                                })}
+                            </ul>
+                        }
+                    </Notification>
+                }
+
+                <div class="box">
+                    <div class="columns is-vcentered">
+                        <div class="column is-4">
+                            <SearchBar valor={store.consulta.busca.clone()} {on_search} />
+                        </div>
+                        <div class="column is-3">
+                            if !categorias.is_empty() || !store.consulta.categoria.is_empty() {
+                                <CategorySelect
+                                    categorias={(*categorias).clone()}
+                                    valor={store.consulta.categoria.clone()}
+                                    on_change={on_category}
+                                />
+                            }
+                        </div>
+                        <div class="column is-2">
+                            <PageSizeSelect valor={store.consulta.por_pagina} on_change={on_change_por_pagina} />
+                        </div>
+                        <div class="column is-3 has-text-right">
+                            <span class="tag is-info is-medium">{ i18n.t_args("lista-total", &fluent_args!["n" => store.total]) }</span>
+                        </div>
+                    </div>
+                    <FilterPanel filtros={filtros} on_apply={on_filter.clone()} />
+                </div>
+
+                <FilterChips {filtros} on_change={on_filter} />
+
+                if !store.selecionados.is_empty() || progresso.is_some() {
+                    <BulkActionBar
+                        quantidade={store.selecionados.len()}
+                        total_busca={store.total}
+                        progresso={*progresso}
+                        carregando_todos={*carregando_todos}
+                        on_select_all={on_select_all}
+                        on_clear={on_clear_selection}
+                        on_delete={pode_remover.then_some(on_bulk_delete)}
+                        on_adjust={pode_editar.then_some(on_bulk_adjust)}
+                    />
+                }
+
+                <div class="box">
+                    <ItemTable
+                        itens={store.itens.clone()}
+                        colunas={(*colunas).clone()}
+                        ordenar_por={store.consulta.ordenar_por.clone()}
+                        ordem={store.consulta.ordem.clone()}
+                        {on_sort}
+                        links_detalhe=true
+                        on_edit={pode_editar.then_some(on_edit)}
+                        on_delete={pode_remover.then_some(on_delete)}
+                        selecionados={store.selecionados.keys().copied().collect::<Vec<_>>()}
+                        on_select={pode_selecionar.then_some(on_select)}
+                        on_select_page={pode_selecionar.then_some(on_select_page)}
+                        on_cell_edit={pode_editar.then_some(on_cell_edit)}
+                        estados_celulas={estados_celulas.0.clone()}
+                    />
+                    if !store.itens.is_empty() {
+                        <Pagination pagina_atual={store.consulta.pagina} total_paginas={store.total_paginas} on_change={on_page} />
+                    }
+                </div>
+            </div>
+
+            if let Some(item) = &*confirmando {
+                <ConfirmModal
+                    titulo={i18n.t("remover-item-titulo")}
+                    texto_confirmar={i18n.t("remover")}
+                    on_confirm={on_confirm_delete}
+                    on_cancel={on_cancel_delete}
+                >
+                    <p>{ i18n.t("remover-item-pergunta") }</p>
+                    <p class="mt-3">
+                        <strong>{ &item.nome }</strong>
+                        { format!(" · {}", item.preco) }
+                    </p>
+                </ConfirmModal>
+            }
+
+            if *confirmando_lote {
+                <ConfirmModal
+                    titulo={i18n.t("remover-selecionados-titulo")}
+                    texto_confirmar={i18n.t("remover")}
+                    on_confirm={on_confirm_bulk_delete}
+                    on_cancel={on_cancel_bulk_delete}
+                >
+                    <p>{ i18n.t_args("remover-selecionados-pergunta", &fluent_args!["n" => store.selecionados.len()]) }</p>
+                </ConfirmModal>
+            }
+
+            if let Some(item) = &*removido {
+                <Toast
+                    chave={*chave_toast}
+                    duracao_ms={DURACAO_DESFAZER_MS}
+                    acao={pode_criar.then_some(AttrValue::from(i18n.t("desfazer")))}
+                    on_action={pode_criar.then_some(on_undo)}
+                    on_close={on_close_toast}
+                >
+                    { i18n.t_args("item-removido", &fluent_args!["nome" => item.nome.as_str()]) }
+                </Toast>
+            }
+        </section>
+    }
+}
+
+/// Executa `operacao` em cada item, um por vez, atualizando `progresso` a cada passo.
+async fn em_lote<F, Fut>(
+    descricao: &str,
+    itens: Vec<Item>,
+    progresso: &UseStateHandle<Option<(usize, usize)>>,
+    operacao: F,
+) -> ResultadoLote
+where
+    F: Fn(Item) -> Fut,
+    Fut: Future<Output = Result<Item, String>>,
+{
+    let total = itens.len();
+    let mut resultado = ResultadoLote::new(descricao);
+    progresso.set(Some((0, total)));
+
+    for (i, item) in itens.into_iter().enumerate() {
+        match operacao(item.clone()).await {
+            Ok(feito) => resultado.sucessos.push(feito),
+            Err(e) => resultado.falhas.push((item, e)),
+        }
+        progresso.set(Some((i + 1, total)));
+    }
+
+    progresso.set(None);
+    resultado
+}
@@ -0,0 +1,119 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::auth::{Sessao, use_autenticacao};
+use crate::components::Notification;
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::pages::use_client;
+use crate::routes::Route;
+
+#[function_component(Login)]
+pub fn login() -> Html {
+    let client = use_client();
+    let auth = use_autenticacao();
+    let i18n = use_i18n();
+    let navigator = use_navigator().unwrap();
+
+    let usuario = use_state(String::new);
+    let senha = use_state(String::new);
+    let enviando = use_state(|| false);
+    let erro = use_state(|| None::<String>);
+
+    let on_change_usuario = {
+        let usuario = usuario.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            usuario.set(input.value());
+        })
+    };
+
+    let on_change_senha = {
+        let senha = senha.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            senha.set(input.value());
+        })
+    };
+
+    let on_submit = {
+        let usuario = usuario.clone();
+        let senha = senha.clone();
+        let enviando = enviando.clone();
+        let erro = erro.clone();
+        let entrar = auth.entrar.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let (u, s) = (usuario.trim().to_string(), (*senha).clone());
+            if u.is_empty() || s.is_empty() {
+                erro.set(Some(i18n::t("login-campos-vazios")));
+                return;
+            }
+
+            let client = client.clone();
+            let navigator = navigator.clone();
+            let enviando = enviando.clone();
+            let erro = erro.clone();
+            let entrar = entrar.clone();
+            enviando.set(true);
+            erro.set(None);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                let sessao = match client.login(&u, &s).await {
+                    Ok(token) => Sessao::new(token),
+                    Err(e) if e.status() == Some(401) => Err(i18n::t("login-invalido")),
+                    Err(e) => Err(i18n::t_args("login-falha", &fluent_args!["erro" => e.to_string()])),
+                };
+                enviando.set(false);
+                match sessao {
+                    Ok(sessao) => {
+                        entrar.emit(sessao);
+                        navigator.push(&Route::Itens);
+                    }
+                    Err(msg) => erro.set(Some(msg)),
+                }
+            });
+        })
+    };
+
+    html! {
+        <section class="section">
+            <div class="container">
+                <div class="columns is-centered">
+                    <div class="column is-5">
+                        <form class="box" onsubmit={on_submit}>
+                            <h1 class="title is-4">{ i18n.t("login-titulo") }</h1>
+                            <p class="subtitle is-6">{ i18n.t("login-subtitulo") }</p>
+
+                            if let Some(aviso) = &auth.aviso {
+                                <Notification tipo="is-warning">{ aviso }</Notification>
+                            }
+
+                            if let Some(msg) = &*erro {
+                                <Notification tipo="is-danger">{ msg }</Notification>
+                            }
+
+                            <div class="field">
+                                <label class="label" for="login-usuario">{ i18n.t("login-usuario") }</label>
+                                <div class="control">
+                                    <input id="login-usuario" class="input" autocomplete="username" value={(*usuario).clone()} oninput={on_change_usuario} />
+                                </div>
+                            </div>
+
+                            <div class="field">
+                                <label class="label" for="login-senha">{ i18n.t("login-senha") }</label>
+                                <div class="control">
+                                    <input id="login-senha" class="input" type="password" autocomplete="current-password" value={(*senha).clone()} oninput={on_change_senha} />
+                                </div>
+                            </div>
+
+                            <button class={classes!("button", "is-primary", enviando.then_some("is-loading"))} type="submit" disabled={*enviando}>
+                                { i18n.t("login-entrar") }
+                            </button>
+                        </form>
+                    </div>
+                </div>
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,22 @@
+mod detalhe;
+mod formulario;
+mod importacao;
+mod lista;
+mod login;
+mod painel;
+
+pub use detalhe::{DetalheItem, DetalheItemProps};
+pub use formulario::{FormularioItem, FormularioItemProps};
+pub use importacao::ImportacaoItens;
+pub use lista::ListaItens;
+pub use login::Login;
+pub use painel::Painel;
+
+use yew::prelude::*;
+
+use crate::api::ItensClient;
+
+#[hook]
+pub fn use_client() -> ItensClient {
+    use_context::<ItensClient>().expect("use_client chamado fora de um ContextProvider<ItensClient>")
+}
@@ -0,0 +1,111 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::api::ListarParams;
+use crate::components::{Notification, PriceHistogram};
+use crate::estatisticas::{self, Estatisticas, FAIXAS_HISTOGRAMA};
+use crate::i18n::{self, fluent_args, use_i18n};
+use crate::models::Item;
+use crate::pages::use_client;
+use crate::routes::Route;
+use crate::store::use_items_store;
+
+/// Quantidade de itens na tabela de incluídos por último.
+const RECENTES: usize = 5;
+
+/// Resumo do catálogo: totais, preços e os itens mais novos, a partir de todas as páginas de `GET /itens`.
+#[function_component(Painel)]
+pub fn painel() -> Html {
+    let i18n = use_i18n();
+    let store = use_items_store();
+    let client = use_client();
+
+    let itens = use_state(|| None::<Vec<Item>>);
+    let erro = use_state(|| None::<String>);
+
+    {
+        let itens = itens.clone();
+        let erro = erro.clone();
+        use_effect_with(store.revisao, move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match client.list_all(&ListarParams::default()).await {
+                    Ok(todos) => {
+                        erro.set(None);
+                        itens.set(Some(todos));
+                    }
+                    Err(e) => erro.set(Some(i18n::t_args("painel-erro", &fluent_args!["erro" => e.to_string()]))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let conteudo = match (&*itens, &*erro) {
+        (_, Some(erro)) => html! { <Notification tipo="is-danger">{ erro }</Notification> },
+        (None, None) => html! { <p>{ i18n.t("carregando") }</p> },
+        (Some(itens), None) => match Estatisticas::calcular(itens) {
+            None => html! { <Notification tipo="is-info">{ i18n.t("painel-vazio") }</Notification> },
+            Some(e) => {
+                let cartao = |rotulo: &str, valor: String| html! {
+                    <div class="level-item has-text-centered">
+                        <div>
+                            <p class="heading">{ i18n.t(rotulo) }</p>
+                            <p class="title">{ valor }</p>
+                        </div>
+                    </div>
+                };
+                html! {
+                    <>
+                        <nav class="level box">
+                            { cartao("painel-total", e.total.to_string()) }
+                            { cartao("painel-media", e.media.to_string()) }
+                            { cartao("painel-mediana", e.mediana.to_string()) }
+                            { cartao("painel-minimo", e.minimo.to_string()) }
+                            { cartao("painel-maximo", e.maximo.to_string()) }
+                        </nav>
+                        <div class="columns">
+                            <div class="column is-7">
+                                <div class="box">
+                                    <h2 class="subtitle">{ i18n.t("painel-distribuicao") }</h2>
+                                    <PriceHistogram faixas={estatisticas::histograma(itens, FAIXAS_HISTOGRAMA)} />
+                                </div>
+                            </div>
+                            <div class="column is-5">
+                                <div class="box">
+                                    <h2 class="subtitle">{ i18n.t("painel-recentes") }</h2>
+                                    <table class="table is-fullwidth is-narrow">
+                                        <thead>
+                                            <tr>
+                                                <th>{ i18n.t("coluna-id") }</th>
+                                                <th>{ i18n.t("coluna-nome") }</th>
+                                                <th class="has-text-right">{ i18n.t("coluna-preco") }</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            { for estatisticas::recentes(itens, RECENTES).iter().map(|item| html! {
+                                                <tr key={item.id}>
+                                                    <td><Link<Route> to={Route::Detalhe { id: item.id }}>{ item.id }</Link<Route>></td>
+                                                    <td>{ &item.nome }</td>
+                                                    <td class="has-text-right">{ item.preco.to_string() }</td>
+                                                </tr>
+                                            })}
+                                        </tbody>
+                                    </table>
+                                </div>
+                            </div>
+                        </div>
+                    </>
+                }
+            }
+        },
+    };
+
+    html! {
+        <section class="section">
+            <div class="container">
+                <h1 class="title">{ i18n.t("painel-titulo") }</h1>
+                { conteudo }
+            </div>
+        </section>
+    }
+}
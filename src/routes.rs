@@ -0,0 +1,24 @@
+use yew_router::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Routable)]
+pub enum Route {
+    #[at("/")]
+    Inicio,
+    #[at("/login")]
+    Login,
+    #[at("/itens")]
+    Itens,
+    #[at("/painel")]
+    Painel,
+    #[at("/itens/novo")]
+    Novo,
+    #[at("/itens/importar")]
+    Importar,
+    #[at("/itens/:id")]
+    Detalhe { id: i32 },
+    #[at("/itens/:id/editar")]
+    Editar { id: i32 },
+    #[not_found]
+    #[at("/404")]
+    NaoEncontrado,
+}
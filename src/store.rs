@@ -0,0 +1,693 @@
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::Closure;
+
+use crate::api::{ItensClient, ListarParams};
+use crate::error::ApiError;
+use crate::filtros::Filtros;
+use crate::i18n::{self, fluent_args};
+use crate::models::{Item, ListarResponse};
+use crate::offline::{self, Conflito, Sincronizacao};
+
+/// Estado da listagem compartilhado pelos componentes da página de itens.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ItemsState {
+    pub consulta: ListarParams,
+    pub itens: Vec<Item>,
+    pub total: i64,
+    pub total_paginas: i64,
+    pub carregando: bool,
+    pub erro: Option<String>,
+    /// Incrementada quando uma alteração invalida a página carregada.
+    pub revisao: u32,
+    /// Itens marcados para ações em lote, por id. Podem estar em outras páginas.
+    pub selecionados: BTreeMap<i32, Item>,
+    /// A página veio da cópia salva porque a API não respondeu.
+    pub offline: bool,
+    /// Alterações feitas sem conexão que ainda não chegaram à API.
+    pub pendentes: usize,
+    pub conflitos: Vec<Conflito>,
+    /// Incrementada para tentar enviar de novo as alterações pendentes.
+    pub sincronia: u32,
+}
+
+impl ItemsState {
+    pub fn new(consulta: ListarParams) -> Self {
+        Self {
+            consulta,
+            ..Self::default()
+        }
+    }
+
+    pub fn selecionado(&self, id: i32) -> bool {
+        self.selecionados.contains_key(&id)
+    }
+
+    pub fn pagina_selecionada(&self) -> bool {
+        !self.itens.is_empty() && self.itens.iter().all(|i| self.selecionado(i.id))
+    }
+
+    /// Posição do item na página carregada, para desfazer uma remoção.
+    pub fn posicao(&self, id: i32) -> Option<usize> {
+        self.itens.iter().position(|i| i.id == id)
+    }
+
+    /// Id negativo ainda não usado, para um item incluído antes da resposta da API.
+    pub fn id_provisorio(&self) -> i32 {
+        self.itens.iter().map(|i| i.id).min().unwrap_or(0).min(0) - 1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ItemsAction {
+    Load,
+    Loaded(ListarResponse),
+    /// Página salva, usada quando a API não responde.
+    LoadedOffline(ListarResponse),
+    Failed(String),
+    Sort(String),
+    Paginate(i64),
+    PageSize(i64),
+    Search(String),
+    /// Categoria escolhida no seletor; vazia mostra todas.
+    Category(String),
+    Filter(Filtros),
+    /// Substitui a consulta inteira, por exemplo ao voltar no histórico.
+    Restore(ListarParams),
+    /// Inclui o item na posição que ele ocupa na página, sem recarregar, se ele fizer parte da consulta.
+    Created(Item),
+    /// Troca o item provisório pelo devolvido pela API, na posição que ele ocupa na consulta.
+    Reconciled { provisorio: i32, item: Item },
+    Updated(Item),
+    Deleted(i32),
+    /// Devolve itens removidos às posições que ocupavam na página.
+    Restored(Vec<(usize, Item)>),
+    /// Itens criados fora da lista, como na importação; a página é recarregada.
+    CreatedMany(Vec<Item>),
+    UpdatedMany(Vec<Item>),
+    DeletedMany(Vec<i32>),
+    /// Uma alteração foi para a fila; informa o tamanho dela.
+    Queued(usize),
+    Sync,
+    Synced(Sincronizacao),
+    DismissConflicts,
+    ToggleSelected(Item),
+    /// Marca todos os itens da página ou, se já estiverem todos marcados, desmarca.
+    TogglePage,
+    SelectAll(Vec<Item>),
+    ClearSelection,
+}
+
+impl Reducible for ItemsState {
+    type Action = ItemsAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut estado = (*self).clone();
+
+        match action {
+            ItemsAction::Load => {
+                estado.carregando = true;
+                estado.erro = None;
+            }
+            ItemsAction::Loaded(res) => {
+                estado.itens = res.itens;
+                estado.total = res.total;
+                estado.total_paginas = res.total_paginas;
+                estado.carregando = false;
+                estado.erro = None;
+                estado.offline = false;
+            }
+            ItemsAction::LoadedOffline(res) => {
+                let carregado = self.reduce(ItemsAction::Loaded(res));
+                return Rc::new(ItemsState { offline: true, ..(*carregado).clone() });
+            }
+            ItemsAction::Failed(msg) => {
+                estado.carregando = false;
+                estado.erro = Some(msg);
+            }
+            ItemsAction::Sort(coluna) => {
+                estado.consulta = estado.consulta.ordenado_por(&coluna);
+            }
+            ItemsAction::Paginate(pagina) => {
+                estado.consulta = estado.consulta.na_pagina(pagina);
+            }
+            ItemsAction::PageSize(por_pagina) => {
+                estado.consulta = estado.consulta.com_por_pagina(por_pagina);
+            }
+            ItemsAction::Search(busca) => {
+                if busca != estado.consulta.busca {
+                    estado.selecionados.clear();
+                }
+                estado.consulta = estado.consulta.com_busca(&busca);
+            }
+            ItemsAction::Category(categoria) => {
+                if categoria != estado.consulta.categoria {
+                    estado.selecionados.clear();
+                }
+                estado.consulta = estado.consulta.com_categoria(&categoria);
+            }
+            ItemsAction::Filter(filtros) => {
+                if filtros != Filtros::de_consulta(&estado.consulta) {
+                    estado.selecionados.clear();
+                }
+                estado.consulta = estado.consulta.com_filtros(&filtros);
+            }
+            ItemsAction::Restore(consulta) => {
+                if consulta == estado.consulta {
+                    return self;
+                }
+                estado.consulta = consulta;
+            }
+            ItemsAction::Created(item) => {
+                let consulta = &estado.consulta;
+                let por_pagina = consulta.por_pagina.max(1);
+                match consulta.confere(&item) {
+                    Some(false) => {}
+                    // Com busca por texto, só a API sabe se o item entra; um provisório fica visível até ela responder.
+                    None if !item.provisorio() => estado.revisao += 1,
+                    _ => {
+                        estado.total += 1;
+                        estado.total_paginas = (estado.total + por_pagina - 1) / por_pagina;
+                        let posicao = estado
+                            .itens
+                            .iter()
+                            .position(|i| consulta.compara(&item, i).is_lt())
+                            .unwrap_or(estado.itens.len());
+                        if posicao == 0 && consulta.pagina > 1 {
+                            // Pertence a uma página anterior, que empurra o último item dela para esta.
+                            estado.revisao += 1;
+                        } else if posicao < por_pagina as usize {
+                            estado.itens.insert(posicao, item);
+                            estado.itens.truncate(por_pagina as usize);
+                        }
+                    }
+                }
+            }
+            ItemsAction::Reconciled { provisorio, item } => {
+                // O item da API pode ter outra posição (o id definitivo) ou não passar mais na consulta.
+                let Some(posicao) = estado.posicao(provisorio) else {
+                    estado.revisao += 1;
+                    return Rc::new(estado);
+                };
+                estado.itens.remove(posicao);
+                estado.total -= 1;
+                // O provisório pode ter empurrado um item para fora desta página; sem ele, ela fica incompleta.
+                let por_pagina = estado.consulta.por_pagina.max(1);
+                if (estado.itens.len() as i64) < por_pagina && estado.consulta.pagina * por_pagina <= estado.total {
+                    estado.revisao += 1;
+                }
+                return Rc::new(estado).reduce(ItemsAction::Created(item));
+            }
+            ItemsAction::CreatedMany(itens) => {
+                estado.total += itens.len() as i64;
+                estado.revisao += 1;
+            }
+            ItemsAction::Updated(item) => return self.reduce(ItemsAction::UpdatedMany(vec![item])),
+            ItemsAction::Deleted(id) => return self.reduce(ItemsAction::DeletedMany(vec![id])),
+            ItemsAction::UpdatedMany(itens) => {
+                for item in itens {
+                    if let Some(atual) = estado.itens.iter_mut().find(|i| i.id == item.id) {
+                        *atual = item.clone();
+                    }
+                    if let Some(marcado) = estado.selecionados.get_mut(&item.id) {
+                        *marcado = item;
+                    }
+                }
+            }
+            ItemsAction::DeletedMany(ids) => {
+                let antes = estado.itens.len();
+                estado.itens.retain(|i| !ids.contains(&i.id));
+                estado.total = (estado.total - ids.len() as i64).max(0);
+                for id in &ids {
+                    estado.selecionados.remove(id);
+                }
+                // Itens de outras páginas mudam a composição desta; uma página esvaziada precisa da seguinte.
+                let fora_da_pagina = antes - estado.itens.len() < ids.len();
+                if fora_da_pagina || (estado.itens.is_empty() && estado.total > 0) {
+                    estado.revisao += 1;
+                }
+            }
+            ItemsAction::Restored(itens) => {
+                estado.total += itens.len() as i64;
+                for (posicao, item) in itens {
+                    let posicao = posicao.min(estado.itens.len());
+                    estado.itens.insert(posicao, item);
+                }
+            }
+            ItemsAction::Queued(pendentes) => {
+                estado.pendentes = pendentes;
+            }
+            ItemsAction::Sync => {
+                estado.sincronia += 1;
+            }
+            ItemsAction::Synced(res) => {
+                estado.pendentes = res.pendentes;
+                if res.enviadas > 0 || !res.conflitos.is_empty() {
+                    estado.revisao += 1;
+                }
+                estado.conflitos.extend(res.conflitos);
+            }
+            ItemsAction::DismissConflicts => {
+                estado.conflitos.clear();
+            }
+            ItemsAction::ToggleSelected(item) => {
+                if estado.selecionados.remove(&item.id).is_none() {
+                    estado.selecionados.insert(item.id, item);
+                }
+            }
+            ItemsAction::TogglePage => {
+                if estado.pagina_selecionada() {
+                    for item in &estado.itens {
+                        estado.selecionados.remove(&item.id);
+                    }
+                } else {
+                    for item in &estado.itens {
+                        estado.selecionados.insert(item.id, item.clone());
+                    }
+                }
+            }
+            ItemsAction::SelectAll(itens) => {
+                estado.selecionados.extend(itens.into_iter().map(|i| (i.id, i)));
+            }
+            ItemsAction::ClearSelection => {
+                estado.selecionados.clear();
+            }
+        }
+
+        Rc::new(estado)
+    }
+}
+
+pub type ItemsStore = UseReducerHandle<ItemsState>;
+
+#[hook]
+pub fn use_items_store() -> ItemsStore {
+    use_context::<ItemsStore>().expect("use_items_store chamado fora de um ItemsProvider")
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ItemsProviderProps {
+    pub client: ItensClient,
+    #[prop_or_default]
+    pub consulta_inicial: ListarParams,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Cria o `ItemsStore` e recarrega a página sempre que a consulta ou a revisão mudam.
+/// A requisição anterior é abortada, então só a resposta mais recente chega a `itens`.
+/// Sem conexão, usa a cópia salva da página; as alterações pendentes são reenviadas ao
+/// montar, quando o navegador volta a ficar online e a cada `Sync`.
+#[function_component(ItemsProvider)]
+pub fn items_provider(props: &ItemsProviderProps) -> Html {
+    let consulta_inicial = props.consulta_inicial.clone();
+    let store = use_reducer(move || ItemsState::new(consulta_inicial));
+
+    {
+        let dispatcher = store.dispatcher();
+        let deps = (
+            store.consulta.clone(),
+            store.revisao,
+            props.client.clone(),
+        );
+
+        use_effect_with(deps, move |(consulta, _, client)| {
+            let consulta = consulta.clone();
+            let client = client.clone();
+            let controller = web_sys::AbortController::new().ok();
+            let sinal = controller.as_ref().map(|c| c.signal());
+
+            wasm_bindgen_futures::spawn_local(async move {
+                dispatcher.dispatch(ItemsAction::Load);
+
+                let resultado = match &sinal {
+                    Some(sinal) => client.list_cancelavel(&consulta, sinal).await,
+                    None => client.list(&consulta).await,
+                };
+                if sinal.is_some_and(|s| s.aborted()) {
+                    return;
+                }
+                match resultado {
+                    Ok(res) => {
+                        dispatcher.dispatch(ItemsAction::Loaded(res.clone()));
+                        offline::guardar_pagina(&consulta, &res).await;
+                    }
+                    Err(ApiError::Rede(msg)) => match offline::pagina_salva(&consulta).await {
+                        Some(res) => dispatcher.dispatch(ItemsAction::LoadedOffline(res)),
+                        None => dispatcher.dispatch(ItemsAction::Failed(erro_ao_buscar(ApiError::Rede(msg)))),
+                    },
+                    Err(e) => dispatcher.dispatch(ItemsAction::Failed(erro_ao_buscar(e))),
+                }
+            });
+
+            move || {
+                if let Some(controller) = controller {
+                    controller.abort();
+                }
+            }
+        });
+    }
+
+    {
+        let dispatcher = store.dispatcher();
+        // Duas sincronizações ao mesmo tempo reenviariam a mesma fila. Um pedido que chega durante
+        // o envio é atendido quando ele termina.
+        let sincronizando = use_mut_ref(|| false);
+        let repetir = use_mut_ref(|| false);
+        use_effect_with((store.sincronia, props.client.clone()), move |(_, client)| {
+            if *sincronizando.borrow() {
+                *repetir.borrow_mut() = true;
+            } else {
+                *sincronizando.borrow_mut() = true;
+                let client = client.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let resultado = offline::sincronizar(&client).await;
+                    *sincronizando.borrow_mut() = false;
+                    dispatcher.dispatch(ItemsAction::Synced(resultado));
+                    if repetir.replace(false) {
+                        dispatcher.dispatch(ItemsAction::Sync);
+                    }
+                });
+            }
+            || ()
+        });
+    }
+
+    {
+        let dispatcher = store.dispatcher();
+        use_effect_with((), move |_| {
+            let ao_reconectar = Closure::<dyn Fn()>::new(move || dispatcher.dispatch(ItemsAction::Sync));
+            let window = web_sys::window();
+            if let Some(window) = &window {
+                let _ = window.add_event_listener_with_callback("online", ao_reconectar.as_ref().unchecked_ref());
+            }
+            move || {
+                if let Some(window) = window {
+                    let _ = window.remove_event_listener_with_callback("online", ao_reconectar.as_ref().unchecked_ref());
+                }
+            }
+        });
+    }
+
+    html! {
+        <ContextProvider<ItemsStore> context={store}>
+            { props.children.clone() }
+        </ContextProvider<ItemsStore>>
+    }
+}
+
+fn erro_ao_buscar(erro: ApiError) -> String {
+    i18n::t_args("lista-erro-buscar", &fluent_args!["erro" => erro.to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dinheiro::Dinheiro;
+
+    fn item(id: i32, nome: &str, preco: f64) -> Item {
+        Item {
+            id,
+            nome: nome.into(),
+            preco: Dinheiro::de_decimal(preco),
+            ..Item::default()
+        }
+    }
+
+    fn carregado() -> Rc<ItemsState> {
+        Rc::new(ItemsState::default()).reduce(ItemsAction::Loaded(ListarResponse {
+            itens: vec![item(1, "Café", 19.9), item(2, "Chá", 9.5)],
+            total: 2,
+            pagina: 1,
+            por_pagina: 10,
+            total_paginas: 1,
+        }))
+    }
+
+    #[test]
+    fn load_e_loaded_controlam_carregamento() {
+        let estado = Rc::new(ItemsState::default()).reduce(ItemsAction::Failed("falhou".into()));
+        let estado = estado.reduce(ItemsAction::Load);
+        assert!(estado.carregando);
+        assert_eq!(estado.erro, None);
+
+        let estado = estado.reduce(ItemsAction::Loaded(ListarResponse {
+            itens: vec![item(1, "Café", 19.9)],
+            total: 11,
+            pagina: 1,
+            por_pagina: 10,
+            total_paginas: 2,
+        }));
+        assert!(!estado.carregando);
+        assert_eq!(estado.itens.len(), 1);
+        assert_eq!((estado.total, estado.total_paginas), (11, 2));
+    }
+
+    #[test]
+    fn failed_guarda_a_mensagem() {
+        let estado = Rc::new(ItemsState::default())
+            .reduce(ItemsAction::Load)
+            .reduce(ItemsAction::Failed("Erro ao buscar itens".into()));
+
+        assert!(!estado.carregando);
+        assert_eq!(estado.erro.as_deref(), Some("Erro ao buscar itens"));
+    }
+
+    #[test]
+    fn pagina_salva_e_fila_de_alteracoes() {
+        let estado = carregado().reduce(ItemsAction::LoadedOffline(ListarResponse {
+            itens: vec![item(1, "Café", 19.9)],
+            total: 1,
+            pagina: 1,
+            por_pagina: 10,
+            total_paginas: 1,
+        }));
+        assert!(estado.offline);
+        assert_eq!(estado.itens.len(), 1);
+
+        let estado = estado.reduce(ItemsAction::Queued(2)).reduce(ItemsAction::Sync);
+        assert_eq!((estado.pendentes, estado.sincronia), (2, 1));
+
+        let conflito = Conflito {
+            operacao: offline::Operacao::Remover { original: item(1, "Café", 19.9) },
+            motivo: "o item foi alterado por outra pessoa".into(),
+        };
+        let estado = estado.reduce(ItemsAction::Synced(Sincronizacao {
+            enviadas: 1,
+            conflitos: vec![conflito],
+            pendentes: 0,
+        }));
+        assert_eq!((estado.pendentes, estado.revisao, estado.conflitos.len()), (0, 1, 1));
+        assert_eq!(estado.clone().reduce(ItemsAction::DismissConflicts).conflitos, vec![]);
+
+        let estado = estado.reduce(ItemsAction::Synced(Sincronizacao::default()));
+        assert_eq!(estado.revisao, 1);
+        assert!(!carregado().offline);
+    }
+
+    #[test]
+    fn sort_paginate_e_search_alteram_a_consulta() {
+        let estado = Rc::new(ItemsState::default()).reduce(ItemsAction::Paginate(3));
+        assert_eq!(estado.consulta.pagina, 3);
+
+        let estado = estado.reduce(ItemsAction::Sort("preco".into()));
+        assert_eq!(estado.consulta.ordenar_por, "preco");
+        assert_eq!(estado.consulta.pagina, 1);
+
+        let estado = estado.reduce(ItemsAction::Sort("preco".into()));
+        assert_eq!(estado.consulta.ordem, "desc");
+
+        let estado = estado.reduce(ItemsAction::Paginate(2)).reduce(ItemsAction::Search("cafe".into()));
+        assert_eq!(estado.consulta.busca, "cafe");
+        assert_eq!(estado.consulta.pagina, 1);
+
+        let estado = estado.reduce(ItemsAction::PageSize(50));
+        assert_eq!(estado.consulta.por_pagina, 50);
+
+        let estado = estado.reduce(ItemsAction::Paginate(2)).reduce(ItemsAction::Category("Bebidas".into()));
+        assert_eq!(estado.consulta.categoria, "Bebidas");
+        assert_eq!(estado.consulta.pagina, 1);
+    }
+
+    #[test]
+    fn restore_com_a_mesma_consulta_mantem_o_estado() {
+        let estado = carregado();
+        let restaurado = estado.clone().reduce(ItemsAction::Restore(ListarParams::default()));
+        assert!(Rc::ptr_eq(&estado, &restaurado));
+    }
+
+    #[test]
+    fn mutacoes_atualizam_a_lista_sem_recarregar() {
+        let estado = carregado();
+
+        let estado = estado.reduce(ItemsAction::Updated(item(2, "Chá verde", 12.0)));
+        assert_eq!(estado.itens[1].nome, "Chá verde");
+
+        let estado = estado.reduce(ItemsAction::Deleted(1));
+        assert_eq!(estado.itens, vec![item(2, "Chá verde", 12.0)]);
+        assert_eq!(estado.total, 1);
+
+        let estado = estado.reduce(ItemsAction::Created(item(3, "Pão", 1.5)));
+        assert_eq!(estado.itens[1], item(3, "Pão", 1.5));
+        assert_eq!(estado.total, 2);
+        assert_eq!(estado.revisao, 0);
+
+        let estado = estado.reduce(ItemsAction::CreatedMany(vec![item(4, "Mel", 30.0)]));
+        assert_eq!(estado.revisao, 1);
+    }
+
+    #[test]
+    fn item_provisorio_e_reconciliado_ou_desfeito() {
+        let estado = carregado();
+        let provisorio = estado.id_provisorio();
+        assert_eq!(provisorio, -1);
+
+        let estado = estado.reduce(ItemsAction::Created(item(provisorio, "Pão", 1.5)));
+        assert!(estado.itens[0].provisorio());
+        assert_eq!(estado.id_provisorio(), -2);
+
+        let reconciliado = estado.clone().reduce(ItemsAction::Reconciled {
+            provisorio,
+            item: item(3, "Pão", 1.5),
+        });
+        assert_eq!(reconciliado.itens.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!((reconciliado.total, reconciliado.revisao), (3, 0));
+
+        let ate_o_id_2 = Filtros {
+            id_max: Some(2),
+            ..Filtros::default()
+        };
+        let fora_do_filtro = carregado()
+            .reduce(ItemsAction::Filter(ate_o_id_2))
+            .reduce(ItemsAction::Created(item(provisorio, "Pão", 1.5)))
+            .reduce(ItemsAction::Reconciled {
+                provisorio,
+                item: item(3, "Pão", 1.5),
+            });
+        assert_eq!((fora_do_filtro.itens.len(), fora_do_filtro.total), (2, 2));
+
+        let desfeito = estado.reduce(ItemsAction::Deleted(provisorio));
+        assert_eq!(desfeito.itens, carregado().itens);
+        assert_eq!((desfeito.total, desfeito.revisao), (2, 0));
+    }
+
+    #[test]
+    fn restored_devolve_o_item_a_posicao_original() {
+        let estado = carregado();
+        let posicao = estado.posicao(1).unwrap();
+
+        let estado = estado.reduce(ItemsAction::Deleted(1)).reduce(ItemsAction::Restored(vec![(posicao, item(1, "Café", 19.9))]));
+        assert_eq!(estado.itens, carregado().itens);
+        assert_eq!(estado.total, 2);
+    }
+
+    #[test]
+    fn created_respeita_o_tamanho_da_pagina() {
+        let estado = carregado()
+            .reduce(ItemsAction::PageSize(2))
+            .reduce(ItemsAction::Created(item(3, "Pão", 1.5)));
+        assert_eq!(estado.itens.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!((estado.total, estado.total_paginas, estado.revisao), (3, 2, 0));
+    }
+
+    #[test]
+    fn created_segue_a_ordenacao_da_consulta() {
+        let estado = carregado()
+            .reduce(ItemsAction::Sort("preco".into()))
+            .reduce(ItemsAction::Sort("preco".into()))
+            .reduce(ItemsAction::Created(item(3, "Pão", 12.0)));
+        assert_eq!(estado.itens.iter().map(|i| i.id).collect::<Vec<_>>(), vec![1, 3, 2]);
+
+        let mut segunda = (*carregado()).clone();
+        segunda.consulta.pagina = 2;
+        let estado = Rc::new(segunda).reduce(ItemsAction::Created(item(0, "Açúcar", 4.0)));
+        assert_eq!((estado.itens.len(), estado.revisao), (2, 1));
+    }
+
+    #[test]
+    fn created_fora_dos_filtros_nao_entra_na_pagina() {
+        let filtros = Filtros {
+            preco_max: Some(Dinheiro::de_centavos(1000)),
+            ..Filtros::default()
+        };
+        let estado = carregado().reduce(ItemsAction::Filter(filtros));
+        let estado = estado.reduce(ItemsAction::Created(item(3, "Mel", 30.0)));
+        assert_eq!((estado.itens.len(), estado.total, estado.revisao), (2, 2, 0));
+
+        let estado = estado.reduce(ItemsAction::Created(item(4, "Pão", 1.5)));
+        assert_eq!((estado.itens.len(), estado.total), (3, 3));
+
+        let com_busca = carregado().reduce(ItemsAction::Search("pão".into()));
+        let estado = com_busca.clone().reduce(ItemsAction::Created(item(3, "Pão", 1.5)));
+        assert_eq!((estado.itens.len(), estado.revisao), (2, 1));
+        let estado = com_busca.reduce(ItemsAction::Created(item(-1, "Pão", 1.5)));
+        assert_eq!((estado.itens[0].id, estado.revisao), (-1, 0));
+    }
+
+    #[test]
+    fn remover_a_pagina_inteira_recarrega() {
+        let mut inicial = (*carregado()).clone();
+        inicial.total = 12;
+        let estado = Rc::new(inicial).reduce(ItemsAction::DeletedMany(vec![1, 2]));
+        assert_eq!((estado.total, estado.revisao), (10, 1));
+    }
+
+    #[test]
+    fn selecao_por_item_e_por_pagina() {
+        let estado = carregado().reduce(ItemsAction::ToggleSelected(item(1, "Café", 19.9)));
+        assert!(estado.selecionado(1));
+        assert!(!estado.pagina_selecionada());
+
+        let estado = estado.reduce(ItemsAction::TogglePage);
+        assert!(estado.pagina_selecionada());
+        assert_eq!(estado.selecionados.len(), 2);
+
+        let estado = estado.reduce(ItemsAction::TogglePage);
+        assert!(estado.selecionados.is_empty());
+
+        let estado = estado
+            .reduce(ItemsAction::SelectAll(vec![item(1, "Café", 19.9), item(7, "Mel", 30.0)]))
+            .reduce(ItemsAction::ToggleSelected(item(7, "Mel", 30.0)));
+        assert_eq!(estado.selecionados.keys().copied().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn lote_atualiza_selecao_e_nova_busca_limpa() {
+        let mut inicial = (*carregado()).clone();
+        inicial.total = 3;
+        let estado = Rc::new(inicial)
+            .reduce(ItemsAction::SelectAll(vec![item(1, "Café", 19.9), item(2, "Chá", 9.5), item(9, "Pão", 1.5)]))
+            .reduce(ItemsAction::UpdatedMany(vec![item(2, "Chá", 10.45)]))
+            .reduce(ItemsAction::DeletedMany(vec![1, 9]));
+
+        assert_eq!(estado.itens, vec![item(2, "Chá", 10.45)]);
+        assert_eq!(estado.total, 1);
+        assert_eq!(estado.selecionados.values().cloned().collect::<Vec<_>>(), vec![item(2, "Chá", 10.45)]);
+        assert_eq!(estado.revisao, 1);
+
+        let estado = estado.reduce(ItemsAction::Paginate(1));
+        assert_eq!(estado.selecionados.len(), 1);
+        let estado = estado.reduce(ItemsAction::Search("chá".into()));
+        assert!(estado.selecionados.is_empty());
+    }
+
+    #[test]
+    fn filtro_altera_a_consulta_e_limpa_a_selecao() {
+        let filtros = Filtros {
+            preco_max: Some(Dinheiro::de_centavos(1000)),
+            ..Filtros::default()
+        };
+        let estado = carregado()
+            .reduce(ItemsAction::Paginate(2))
+            .reduce(ItemsAction::TogglePage)
+            .reduce(ItemsAction::Filter(filtros));
+
+        assert_eq!(estado.consulta.preco_max, Some(Dinheiro::de_centavos(1000)));
+        assert_eq!(estado.consulta.pagina, 1);
+        assert!(estado.selecionados.is_empty());
+    }
+}
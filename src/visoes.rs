@@ -0,0 +1,145 @@
+//! Visões salvas da lista: combinações nomeadas de busca, filtros, ordenação e itens por página.
+
+use crate::api::ListarParams;
+use crate::i18n::{self, fluent_args};
+
+const CHAVE_STORAGE: &str = "rust-app-sample:visoes";
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct VisaoSalva {
+    pub nome: String,
+    /// Sempre na primeira página.
+    pub consulta: ListarParams,
+}
+
+impl VisaoSalva {
+    pub fn new(nome: &str, consulta: &ListarParams) -> Self {
+        Self {
+            nome: nome.trim().to_string(),
+            consulta: consulta.na_pagina(1),
+        }
+    }
+
+    /// Verdadeiro se a consulta atual é a desta visão, em qualquer página.
+    pub fn aplicada_em(&self, consulta: &ListarParams) -> bool {
+        self.consulta == consulta.na_pagina(1)
+    }
+
+    /// Nomes iguais sem diferenciar maiúsculas nem espaços nas pontas.
+    fn tem_nome(&self, nome: &str) -> bool {
+        self.nome.to_lowercase() == nome.trim().to_lowercase()
+    }
+}
+
+/// Inclui a visão ou substitui a que tem o mesmo nome (sem diferenciar maiúsculas).
+pub fn salvar(visoes: &[VisaoSalva], visao: VisaoSalva) -> Vec<VisaoSalva> {
+    let mut novas = visoes.to_vec();
+    match novas.iter_mut().find(|v| v.tem_nome(&visao.nome)) {
+        Some(existente) => *existente = visao,
+        None => novas.push(visao),
+    }
+    novas
+}
+
+/// Tira a visão com esse nome, com a mesma regra de [`salvar`].
+pub fn remover(visoes: &[VisaoSalva], nome: &str) -> Vec<VisaoSalva> {
+    visoes.iter().filter(|v| !v.tem_nome(nome)).cloned().collect()
+}
+
+pub fn para_json(visoes: &[VisaoSalva]) -> String {
+    serde_json::to_string_pretty(visoes).unwrap_or_default()
+}
+
+/// Junta as visões de um JSON exportado às existentes. Devolve a lista nova e quantas foram importadas.
+pub fn importar_json(visoes: &[VisaoSalva], texto: &str) -> Result<(Vec<VisaoSalva>, usize), String> {
+    let importadas: Vec<VisaoSalva> =
+        serde_json::from_str(texto).map_err(|e| i18n::t_args("visoes-arquivo-invalido", &fluent_args!["erro" => e.to_string()]))?;
+    let validas: Vec<VisaoSalva> = importadas
+        .into_iter()
+        .filter(|v| !v.nome.trim().is_empty())
+        .map(|v| VisaoSalva::new(&v.nome, &v.consulta.normalizado()))
+        .collect();
+    let quantidade = validas.len();
+    let novas = validas.into_iter().fold(visoes.to_vec(), |acc, v| salvar(&acc, v));
+    Ok((novas, quantidade))
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Visões gravadas no `localStorage`; vazio se não houver nada ou o conteúdo for inválido.
+pub fn carregar() -> Vec<VisaoSalva> {
+    storage()
+        .and_then(|s| s.get_item(CHAVE_STORAGE).ok()?)
+        .and_then(|texto| serde_json::from_str(&texto).ok())
+        .unwrap_or_default()
+}
+
+pub fn gravar(visoes: &[VisaoSalva]) {
+    if let Some(storage) = storage() {
+        let _ = storage.set_item(CHAVE_STORAGE, &serde_json::to_string(visoes).unwrap_or_default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mais_caros() -> ListarParams {
+        ListarParams::default().ordenado_por("preco").ordenado_por("preco").com_por_pagina(50)
+    }
+
+    #[test]
+    fn visao_guarda_a_consulta_na_primeira_pagina() {
+        let visao = VisaoSalva::new("  Mais caros ", &mais_caros().na_pagina(3));
+
+        assert_eq!(visao.nome, "Mais caros");
+        assert_eq!(visao.consulta.pagina, 1);
+        assert!(visao.aplicada_em(&mais_caros().na_pagina(2)));
+        assert!(!visao.aplicada_em(&ListarParams::default()));
+    }
+
+    #[test]
+    fn salvar_com_o_mesmo_nome_substitui() {
+        let visoes = salvar(&[], VisaoSalva::new("Mais caros", &ListarParams::default()));
+        let visoes = salvar(&visoes, VisaoSalva::new("MAIS CAROS", &mais_caros()));
+        let visoes = salvar(&visoes, VisaoSalva::new("Café", &ListarParams::default().com_busca("café")));
+
+        assert_eq!(visoes.len(), 2);
+        assert_eq!(visoes[0].consulta, mais_caros());
+        assert_eq!(remover(&visoes, "Café").len(), 1);
+    }
+
+    #[test]
+    fn remover_compara_o_nome_como_salvar() {
+        let visoes = salvar(&[], VisaoSalva::new("Mais caros", &mais_caros()));
+
+        assert!(remover(&visoes, "MAIS CAROS").is_empty());
+        assert!(remover(&visoes, " mais caros ").is_empty());
+        assert_eq!(remover(&visoes, "Mais baratos").len(), 1);
+    }
+
+    #[test]
+    fn exporta_e_importa_json() {
+        let origem = vec![VisaoSalva::new("Mais caros", &mais_caros())];
+        let json = para_json(&origem);
+        assert!(json.contains(r#""ordem": "desc""#));
+
+        let existentes = vec![VisaoSalva::new("Café", &ListarParams::default().com_busca("café"))];
+        let (visoes, importadas) = importar_json(&existentes, &json).unwrap();
+        assert_eq!(importadas, 1);
+        assert_eq!(visoes.iter().map(|v| v.nome.as_str()).collect::<Vec<_>>(), vec!["Café", "Mais caros"]);
+        assert_eq!(visoes[1].consulta, mais_caros());
+    }
+
+    #[test]
+    fn importacao_normaliza_e_rejeita_arquivos_invalidos() {
+        let json = r#"[{"nome": "Estranha", "consulta": {"ordenar_por": "xyz", "por_pagina": 5000, "pagina": 9}}, {"nome": " ", "consulta": {}}]"#;
+        let (visoes, importadas) = importar_json(&[], json).unwrap();
+
+        assert_eq!(importadas, 1);
+        assert_eq!(visoes[0].consulta, ListarParams::default());
+        assert!(importar_json(&[], "{}").unwrap_err().starts_with("Arquivo de visões inválido"));
+    }
+}
@@ -0,0 +1,195 @@
+//! Gera uma pasta de trabalho XLSX mínima (uma aba, sem compressão) sem dependências externas.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Celula {
+    Texto(String),
+    Inteiro(i64),
+    /// Número exibido com duas casas decimais.
+    Decimal(f64),
+}
+
+pub const MIME: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+pub fn planilha(aba: &str, linhas: &[Vec<Celula>]) -> Vec<u8> {
+    let mut zip = Zip::default();
+    zip.adicionar("[Content_Types].xml", TIPOS);
+    zip.adicionar("_rels/.rels", RELS);
+    zip.adicionar(
+        "xl/workbook.xml",
+        &format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+            escapar(aba)
+        ),
+    );
+    zip.adicionar("xl/_rels/workbook.xml.rels", WORKBOOK_RELS);
+    zip.adicionar("xl/styles.xml", ESTILOS);
+    zip.adicionar("xl/worksheets/sheet1.xml", &aba_xml(linhas));
+    zip.finalizar()
+}
+
+fn aba_xml(linhas: &[Vec<Celula>]) -> String {
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#,
+    );
+    for (i, linha) in linhas.iter().enumerate() {
+        let numero = i + 1;
+        xml.push_str(&format!(r#"<row r="{}">"#, numero));
+        for (j, celula) in linha.iter().enumerate() {
+            let referencia = format!("{}{}", coluna(j), numero);
+            match celula {
+                Celula::Texto(t) => xml.push_str(&format!(
+                    r#"<c r="{}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                    referencia,
+                    escapar(t)
+                )),
+                Celula::Inteiro(n) => xml.push_str(&format!(r#"<c r="{}"><v>{}</v></c>"#, referencia, n)),
+                Celula::Decimal(n) => xml.push_str(&format!(r#"<c r="{}" s="1"><v>{}</v></c>"#, referencia, n)),
+            }
+        }
+        xml.push_str("</row>");
+    }
+    xml.push_str("</sheetData></worksheet>");
+    xml
+}
+
+/// Letra da coluna a partir do índice: 0 → A, 25 → Z, 26 → AA.
+fn coluna(mut indice: usize) -> String {
+    let mut letras = Vec::new();
+    loop {
+        letras.push((b'A' + (indice % 26) as u8) as char);
+        if indice < 26 {
+            break;
+        }
+        indice = indice / 26 - 1;
+    }
+    letras.iter().rev().collect()
+}
+
+fn escapar(texto: &str) -> String {
+    texto
+        .chars()
+        .filter(|&c| c == '\t' || c == '\n' || c == '\r' || c >= ' ')
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const TIPOS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/></Types>"#;
+
+const RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+const ESTILOS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><fonts count="1"><font><sz val="11"/><name val="Calibri"/></font></fonts><fills count="2"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="2"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="4" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/></cellXfs></styleSheet>"#;
+
+/// Arquivo ZIP com entradas armazenadas (método 0), suficiente para o XLSX.
+#[derive(Default)]
+struct Zip {
+    dados: Vec<u8>,
+    central: Vec<u8>,
+    entradas: u16,
+}
+
+impl Zip {
+    fn adicionar(&mut self, nome: &str, conteudo: &str) {
+        let conteudo = conteudo.as_bytes();
+        let crc = crc32(conteudo);
+        let tamanho = conteudo.len() as u32;
+        let deslocamento = self.dados.len() as u32;
+
+        let mut cabecalho = Vec::new();
+        cabecalho.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        cabecalho.extend_from_slice(&20u16.to_le_bytes());
+        cabecalho.extend_from_slice(&0x0800u16.to_le_bytes());
+        cabecalho.extend_from_slice(&0u16.to_le_bytes());
+        cabecalho.extend_from_slice(&0u16.to_le_bytes());
+        cabecalho.extend_from_slice(&0x0021u16.to_le_bytes());
+        cabecalho.extend_from_slice(&crc.to_le_bytes());
+        cabecalho.extend_from_slice(&tamanho.to_le_bytes());
+        cabecalho.extend_from_slice(&tamanho.to_le_bytes());
+        cabecalho.extend_from_slice(&(nome.len() as u16).to_le_bytes());
+        cabecalho.extend_from_slice(&0u16.to_le_bytes());
+
+        self.dados.extend_from_slice(&cabecalho);
+        self.dados.extend_from_slice(nome.as_bytes());
+        self.dados.extend_from_slice(conteudo);
+
+        self.central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        self.central.extend_from_slice(&20u16.to_le_bytes());
+        self.central.extend_from_slice(&cabecalho[4..]);
+        self.central.extend_from_slice(&0u16.to_le_bytes());
+        self.central.extend_from_slice(&0u16.to_le_bytes());
+        self.central.extend_from_slice(&0u16.to_le_bytes());
+        self.central.extend_from_slice(&0u32.to_le_bytes());
+        self.central.extend_from_slice(&deslocamento.to_le_bytes());
+        self.central.extend_from_slice(nome.as_bytes());
+        self.entradas += 1;
+    }
+
+    fn finalizar(mut self) -> Vec<u8> {
+        let inicio_central = self.dados.len() as u32;
+        let tamanho_central = self.central.len() as u32;
+        self.dados.append(&mut self.central);
+        self.dados.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        self.dados.extend_from_slice(&0u16.to_le_bytes());
+        self.dados.extend_from_slice(&0u16.to_le_bytes());
+        self.dados.extend_from_slice(&self.entradas.to_le_bytes());
+        self.dados.extend_from_slice(&self.entradas.to_le_bytes());
+        self.dados.extend_from_slice(&tamanho_central.to_le_bytes());
+        self.dados.extend_from_slice(&inicio_central.to_le_bytes());
+        self.dados.extend_from_slice(&0u16.to_le_bytes());
+        self.dados
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_conhecido() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn letras_das_colunas() {
+        assert_eq!(coluna(0), "A");
+        assert_eq!(coluna(25), "Z");
+        assert_eq!(coluna(26), "AA");
+        assert_eq!(coluna(701), "ZZ");
+    }
+
+    #[test]
+    fn aba_com_tipos_de_celula() {
+        let xml = aba_xml(&[vec![Celula::Texto("Pão & <mel>".into()), Celula::Inteiro(3), Celula::Decimal(9.5)]]);
+        assert!(xml.contains(r#"<c r="A1" t="inlineStr"><is><t xml:space="preserve">Pão &amp; &lt;mel&gt;</t></is></c>"#));
+        assert!(xml.contains(r#"<c r="B1"><v>3</v></c>"#));
+        assert!(xml.contains(r#"<c r="C1" s="1"><v>9.5</v></c>"#));
+    }
+
+    #[test]
+    fn zip_tem_todas_as_partes() {
+        let bytes = planilha("Itens", &[vec![Celula::Texto("a".into())]]);
+
+        assert_eq!(&bytes[..4], b"PK\x03\x04");
+        let fim = &bytes[bytes.len() - 22..];
+        assert_eq!(&fim[..4], b"PK\x05\x06");
+        assert_eq!(u16::from_le_bytes([fim[10], fim[11]]), 6);
+        let texto = String::from_utf8_lossy(&bytes);
+        assert!(texto.contains("xl/worksheets/sheet1.xml"));
+        assert!(texto.contains(r#"<sheet name="Itens""#));
+    }
+}
@@ -0,0 +1,66 @@
+// Service worker: guarda a casca da aplicação para que ela abra sem conexão.
+// Os dados da API não passam por aqui; a aplicação guarda as páginas no IndexedDB.
+const CACHE = "rust-items-v1";
+const CASCA = ["/", "/index.html", "/config.json", "/manifest.webmanifest", "/icon.svg"];
+const DESTINOS = ["script", "style", "font", "image", "manifest"];
+
+self.addEventListener("install", (evento) => {
+  evento.waitUntil(
+    caches
+      .open(CACHE)
+      .then((cache) => cache.addAll(CASCA))
+      .then(() => self.skipWaiting())
+  );
+});
+
+self.addEventListener("activate", (evento) => {
+  evento.waitUntil(
+    caches
+      .keys()
+      .then((nomes) => Promise.all(nomes.filter((nome) => nome !== CACHE).map((nome) => caches.delete(nome))))
+      .then(() => self.clients.claim())
+  );
+});
+
+function guardar(requisicao, resposta) {
+  if (resposta.ok || resposta.type === "opaque") {
+    const copia = resposta.clone();
+    caches.open(CACHE).then((cache) => cache.put(requisicao, copia));
+  }
+  return resposta;
+}
+
+// Rede primeiro; sem conexão, a cópia salva (ou `alternativa`, para as rotas da aplicação).
+function redePrimeiro(requisicao, alternativa) {
+  return fetch(requisicao)
+    .then((resposta) => guardar(requisicao, resposta))
+    .catch(() =>
+      caches
+        .match(requisicao)
+        .then((salva) => salva || (alternativa && caches.match(alternativa)))
+        .then((salva) => salva || Response.error())
+    );
+}
+
+// Cópia salva primeiro: os arquivos gerados pelo Trunk têm hash no nome.
+function cachePrimeiro(requisicao) {
+  return caches
+    .match(requisicao)
+    .then((salva) => salva || fetch(requisicao).then((resposta) => guardar(requisicao, resposta)));
+}
+
+self.addEventListener("fetch", (evento) => {
+  const requisicao = evento.request;
+  if (requisicao.method !== "GET") {
+    return;
+  }
+  const url = new URL(requisicao.url);
+
+  if (requisicao.mode === "navigate") {
+    evento.respondWith(redePrimeiro(requisicao, "/index.html"));
+  } else if (url.origin === self.location.origin && url.pathname === "/config.json") {
+    evento.respondWith(redePrimeiro(requisicao));
+  } else if (DESTINOS.includes(requisicao.destination) || url.pathname.endsWith(".wasm")) {
+    evento.respondWith(cachePrimeiro(requisicao));
+  }
+});
@@ -0,0 +1,1116 @@
+//! Testes de componente do `App` em navegador headless, contra um fake da API.
+//!
+//! `wasm-pack test --headless --firefox` ou
+//! `cargo test --target wasm32-unknown-unknown` (com `CHROMEDRIVER`/`GECKODRIVER` definido).
+
+#![cfg(target_arch = "wasm32")]
+
+mod support;
+
+use std::time::Duration;
+
+use rust_app_sample::{AlteracaoPreco, App, Dinheiro, Item, ItensClient, ListarParams, NovoItem};
+use support::{FakeApi, itens_de_exemplo, token};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, Event, EventInit, HtmlElement, HtmlInputElement};
+use yew::AppHandle;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct Montado {
+    raiz: Element,
+    app: Option<AppHandle<App>>,
+    api: FakeApi,
+}
+
+impl Drop for Montado {
+    fn drop(&mut self) {
+        if let Some(app) = self.app.take() {
+            app.destroy();
+        }
+        self.raiz.remove();
+    }
+}
+
+async fn montar(caminho: &str, itens: Vec<Item>) -> Montado {
+    montar_com(caminho, itens, |_| ()).await
+}
+
+/// Como `montar`, mas `preparar` roda antes de o `App` ser renderizado.
+async fn montar_com(caminho: &str, itens: Vec<Item>, preparar: impl FnOnce(&FakeApi)) -> Montado {
+    let api = FakeApi::instalar(itens);
+    rust_app_sample::offline::limpar().await;
+    let window = web_sys::window().unwrap();
+    let storage = window.local_storage().unwrap().unwrap();
+    storage.clear().unwrap();
+    // Os textos esperados nos testes são os do catálogo pt-BR, qualquer que seja o idioma do navegador.
+    storage.set_item("rust-app-sample:idioma", "pt-BR").unwrap();
+    preparar(&api);
+    window
+        .history()
+        .unwrap()
+        .replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(caminho))
+        .unwrap();
+
+    let document = window.document().unwrap();
+    let raiz = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&raiz).unwrap();
+
+    let app = yew::Renderer::<App>::with_root(raiz.clone()).render();
+    let montado = Montado {
+        raiz,
+        app: Some(app),
+        api,
+    };
+    esperar(|| montado.raiz.child_element_count() > 0).await;
+    montado
+}
+
+async fn esperar(condicao: impl Fn() -> bool) {
+    for _ in 0..200 {
+        if condicao() {
+            return;
+        }
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("condição não atendida a tempo");
+}
+
+impl Montado {
+    fn todos(&self, seletor: &str) -> Vec<Element> {
+        let lista = self.raiz.query_selector_all(seletor).unwrap();
+        (0..lista.length())
+            .filter_map(|i| lista.item(i))
+            .filter_map(|n| n.dyn_into::<Element>().ok())
+            .collect()
+    }
+
+    fn textos(&self, seletor: &str) -> Vec<String> {
+        self.todos(seletor)
+            .iter()
+            .map(|e| e.text_content().unwrap_or_default().trim().to_string())
+            .collect()
+    }
+
+    fn nomes_na_tabela(&self) -> Vec<String> {
+        self.textos("tbody tr td:nth-child(3)")
+    }
+
+    fn texto(&self) -> String {
+        self.raiz.text_content().unwrap_or_default()
+    }
+
+    fn clicar(&self, seletor: &str, texto: &str) {
+        let alvo = self
+            .todos(seletor)
+            .into_iter()
+            .find(|e| e.text_content().unwrap_or_default().trim() == texto)
+            .unwrap_or_else(|| panic!("nenhum `{}` com o texto `{}`", seletor, texto));
+        alvo.unchecked_into::<HtmlElement>().click();
+    }
+
+    fn digitar(&self, seletor: &str, valor: &str) {
+        let input: HtmlInputElement = self.raiz.query_selector(seletor).unwrap().unwrap().unchecked_into();
+        input.set_value(valor);
+        disparar(&input, "input");
+    }
+
+    fn marcar(&self, rotulo: &str) {
+        let seletor = format!("input[type=checkbox][aria-label='{}']", rotulo);
+        let caixa: HtmlInputElement = self.raiz.query_selector(&seletor).unwrap().unwrap().unchecked_into();
+        caixa.click();
+    }
+
+    fn duplo_clique(&self, texto: &str) {
+        let celula = self
+            .todos("tbody td")
+            .into_iter()
+            .find(|e| e.text_content().unwrap_or_default().trim() == texto)
+            .unwrap_or_else(|| panic!("nenhuma célula `{}`", texto));
+        let init = web_sys::MouseEventInit::new();
+        init.set_bubbles(true);
+        let evento = web_sys::MouseEvent::new_with_mouse_event_init_dict("dblclick", &init).unwrap();
+        celula.dispatch_event(&evento).unwrap();
+    }
+
+    fn editor(&self) -> Option<HtmlInputElement> {
+        self.raiz
+            .query_selector("td input[aria-label^='Editar']")
+            .unwrap()
+            .map(|e| e.unchecked_into())
+    }
+
+    fn tecla(&self, tecla: &str, shift: bool) {
+        pressionar(&self.editor().unwrap(), tecla, shift);
+    }
+
+    fn marcar_radio(&self, rotulo: &str) {
+        let alvo = self
+            .todos("label.radio")
+            .into_iter()
+            .find(|e| e.text_content().unwrap_or_default().trim() == rotulo)
+            .and_then(|label| label.query_selector("input").unwrap())
+            .unwrap_or_else(|| panic!("nenhuma opção `{}`", rotulo));
+        alvo.unchecked_into::<HtmlElement>().click();
+    }
+
+    fn enviar_arquivo(&self, nome: &str, conteudo: &str) {
+        let partes = js_sys::Array::of1(&conteudo.into());
+        let arquivo = web_sys::File::new_with_str_sequence(&partes, nome).unwrap();
+        let transferencia = web_sys::DataTransfer::new().unwrap();
+        transferencia.items().add_with_file(&arquivo).unwrap();
+
+        let input: HtmlInputElement = self.raiz.query_selector("input[type=file]").unwrap().unwrap().unchecked_into();
+        input.set_files(transferencia.files().as_ref());
+        disparar(&input, "change");
+    }
+
+    fn selecionar(&self, seletor: &str, valor: &str) {
+        let select: web_sys::HtmlSelectElement = self.raiz.query_selector(seletor).unwrap().unwrap().unchecked_into();
+        select.set_value(valor);
+        disparar(&select, "change");
+    }
+}
+
+fn disparar(alvo: &web_sys::EventTarget, tipo: &str) {
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    let evento = Event::new_with_event_init_dict(tipo, &init).unwrap();
+    alvo.dispatch_event(&evento).unwrap();
+}
+
+fn pressionar(alvo: &web_sys::EventTarget, tecla: &str, shift: bool) {
+    let init = web_sys::KeyboardEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_key(tecla);
+    init.set_shift_key(shift);
+    let evento = web_sys::KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    alvo.dispatch_event(&evento).unwrap();
+}
+
+fn url_atual() -> String {
+    let location = web_sys::window().unwrap().location();
+    format!("{}{}", location.pathname().unwrap(), location.search().unwrap())
+}
+
+#[wasm_bindgen_test]
+async fn lista_a_primeira_pagina_com_total_e_paginacao() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    assert_eq!(app.nomes_na_tabela()[0], "Produto 01");
+    assert!(app.texto().contains("12 itens"));
+    assert_eq!(app.textos(".pagination-link"), vec!["1", "2"]);
+}
+
+#[wasm_bindgen_test]
+async fn lista_vazia_mostra_mensagem() {
+    let app = montar("/itens", Vec::new()).await;
+    esperar(|| app.texto().contains("Nenhum item encontrado.")).await;
+}
+
+#[wasm_bindgen_test]
+async fn busca_filtra_e_vai_para_a_url() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    app.digitar("input[type=text]", "Produto 1");
+    app.clicar("button", "Buscar");
+
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 10", "Produto 11", "Produto 12"]).await;
+    assert!(url_atual().contains("busca=Produto+1"));
+    assert!(app.api.requisicoes().iter().any(|r| r.contains("busca=Produto+1")));
+}
+
+#[wasm_bindgen_test]
+async fn busca_enquanto_digita_apos_uma_pausa() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+    let buscas = || app.api.requisicoes().iter().filter(|r| r.contains("busca=")).count();
+    let historico = || web_sys::window().unwrap().history().unwrap().length().unwrap();
+    let entradas = historico();
+
+    app.digitar("input[type=text]", "Produto");
+    app.digitar("input[type=text]", "Produto 1");
+    assert_eq!(buscas(), 0);
+
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 10", "Produto 11", "Produto 12"]).await;
+    assert_eq!(buscas(), 1);
+    assert!(url_atual().contains("busca=Produto+1"));
+
+    // Cada pausa na digitação troca a entrada do histórico; mudar a ordenação empilha uma nova.
+    app.digitar("input[type=text]", "Produto 0");
+    esperar(|| url_atual().contains("busca=Produto+0")).await;
+    assert_eq!(historico(), entradas);
+    app.clicar("th", "Nome");
+    esperar(|| url_atual().contains("ordenar_por=nome")).await;
+    assert_eq!(historico(), entradas + 1);
+}
+
+#[wasm_bindgen_test]
+async fn enter_busca_sem_esperar() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    app.digitar("input[type=text]", "Produto 11");
+    pressionar(&app.raiz.query_selector("input[type=text]").unwrap().unwrap(), "Enter", false);
+
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 11"]).await;
+    yew::platform::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(app.api.requisicoes().iter().filter(|r| r.contains("busca=")).count(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn resposta_antiga_nao_sobrescreve_a_busca_nova() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+    app.api.atrasar("busca=Produto+0", 200);
+    let campo = app.raiz.query_selector("input[type=text]").unwrap().unwrap();
+
+    app.digitar("input[type=text]", "Produto 0");
+    pressionar(&campo, "Enter", false);
+    esperar(|| app.api.requisicoes().iter().any(|r| r.contains("busca=Produto+0"))).await;
+
+    app.digitar("input[type=text]", "Produto 1");
+    pressionar(&campo, "Enter", false);
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 10", "Produto 11", "Produto 12"]).await;
+
+    yew::platform::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(app.nomes_na_tabela(), vec!["Produto 10", "Produto 11", "Produto 12"]);
+    assert!(!app.texto().contains("Erro ao buscar itens"));
+}
+
+#[wasm_bindgen_test]
+async fn painel_de_filtros_aplica_faixas_e_mostra_etiquetas() {
+    let app = montar("/itens?ordenar_por=preco", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    app.clicar("button", "▸ Filtros");
+    esperar(|| app.todos("input[aria-label='Preço mínimo']").len() == 1).await;
+    app.digitar("input[aria-label='Preço mínimo']", "5");
+    app.digitar("input[aria-label='Preço máximo']", "10,5");
+    app.digitar("input[aria-label='ID final']", "8");
+    app.clicar("button", "Aplicar filtros");
+
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 02", "Produto 08", "Produto 03"]).await;
+    assert_eq!(app.textos(".tag.is-light.is-medium"), vec!["Preço ≥ R$ 5,00", "Preço ≤ R$ 10,50", "ID ≤ 8"]);
+    assert!(url_atual().contains("ordenar_por=preco"));
+    assert!(url_atual().contains("preco_min=5.00"));
+    assert!(app.api.requisicoes().iter().any(|r| r.contains("preco_max=10.50") && r.contains("id_max=8")));
+
+    app.raiz
+        .query_selector("button[aria-label='Remover filtro ID ≤ 8']")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .click();
+    esperar(|| app.nomes_na_tabela().len() == 4).await;
+    assert!(!url_atual().contains("id_max"));
+
+    app.clicar("button", "Limpar filtros");
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+    assert!(app.todos(".tag.is-light.is-medium").is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn modo_do_nome_e_validacao_do_painel() {
+    let app = montar("/itens?busca=produto+1&modo=comeca_com", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 10", "Produto 11", "Produto 12"]).await;
+    assert!(app.texto().contains("Nome começa com"));
+
+    app.todos("button[aria-expanded]")[0].unchecked_ref::<HtmlElement>().click();
+    esperar(|| app.todos("select[aria-label='Modo do nome']").len() == 1).await;
+    app.selecionar("select[aria-label='Modo do nome']", "regex");
+    app.digitar("input[aria-label='ID inicial']", "12");
+    app.digitar("input[aria-label='ID final']", "3");
+    app.clicar("button", "Aplicar filtros");
+    esperar(|| app.texto().contains("O ID inicial é maior que o final")).await;
+
+    app.digitar("input[aria-label='ID final']", "");
+    app.clicar("button", "Aplicar filtros");
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 12"]).await;
+    assert!(url_atual().contains("modo=regex"));
+}
+
+#[wasm_bindgen_test]
+async fn clicar_no_cabecalho_alterna_a_ordenacao() {
+    let app = montar("/itens", itens_de_exemplo(5)).await;
+    esperar(|| app.nomes_na_tabela().len() == 5).await;
+
+    app.clicar("th", "Nome");
+    esperar(|| app.textos("th").iter().any(|t| t == "Nome ▲")).await;
+
+    app.clicar("th", "Nome ▲");
+    esperar(|| app.nomes_na_tabela().first().map(String::as_str) == Some("Produto 05")).await;
+    assert!(app.textos("th").iter().any(|t| t == "Nome ▼"));
+    assert!(url_atual().contains("ordenar_por=nome&ordem=desc"));
+}
+
+#[wasm_bindgen_test]
+async fn paginacao_e_itens_por_pagina() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    app.clicar(".pagination-link", "2");
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 11", "Produto 12"]).await;
+    assert!(url_atual().contains("pagina=2"));
+
+    app.selecionar("select", "5");
+    esperar(|| app.nomes_na_tabela().len() == 5).await;
+    assert_eq!(app.textos(".pagination-link"), vec!["1", "2", "3"]);
+}
+
+#[wasm_bindgen_test]
+async fn url_com_consulta_restaura_a_visao() {
+    let app = montar("/itens?ordenar_por=id&ordem=desc&pagina=2&por_pagina=5", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 5).await;
+
+    assert_eq!(app.nomes_na_tabela()[0], "Produto 07");
+    assert!(app.todos(".pagination-link.is-current").iter().any(|e| e.text_content().as_deref() == Some("2")));
+}
+
+#[wasm_bindgen_test]
+async fn inclui_um_item_novo() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.clicar("button", "Incluir novo");
+    esperar(|| url_atual() == "/itens/novo").await;
+
+    app.digitar("input[placeholder='Nome do produto']", "Café");
+    app.digitar("input[placeholder='Ex: 49,90']", "19.90");
+    app.clicar("button", "Salvar");
+
+    esperar(|| app.nomes_na_tabela().contains(&"Café".to_string())).await;
+    assert_eq!(url_atual(), "/itens");
+    assert_eq!(app.api.itens().last().unwrap().preco, Dinheiro::de_centavos(1990));
+}
+
+#[wasm_bindgen_test]
+async fn formulario_destaca_campos_invalidos() {
+    let app = montar("/itens/novo", Vec::new()).await;
+    esperar(|| app.texto().contains("Novo item")).await;
+
+    app.clicar("button", "Salvar");
+    esperar(|| app.todos("input.is-danger").len() == 2).await;
+    assert!(app.texto().contains("Informe o nome do produto"));
+
+    app.digitar("input[placeholder='Nome do produto']", "duplicado");
+    app.digitar("input[placeholder='Ex: 49,90']", "1");
+    app.clicar("button", "Salvar");
+
+    esperar(|| app.texto().contains("Já existe um item com esse nome")).await;
+    assert_eq!(app.todos("input.is-danger").len(), 1);
+    assert!(app.api.itens().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn edita_um_item_a_partir_da_tabela() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.clicar("button", "✏️");
+    esperar(|| url_atual() == "/itens/1/editar").await;
+
+    let nome = || {
+        app.raiz
+            .query_selector("input[placeholder='Nome do produto']")
+            .unwrap()
+            .map(|e| e.unchecked_into::<HtmlInputElement>().value())
+    };
+    esperar(|| nome().as_deref() == Some("Produto 01")).await;
+
+    app.digitar("input[placeholder='Nome do produto']", "Produto editado");
+    app.clicar("button", "Salvar alterações");
+
+    esperar(|| app.nomes_na_tabela().contains(&"Produto editado".to_string())).await;
+    assert_eq!(app.api.itens()[0].nome, "Produto editado");
+}
+
+#[wasm_bindgen_test]
+async fn link_direto_de_edicao_carrega_o_item_pela_api() {
+    let app = montar("/itens/2/editar", itens_de_exemplo(3)).await;
+    esperar(|| app.texto().contains("Editar item")).await;
+
+    let input = || {
+        app.raiz
+            .query_selector("input[placeholder='Nome do produto']")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<HtmlInputElement>()
+    };
+    esperar(|| input().value() == "Produto 02").await;
+    assert!(app.api.requisicoes().iter().any(|r| r == "GET /itens/2"));
+}
+
+#[wasm_bindgen_test]
+async fn config_e_buscado_na_raiz_mesmo_numa_rota_aninhada() {
+    let app = montar("/itens/2/editar", itens_de_exemplo(3)).await;
+    esperar(|| app.api.requisicoes().iter().any(|r| r.ends_with("/config.json"))).await;
+
+    let configs: Vec<String> = app.api.requisicoes().into_iter().filter(|r| r.ends_with("config.json")).collect();
+    assert_eq!(configs, vec!["GET /config.json"]);
+}
+
+#[wasm_bindgen_test]
+async fn remove_um_item_apos_confirmar() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.clicar("button", "🗑️");
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    assert!(app.textos(".modal-card-body").join(" ").contains("Produto 01 · R$ 3,50"));
+    assert_eq!(app.api.itens().len(), 3);
+
+    app.clicar(".modal-card-foot button", "Remover");
+
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 02", "Produto 03"]).await;
+    assert!(app.todos(".modal").is_empty());
+    assert!(app.texto().contains("2 itens"));
+    assert_eq!(app.api.itens().len(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn cancelar_a_confirmacao_mantem_o_item() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.clicar("button", "🗑️");
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Cancelar");
+
+    esperar(|| app.todos(".modal").is_empty()).await;
+    assert!(!app.api.requisicoes().iter().any(|r| r.starts_with("DELETE")));
+    assert_eq!(app.nomes_na_tabela().len(), 3);
+}
+
+#[wasm_bindgen_test]
+async fn desfazer_recria_o_item_removido() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.clicar("button", "🗑️");
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Remover");
+    esperar(|| app.texto().contains("\"Produto 01\" removido.")).await;
+
+    app.clicar("button", "Desfazer");
+
+    esperar(|| app.nomes_na_tabela().contains(&"Produto 01".to_string())).await;
+    let recriado = app.api.itens().into_iter().find(|i| i.nome == "Produto 01").unwrap();
+    assert_eq!((recriado.id, recriado.preco.centavos()), (4, 350));
+    assert!(!app.texto().contains("removido."));
+}
+
+#[wasm_bindgen_test]
+async fn ajuste_de_preco_em_lote_informa_as_falhas() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.marcar("Selecionar a página");
+    esperar(|| app.texto().contains("3 selecionados")).await;
+
+    app.digitar("input[aria-label='Ajuste de preço']", "-4");
+    app.clicar("button", "Ajustar preço");
+
+    esperar(|| app.texto().contains("Ajuste de preço: 2 de 3 itens concluídos, 1 com falha.")).await;
+    assert!(app.texto().contains("#1 Produto 01: o preço ficaria negativo"));
+    let precos: Vec<i64> = app.api.itens().iter().map(|i| i.preco.centavos()).collect();
+    assert_eq!(precos, vec![350, 250, 550]);
+    assert_eq!(app.api.requisicoes().iter().filter(|r| r.starts_with("PUT")).count(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn remove_todos_os_itens_da_busca() {
+    let app = montar("/itens?busca=Produto+1&por_pagina=2", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 2).await;
+
+    app.marcar("Selecionar Produto 10");
+    esperar(|| app.texto().contains("1 selecionado")).await;
+    app.clicar("button", "Selecionar todos os 3 da busca");
+    esperar(|| app.texto().contains("3 selecionados")).await;
+
+    app.clicar("button", "Remover selecionados");
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Remover");
+
+    esperar(|| app.texto().contains("Remoção: 3 de 3 itens concluídos.")).await;
+    esperar(|| app.texto().contains("Nenhum item encontrado.")).await;
+    assert_eq!(app.api.itens().len(), 9);
+    assert!(!app.texto().contains("selecionado"));
+}
+
+#[wasm_bindgen_test]
+async fn importa_csv_com_previa_e_relatorio() {
+    let app = montar("/itens", itens_de_exemplo(2)).await;
+    esperar(|| app.nomes_na_tabela().len() == 2).await;
+
+    app.clicar("button", "Importar CSV");
+    esperar(|| url_atual() == "/itens/importar").await;
+
+    app.enviar_arquivo("precos.csv", "Produto;Preço\nCafé;R$ 19,90\nsem preço;\nduplicado;1,00\nMel;1.030,5\n");
+    esperar(|| app.texto().contains("3 válidas")).await;
+    assert!(app.texto().contains("1 com erro"));
+    assert!(app.texto().contains("Separador detectado: ponto e vírgula"));
+    assert!(app.textos("tbody td").contains(&"Informe o preço".to_string()));
+
+    app.clicar("button", "Importar 3 itens");
+
+    esperar(|| app.texto().contains("2 de 3 itens importados, 1 com falha. 1 linha com erro não foi enviada.")).await;
+    assert!(app.texto().contains("Linha 4 (duplicado): dados inválidos"));
+    let novos: Vec<(String, i64)> = app.api.itens().into_iter().skip(2).map(|i| (i.nome, i.preco.centavos())).collect();
+    assert_eq!(novos, vec![("Café".to_string(), 1990), ("Mel".to_string(), 103_050)]);
+
+    app.clicar("button", "Voltar para a lista");
+    esperar(|| app.nomes_na_tabela().len() == 4).await;
+}
+
+#[wasm_bindgen_test]
+async fn exportar_todas_as_paginas_percorre_a_busca() {
+    let app = montar("/itens?busca=Produto&ordenar_por=nome&ordem=desc", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    app.clicar("button", "Exportar");
+    app.marcar_radio("Todas as páginas");
+    app.clicar("a.dropdown-item", "JSON");
+
+    esperar(|| app.api.requisicoes().iter().any(|r| r.contains("por_pagina=100"))).await;
+    let exportacao = app.api.requisicoes().into_iter().find(|r| r.contains("por_pagina=100")).unwrap();
+    assert!(exportacao.contains("busca=Produto"));
+    assert!(exportacao.contains("ordenar_por=nome&ordem=desc"));
+    esperar(|| !app.todos(".dropdown .button:not(.is-loading)").is_empty()).await;
+    assert!(!app.texto().contains("Falha ao exportar"));
+}
+
+#[wasm_bindgen_test]
+async fn edita_o_nome_na_celula_com_enter() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.duplo_clique("Produto 01");
+    esperar(|| app.editor().is_some()).await;
+    assert_eq!(app.editor().unwrap().value(), "Produto 01");
+
+    app.editor().unwrap().set_value("Café");
+    app.tecla("Enter", false);
+
+    esperar(|| app.nomes_na_tabela()[0] == "Café").await;
+    assert!(app.editor().is_none());
+    assert_eq!(url_atual(), "/itens");
+    assert_eq!(app.api.itens()[0].nome, "Café");
+}
+
+#[wasm_bindgen_test]
+async fn escape_cancela_e_valor_invalido_marca_a_celula() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.duplo_clique("Produto 02");
+    esperar(|| app.editor().is_some()).await;
+    app.editor().unwrap().set_value("Outro nome");
+    app.tecla("Escape", false);
+    esperar(|| app.editor().is_none()).await;
+    assert_eq!(app.nomes_na_tabela()[1], "Produto 02");
+
+    app.duplo_clique("R$ 3,50");
+    esperar(|| app.editor().is_some()).await;
+    app.editor().unwrap().set_value("abc");
+    app.tecla("Enter", false);
+
+    esperar(|| app.todos("td.has-background-danger-light").len() == 1).await;
+    assert!(app.texto().contains("Preço inválido"));
+    assert!(!app.api.requisicoes().iter().any(|r| r.starts_with("PUT")));
+}
+
+#[wasm_bindgen_test]
+async fn tab_salva_e_vai_para_o_preco() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.duplo_clique("Produto 01");
+    esperar(|| app.editor().is_some()).await;
+    assert_eq!(app.editor().unwrap().get_attribute("aria-label").as_deref(), Some("Editar nome"));
+    app.editor().unwrap().set_value("Pão");
+    app.tecla("Tab", false);
+
+    esperar(|| app.editor().map(|e| e.value()) == Some("3,50".to_string())).await;
+    assert_eq!(app.editor().unwrap().get_attribute("aria-label").as_deref(), Some("Editar preço"));
+    app.editor().unwrap().set_value("4,25");
+    app.tecla("Enter", false);
+
+    esperar(|| app.api.itens()[0].preco == Dinheiro::de_centavos(425)).await;
+    esperar(|| app.textos("tbody td").contains(&"R$ 4,25".to_string())).await;
+    assert_eq!(app.api.itens()[0].nome, "Pão");
+    assert_eq!(app.api.requisicoes().iter().filter(|r| r.starts_with("PUT /itens/1")).count(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn erro_da_api_aparece_na_celula() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.duplo_clique("Produto 03");
+    esperar(|| app.editor().is_some()).await;
+    app.editor().unwrap().set_value("duplicado");
+    app.tecla("Enter", false);
+
+    esperar(|| app.texto().contains("Já existe um item com esse nome")).await;
+    assert_eq!(app.todos("td.has-background-danger-light").len(), 1);
+    assert_eq!(app.nomes_na_tabela()[2], "Produto 03");
+    assert_eq!(app.api.itens()[2].nome, "Produto 03");
+}
+
+fn listagens(app: &Montado) -> usize {
+    app.api.requisicoes().iter().filter(|r| r.starts_with("GET /itens?")).count()
+}
+
+#[wasm_bindgen_test]
+async fn alteracoes_aparecem_sem_recarregar_a_pagina() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+    let antes = listagens(&app);
+
+    app.duplo_clique("Produto 02");
+    esperar(|| app.editor().is_some()).await;
+    app.editor().unwrap().set_value("Chá");
+    app.tecla("Enter", false);
+    esperar(|| app.nomes_na_tabela()[1] == "Chá").await;
+
+    app.clicar("button", "🗑️");
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Remover");
+    esperar(|| app.nomes_na_tabela() == vec!["Chá", "Produto 03"]).await;
+
+    esperar(|| app.texto().contains("\"Produto 01\" removido.")).await;
+    app.clicar("button", "Desfazer");
+    // Com o id definitivo, o item recriado vai para o fim da ordenação por id.
+    esperar(|| app.textos("tbody td").contains(&"4".to_string())).await;
+    assert_eq!(app.nomes_na_tabela(), vec!["Chá", "Produto 03", "Produto 01"]);
+
+    assert!(!app.texto().contains("Carregando..."));
+    assert!(app.texto().contains("3 itens"));
+    assert_eq!(listagens(&app), antes);
+}
+
+#[wasm_bindgen_test]
+async fn falha_ao_remover_devolve_o_item_a_lista() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+    app.api.remover_por_fora(2);
+
+    let lixeiras: Vec<_> = app
+        .todos("button")
+        .into_iter()
+        .filter(|b| b.text_content().as_deref() == Some("🗑️"))
+        .collect();
+    lixeiras[1].unchecked_ref::<web_sys::HtmlElement>().click();
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Remover");
+
+    esperar(|| app.texto().contains("Falha ao remover")).await;
+    assert_eq!(app.nomes_na_tabela(), vec!["Produto 01", "Produto 02", "Produto 03"]);
+    assert!(app.texto().contains("3 itens"));
+    assert!(!app.texto().contains("removido."));
+}
+
+#[wasm_bindgen_test]
+async fn salva_e_aplica_uma_visao() {
+    let app = montar("/itens?ordenar_por=preco&ordem=desc&por_pagina=50", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 12).await;
+
+    app.clicar("button", "Visões");
+    app.digitar("input[aria-label='Nome da visão']", "Mais caros");
+    app.clicar("button", "Salvar visão atual");
+    esperar(|| app.textos("button").contains(&"Visão: Mais caros".to_string())).await;
+    assert!(app.texto().contains("Visão \"Mais caros\" salva."));
+
+    let gravado = web_sys::window().unwrap().local_storage().unwrap().unwrap().get_item("rust-app-sample:visoes").unwrap().unwrap();
+    assert!(gravado.contains("Mais caros") && gravado.contains("\"por_pagina\":50"));
+
+    app.clicar("th", "ID");
+    esperar(|| app.nomes_na_tabela()[0] == "Produto 01").await;
+    assert!(app.textos("button").contains(&"Visões".to_string()));
+
+    app.clicar("button", "Visões");
+    app.clicar(".dropdown-item a", "Mais caros");
+    esperar(|| app.nomes_na_tabela()[0] == "Produto 11").await;
+    assert!(url_atual().contains("ordem=desc"));
+    assert!(url_atual().contains("por_pagina=50"));
+}
+
+#[wasm_bindgen_test]
+async fn importa_visoes_de_um_json() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+
+    app.enviar_arquivo(
+        "visoes.json",
+        r#"[{"nome": "Baratos", "consulta": {"ordenar_por": "preco", "por_pagina": 5}}]"#,
+    );
+    esperar(|| app.texto().contains("1 visão importada.")).await;
+
+    app.clicar("button", "Visões");
+    app.clicar(".dropdown-item a", "Baratos");
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 06", "Produto 12", "Produto 01", "Produto 07", "Produto 02"]).await;
+
+    app.enviar_arquivo("quebrado.json", "não é json");
+    esperar(|| app.texto().contains("Arquivo de visões inválido")).await;
+}
+
+#[wasm_bindgen_test]
+async fn sem_conexao_mostra_a_ultima_copia_da_pagina() {
+    let app = montar("/itens", itens_de_exemplo(12)).await;
+    esperar(|| app.nomes_na_tabela().len() == 10).await;
+    app.api.desconectar();
+
+    app.clicar("th", "Nome");
+    esperar(|| app.texto().contains("Erro ao buscar itens")).await;
+
+    app.clicar("th", "ID");
+    esperar(|| app.texto().contains("Mostrando a última cópia salva desta página.")).await;
+    assert!(!app.texto().contains("Erro ao buscar itens"));
+    assert_eq!(app.nomes_na_tabela().len(), 10);
+    assert_eq!(app.nomes_na_tabela()[0], "Produto 01");
+    assert!(app.texto().contains("12 itens"));
+}
+
+fn remover_pela_lixeira(app: &Montado, linha: usize) {
+    let lixeiras: Vec<_> = app
+        .todos("button")
+        .into_iter()
+        .filter(|b| b.text_content().as_deref() == Some("🗑️"))
+        .collect();
+    lixeiras[linha].unchecked_ref::<HtmlElement>().click();
+}
+
+#[wasm_bindgen_test]
+async fn alteracoes_sem_conexao_sao_enviadas_quando_a_conexao_volta() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+    app.api.desconectar();
+
+    remover_pela_lixeira(&app, 1);
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Remover");
+    esperar(|| app.texto().contains("1 alteração pendente")).await;
+    assert_eq!(app.nomes_na_tabela(), vec!["Produto 01", "Produto 03"]);
+
+    app.duplo_clique("Produto 03");
+    esperar(|| app.editor().is_some()).await;
+    app.editor().unwrap().set_value("Chá");
+    app.tecla("Enter", false);
+    esperar(|| app.texto().contains("2 alterações pendentes")).await;
+    assert_eq!(app.nomes_na_tabela(), vec!["Produto 01", "Chá"]);
+    assert_eq!(app.api.itens().len(), 3);
+
+    app.api.reconectar();
+    disparar(&web_sys::window().unwrap(), "online");
+
+    esperar(|| !app.texto().contains("pendente")).await;
+    let nomes: Vec<String> = app.api.itens().into_iter().map(|i| i.nome).collect();
+    assert_eq!(nomes, vec!["Produto 01", "Chá"]);
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 01", "Chá"]).await;
+}
+
+#[wasm_bindgen_test]
+async fn alteracoes_simultaneas_sem_conexao_entram_todas_na_fila() {
+    let app = montar("/itens", itens_de_exemplo(2)).await;
+    esperar(|| app.nomes_na_tabela().len() == 2).await;
+    app.api.desconectar();
+
+    let client = ItensClient::new("http://localhost:3000");
+    for (provisorio, nome) in [(-1, "Café"), (-2, "Chá"), (-3, "Mel")] {
+        let client = client.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let novo = NovoItem {
+                nome: nome.into(),
+                preco: Dinheiro::de_centavos(100),
+                ..NovoItem::default()
+            };
+            let _ = rust_app_sample::offline::criar(&client, provisorio, &novo).await;
+        });
+    }
+
+    for _ in 0..50 {
+        if rust_app_sample::offline::fila().await.len() == 3 {
+            break;
+        }
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(rust_app_sample::offline::fila().await.len(), 3);
+}
+
+#[wasm_bindgen_test]
+async fn conflito_ao_sincronizar_e_relatado() {
+    let app = montar("/itens", itens_de_exemplo(3)).await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+    app.api.desconectar();
+
+    app.duplo_clique("Produto 03");
+    esperar(|| app.editor().is_some()).await;
+    app.editor().unwrap().set_value("Chá");
+    app.tecla("Enter", false);
+    esperar(|| app.texto().contains("1 alteração pendente")).await;
+
+    app.api.alterar_por_fora(3, "Produto 03 revisado");
+    app.api.reconectar();
+    app.clicar("button", "Sincronizar agora");
+
+    esperar(|| app.texto().contains("Alteração de \"Produto 03\": o item foi alterado por outra pessoa")).await;
+    assert!(!app.texto().contains("pendente"));
+    assert_eq!(app.api.itens()[2].nome, "Produto 03 revisado");
+    esperar(|| app.nomes_na_tabela()[2] == "Produto 03 revisado").await;
+}
+
+fn token_gravado() -> Option<String> {
+    let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+    storage.get_item("rust-app-sample:token").unwrap()
+}
+
+fn gravar_token(token: &str) {
+    let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+    storage.set_item("rust-app-sample:token", token).unwrap();
+}
+
+fn agora_s() -> f64 {
+    js_sys::Date::now() / 1000.0
+}
+
+#[wasm_bindgen_test]
+async fn login_de_leitor_esconde_as_acoes_de_alteracao() {
+    let app = montar_com("/itens", itens_de_exemplo(3), |api| api.exigir_login()).await;
+    esperar(|| url_atual() == "/login").await;
+    esperar(|| app.texto().contains("A API exige login para continuar.")).await;
+
+    app.digitar("#login-usuario", "leitor");
+    app.digitar("#login-senha", "errada");
+    app.clicar("button", "Entrar");
+    esperar(|| app.texto().contains("Usuário ou senha inválidos.")).await;
+
+    app.digitar("#login-senha", "leitor");
+    app.clicar("button", "Entrar");
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    assert_eq!(url_atual(), "/itens");
+    assert!(token_gravado().is_some());
+    assert!(app.texto().contains("leitor"));
+    assert!(!app.texto().contains("Incluir novo"));
+    assert!(!app.texto().contains("Importar CSV"));
+    assert!(app.todos("tbody button").is_empty());
+    assert!(app.todos("tbody input[type=checkbox]").is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn sair_apaga_o_token_e_volta_para_o_login() {
+    let admin = token("admin", &["itens:criar", "itens:editar", "itens:remover"], agora_s() + 3600.0);
+    let app = montar_com("/itens", itens_de_exemplo(3), |api| {
+        api.exigir_login();
+        gravar_token(&admin);
+    })
+    .await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+    assert!(app.texto().contains("Incluir novo"));
+
+    // Uma alteração feita sem conexão não é perdida ao sair: pede confirmação e continua na fila.
+    app.api.desconectar();
+    remover_pela_lixeira(&app, 0);
+    esperar(|| app.todos(".modal.is-active").len() == 1).await;
+    app.clicar(".modal-card-foot button", "Remover");
+    esperar(|| app.texto().contains("1 alteração pendente")).await;
+
+    app.clicar("a", "Sair");
+    esperar(|| app.texto().contains("Sair com alterações pendentes?")).await;
+    assert!(token_gravado().is_some());
+    app.clicar(".modal-card-foot button", "Sair");
+    esperar(|| url_atual() == "/login").await;
+
+    assert_eq!(token_gravado(), None);
+    for _ in 0..50 {
+        if rust_app_sample::offline::pagina_salva(&ListarParams::default()).await.is_none() {
+            break;
+        }
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(rust_app_sample::offline::pagina_salva(&ListarParams::default()).await, None);
+    assert_eq!(rust_app_sample::offline::fila().await.len(), 1);
+    assert!(app.textos("a.navbar-item").contains(&"Entrar".to_string()));
+}
+
+#[wasm_bindgen_test]
+async fn sessao_expirada_leva_ao_login() {
+    let curto = token("admin", &["itens:criar"], agora_s() + 2.0);
+    let app = montar_com("/itens", itens_de_exemplo(3), |api| {
+        api.exigir_login();
+        gravar_token(&curto);
+    })
+    .await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    yew::platform::time::sleep(Duration::from_millis(1500)).await;
+    esperar(|| url_atual() == "/login").await;
+
+    assert!(app.texto().contains("Sua sessão expirou. Entre novamente."));
+    assert_eq!(token_gravado(), None);
+}
+
+fn idioma_gravado() -> Option<String> {
+    let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+    storage.get_item("rust-app-sample:idioma").unwrap()
+}
+
+fn idioma_do_documento() -> Option<String> {
+    web_sys::window().unwrap().document().unwrap().document_element().unwrap().get_attribute("lang")
+}
+
+#[wasm_bindgen_test]
+async fn seletor_de_idioma_traduz_a_interface_e_lembra_a_escolha() {
+    let app = montar("/itens", itens_de_exemplo(1)).await;
+    esperar(|| app.texto().contains("1 item")).await;
+    assert!(app.texto().contains("Incluir novo"));
+
+    app.selecionar("select[aria-label=Idioma]", "en-US");
+    esperar(|| app.texto().contains("Add new")).await;
+
+    assert!(app.texto().contains("1 item"));
+    assert!(app.textos("thead th").contains(&"Price".to_string()));
+    assert!(app.textos("a.navbar-item").contains(&"Items".to_string()));
+    assert_eq!(idioma_gravado().as_deref(), Some("en-US"));
+    assert_eq!(idioma_do_documento().as_deref(), Some("en-US"));
+}
+
+#[wasm_bindgen_test]
+async fn idioma_gravado_e_usado_ao_abrir() {
+    let app = montar_com("/itens", itens_de_exemplo(2), |_| {
+        let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+        storage.set_item("rust-app-sample:idioma", "en-US").unwrap();
+    })
+    .await;
+    esperar(|| app.texto().contains("2 items")).await;
+
+    assert!(app.texto().contains("Manage the items served by the Rust API."));
+    assert!(app.texto().contains("Import CSV"));
+    assert_eq!(idioma_do_documento().as_deref(), Some("en-US"));
+}
+
+#[wasm_bindgen_test]
+async fn preco_segue_o_formato_do_idioma() {
+    let app = montar("/itens", itens_de_exemplo(1)).await;
+    esperar(|| app.textos("tbody td").contains(&"R$ 3,50".to_string())).await;
+
+    app.selecionar("select[aria-label=Idioma]", "en-US");
+    esperar(|| app.textos("tbody td").contains(&"R$3.50".to_string())).await;
+
+    app.clicar("button", "Add new");
+    esperar(|| url_atual() == "/itens/novo").await;
+    app.digitar("input[placeholder='Product name']", "Mel");
+    app.digitar("input[placeholder='E.g. 49.90']", "1,030.555x");
+    let preco: HtmlInputElement = app.raiz.query_selector("input[inputmode=decimal]").unwrap().unwrap().unchecked_into();
+    esperar(|| preco.value() == "1,030.55").await;
+    app.clicar("button", "Save");
+
+    esperar(|| app.textos("tbody td").contains(&"R$1,030.55".to_string())).await;
+    assert_eq!(app.api.itens().last().unwrap().preco, Dinheiro::de_centavos(103_055));
+}
+
+#[wasm_bindgen_test]
+async fn seletor_de_categoria_filtra_a_lista() {
+    let app = montar("/itens", itens_de_exemplo(6)).await;
+    esperar(|| app.todos("select[aria-label=Categoria] option").len() == 3).await;
+
+    app.selecionar("select[aria-label=Categoria]", "Bebidas");
+
+    esperar(|| app.nomes_na_tabela() == vec!["Produto 01", "Produto 03", "Produto 05"]).await;
+    assert!(url_atual().contains("categoria=Bebidas"));
+    assert!(app.texto().contains("3 itens"));
+}
+
+#[wasm_bindgen_test]
+async fn colunas_opcionais_ficam_gravadas() {
+    let app = montar("/itens", itens_de_exemplo(2)).await;
+    esperar(|| app.nomes_na_tabela().len() == 2).await;
+    assert!(!app.textos("thead th").contains(&"Estoque".to_string()));
+
+    app.marcar("Estoque");
+    app.marcar("SKU");
+
+    esperar(|| app.textos("thead th").contains(&"Estoque".to_string())).await;
+    assert!(app.textos("tbody td").contains(&"SKU-002".to_string()));
+    assert!(app.textos("tbody td").contains(&"4".to_string()));
+    let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+    assert_eq!(storage.get_item("rust-app-sample:colunas").unwrap().as_deref(), Some(r#"["estoque","sku"]"#));
+}
+
+#[wasm_bindgen_test]
+async fn formulario_envia_os_campos_opcionais() {
+    let app = montar("/itens/novo", itens_de_exemplo(2)).await;
+    esperar(|| app.todos("datalist option").len() == 2).await;
+
+    app.digitar("input[placeholder='Nome do produto']", "Café");
+    app.digitar("input[placeholder='Ex: 49,90']", "19,90");
+    app.digitar("input[list=categorias-item]", "Mercearia");
+    app.digitar("input[aria-label=Estoque]", "-3");
+    app.clicar("button", "Salvar");
+    esperar(|| app.texto().contains("Estoque inválido")).await;
+
+    app.digitar("input[aria-label=Estoque]", "12");
+    app.clicar("button", "Salvar");
+
+    esperar(|| app.api.itens().len() == 3).await;
+    let criado = app.api.itens().pop().unwrap();
+    assert_eq!(
+        (criado.categoria.as_deref(), criado.sku, criado.estoque, criado.ativo),
+        (Some("Mercearia"), None, Some(12), true)
+    );
+}
+
+#[wasm_bindgen_test]
+async fn detalhe_mostra_o_item_e_o_historico_de_precos() {
+    let app = montar_com("/itens", itens_de_exemplo(3), |api| {
+        let alteracao = |em: &str, centavos| AlteracaoPreco {
+            em: em.to_string(),
+            preco: Dinheiro::de_centavos(centavos),
+        };
+        api.definir_historico(
+            2,
+            vec![
+                alteracao("2025-01-02T12:00:00Z", 500),
+                alteracao("2025-02-01T12:00:00Z", 600),
+                alteracao("2025-03-03T12:00:00Z", 650),
+            ],
+        );
+    })
+    .await;
+    esperar(|| app.nomes_na_tabela().len() == 3).await;
+
+    app.clicar("tbody a", "2");
+    esperar(|| app.texto().contains("Histórico de preços")).await;
+    esperar(|| app.todos("svg circle").len() == 3).await;
+
+    assert_eq!(url_atual(), "/itens/2");
+    assert_eq!(app.textos("h1.title"), vec!["Produto 02"]);
+    assert!(app.textos("td").contains(&"SKU-002".to_string()));
+    assert!(app.textos("td").contains(&"Mercearia".to_string()));
+    assert!(app.api.requisicoes().contains(&"GET /itens/2/historico".to_string()));
+    let variacoes: Vec<String> = app.textos("tbody tr td:nth-child(3)");
+    assert_eq!(variacoes, vec!["+R$ 0,50", "+R$ 1,00", "—"]);
+
+    app.clicar("button", "Editar item");
+    esperar(|| url_atual() == "/itens/2/editar").await;
+}
+
+#[wasm_bindgen_test]
+async fn painel_resume_o_catalogo_com_todas_as_paginas() {
+    let app = montar("/itens", itens_de_exemplo(6)).await;
+    esperar(|| app.nomes_na_tabela().len() == 6).await;
+
+    app.clicar("a.navbar-item", "Painel");
+    esperar(|| app.todos("p.title").len() == 5).await;
+
+    assert_eq!(url_atual(), "/painel");
+    assert_eq!(app.textos("p.title"), vec!["6", "R$ 8,17", "R$ 8,00", "R$ 1,50", "R$ 15,50"]);
+    assert_eq!(app.todos("svg rect").len(), 8);
+    assert_eq!(app.textos("tbody tr td:nth-child(2)"), vec!["Produto 06", "Produto 05", "Produto 04", "Produto 03", "Produto 02"]);
+    assert!(app.api.requisicoes().iter().any(|r| r.contains("por_pagina=100")));
+}
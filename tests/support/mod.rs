@@ -0,0 +1,357 @@
+//! Fake em processo dos endpoints `/itens`: substitui `window.fetch` durante o teste.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use js_sys::Promise;
+use rust_app_sample::{AlteracaoPreco, Dinheiro, Item, ListarResponse, NovoItem};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{JsFuture, future_to_promise};
+use web_sys::{Headers, Request, Response, ResponseInit, Url};
+
+#[derive(Default)]
+struct EstadoFake {
+    itens: Vec<Item>,
+    historicos: BTreeMap<i32, Vec<AlteracaoPreco>>,
+    proximo_id: i32,
+    requisicoes: Vec<String>,
+    /// Trecho da URL e atraso da resposta em milissegundos.
+    atrasos: Vec<(String, u64)>,
+    /// Simula a API fora do ar: `fetch` falha como falharia sem rede.
+    sem_conexao: bool,
+    /// Responde 401 em `/itens` sem um `Authorization: Bearer` válido.
+    exigir_login: bool,
+}
+
+pub struct FakeApi {
+    estado: Rc<RefCell<EstadoFake>>,
+    fetch_original: JsValue,
+    _fetch: Closure<dyn FnMut(JsValue, JsValue) -> Promise>,
+}
+
+impl FakeApi {
+    pub fn instalar(itens: Vec<Item>) -> Self {
+        let proximo_id = itens.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+        let estado = Rc::new(RefCell::new(EstadoFake {
+            itens,
+            proximo_id,
+            ..EstadoFake::default()
+        }));
+
+        let window = web_sys::window().unwrap();
+        let fetch_original = js_sys::Reflect::get(&window, &"fetch".into()).unwrap();
+
+        let estado_fetch = estado.clone();
+        let fetch = Closure::<dyn FnMut(JsValue, JsValue) -> Promise>::new(move |entrada: JsValue, _init: JsValue| {
+            let estado = estado_fetch.clone();
+            future_to_promise(async move {
+                let req: Request = entrada.dyn_into()?;
+                if estado.borrow().sem_conexao {
+                    return Err(js_sys::TypeError::new("Failed to fetch").into());
+                }
+                let atraso = estado
+                    .borrow()
+                    .atrasos
+                    .iter()
+                    .find(|(trecho, _)| req.url().contains(trecho.as_str()))
+                    .map(|(_, ms)| *ms);
+                if let Some(ms) = atraso {
+                    yew::platform::time::sleep(Duration::from_millis(ms)).await;
+                    if req.signal().aborted() {
+                        return Err(js_sys::Error::new("The operation was aborted.").into());
+                    }
+                }
+                let corpo = match req.text() {
+                    Ok(p) => JsFuture::from(p).await?.as_string().unwrap_or_default(),
+                    Err(_) => String::new(),
+                };
+                let autorizado = req
+                    .headers()
+                    .get("authorization")?
+                    .and_then(|v| v.strip_prefix("Bearer ").map(token_valido))
+                    .unwrap_or(false);
+                let resp = if estado.borrow().exigir_login && !autorizado && req.url().contains("/itens") {
+                    json(401, r#"{"erro": "Login necessário"}"#)?
+                } else {
+                    responder(&estado, &req.method(), &req.url(), &corpo)?
+                };
+                Ok(resp.into())
+            })
+        });
+        js_sys::Reflect::set(&window, &"fetch".into(), fetch.as_ref()).unwrap();
+
+        Self {
+            estado,
+            fetch_original,
+            _fetch: fetch,
+        }
+    }
+
+    pub fn itens(&self) -> Vec<Item> {
+        self.estado.borrow().itens.clone()
+    }
+
+    /// Atrasa as respostas das requisições cuja URL contém `trecho`.
+    pub fn atrasar(&self, trecho: &str, ms: u64) {
+        self.estado.borrow_mut().atrasos.push((trecho.to_string(), ms));
+    }
+
+    /// Remove o item direto no fake, como se outro usuário o tivesse removido.
+    pub fn remover_por_fora(&self, id: i32) {
+        self.estado.borrow_mut().itens.retain(|i| i.id != id);
+    }
+
+    pub fn desconectar(&self) {
+        self.estado.borrow_mut().sem_conexao = true;
+    }
+
+    pub fn reconectar(&self) {
+        self.estado.borrow_mut().sem_conexao = false;
+    }
+
+    /// Renomeia o item direto no fake, como se outro usuário o tivesse alterado.
+    pub fn alterar_por_fora(&self, id: i32, nome: &str) {
+        if let Some(item) = self.estado.borrow_mut().itens.iter_mut().find(|i| i.id == id) {
+            item.nome = nome.to_string();
+        }
+    }
+
+    /// Histórico devolvido por `GET /itens/:id/historico`; sem ele, a lista vem vazia.
+    pub fn definir_historico(&self, id: i32, historico: Vec<AlteracaoPreco>) {
+        self.estado.borrow_mut().historicos.insert(id, historico);
+    }
+
+    pub fn exigir_login(&self) {
+        self.estado.borrow_mut().exigir_login = true;
+    }
+
+    /// Requisições recebidas, no formato `MÉTODO /caminho?query`.
+    pub fn requisicoes(&self) -> Vec<String> {
+        self.estado.borrow().requisicoes.clone()
+    }
+}
+
+impl Drop for FakeApi {
+    fn drop(&mut self) {
+        let window = web_sys::window().unwrap();
+        js_sys::Reflect::set(&window, &"fetch".into(), &self.fetch_original).unwrap();
+    }
+}
+
+pub fn itens_de_exemplo(quantidade: i32) -> Vec<Item> {
+    (1..=quantidade)
+        .map(|id| Item {
+            id,
+            nome: format!("Produto {:02}", id),
+            preco: Dinheiro::de_centavos((id * 3 % 17) as i64 * 100 + 50),
+            categoria: Some(if id % 2 == 0 { "Mercearia" } else { "Bebidas" }.to_string()),
+            sku: Some(format!("SKU-{:03}", id)),
+            estoque: Some(id * 2),
+            ..Item::default()
+        })
+        .collect()
+}
+
+/// Token no formato JWT, sem assinatura, como o emitido pelo `/login` do fake.
+pub fn token(usuario: &str, permissoes: &[&str], exp_s: f64) -> String {
+    let payload = serde_json::json!({ "sub": usuario, "exp": exp_s as i64, "permissions": permissoes });
+    format!(
+        "{}.{}.",
+        URL_SAFE_NO_PAD.encode(r#"{"alg":"none"}"#),
+        URL_SAFE_NO_PAD.encode(payload.to_string())
+    )
+}
+
+fn token_valido(token: &str) -> bool {
+    rust_app_sample::Sessao::new(token).is_ok_and(|s| !s.expirada((js_sys::Date::now() / 1000.0) as i64))
+}
+
+fn responder(estado: &Rc<RefCell<EstadoFake>>, metodo: &str, url: &str, corpo: &str) -> Result<Response, JsValue> {
+    let url = Url::new(url)?;
+    let caminho = url.pathname();
+    let query = url.search_params();
+    let mut estado = estado.borrow_mut();
+    estado.requisicoes.push(format!("{} {}{}", metodo, caminho, url.search()));
+
+    let segmentos: Vec<&str> = caminho.trim_matches('/').split('/').collect();
+    let id = segmentos.get(1).and_then(|s| s.parse::<i32>().ok());
+
+    match (metodo, segmentos.as_slice(), id) {
+        ("POST", ["login"], _) => {
+            let credenciais: serde_json::Value = serde_json::from_str(corpo).unwrap_or_default();
+            let exp = js_sys::Date::now() / 1000.0 + 3600.0;
+            match (credenciais["usuario"].as_str(), credenciais["senha"].as_str()) {
+                (Some("admin"), Some("admin")) => json(
+                    200,
+                    &serde_json::json!({ "token": token("admin", &["itens:criar", "itens:editar", "itens:remover"], exp) })
+                        .to_string(),
+                ),
+                (Some("leitor"), Some("leitor")) => {
+                    json(200, &serde_json::json!({ "token": token("leitor", &[], exp) }).to_string())
+                }
+                _ => json(401, r#"{"erro": "Usuário ou senha inválidos"}"#),
+            }
+        }
+        ("GET", ["itens"], _) => {
+            let texto = |nome: &str, padrao: &str| query.get(nome).unwrap_or_else(|| padrao.to_string());
+            let numero = |nome: &str, padrao: i64| query.get(nome).and_then(|v| v.parse().ok()).unwrap_or(padrao);
+            let decimal = |nome: &str| query.get(nome).and_then(|v| Dinheiro::de_canonico(&v));
+            let inteiro = |nome: &str| query.get(nome).and_then(|v| v.parse::<i32>().ok());
+            let (preco_min, preco_max) = (decimal("preco_min"), decimal("preco_max"));
+            let (id_min, id_max) = (inteiro("id_min"), inteiro("id_max"));
+            let categoria = texto("categoria", "");
+            let na_faixa: Vec<Item> = estado
+                .itens
+                .iter()
+                .filter(|i| preco_min.is_none_or(|v| i.preco >= v) && preco_max.is_none_or(|v| i.preco <= v))
+                .filter(|i| id_min.is_none_or(|v| i.id >= v) && id_max.is_none_or(|v| i.id <= v))
+                .filter(|i| categoria.is_empty() || i.categoria.as_deref() == Some(categoria.as_str()))
+                .cloned()
+                .collect();
+            let resposta = listar(
+                &na_faixa,
+                &texto("busca", ""),
+                &texto("modo", "contem"),
+                &texto("ordenar_por", "id"),
+                &texto("ordem", "asc"),
+                numero("pagina", 1),
+                numero("por_pagina", 10),
+            );
+            let corpo = serde_json::json!({
+                "itens": resposta.itens,
+                "total": resposta.total,
+                "pagina": resposta.pagina,
+                "por_pagina": resposta.por_pagina,
+                "total_paginas": resposta.total_paginas,
+            });
+            json(200, &corpo.to_string())
+        }
+        ("GET", ["categorias"], _) => {
+            let mut categorias: Vec<String> = estado.itens.iter().filter_map(|i| i.categoria.clone()).collect();
+            categorias.sort();
+            categorias.dedup();
+            json(200, &serde_json::to_string(&categorias).unwrap())
+        }
+        ("POST", ["itens"], _) => match validar(corpo) {
+            Ok(novo) => {
+                let item = novo.com_id(estado.proximo_id);
+                estado.proximo_id += 1;
+                estado.itens.push(item.clone());
+                json(201, &serde_json::to_string(&item).unwrap())
+            }
+            Err(resp) => resp,
+        },
+        ("GET", ["itens", _], Some(id)) => match estado.itens.iter().find(|i| i.id == id) {
+            Some(item) => json(200, &serde_json::to_string(item).unwrap()),
+            None => json(404, r#"{"erro": "Item não encontrado"}"#),
+        },
+        ("GET", ["itens", _, "historico"], Some(id)) => match estado.itens.iter().find(|i| i.id == id) {
+            Some(_) => json(200, &serde_json::to_string(&estado.historicos.get(&id).cloned().unwrap_or_default()).unwrap()),
+            None => json(404, r#"{"erro": "Item não encontrado"}"#),
+        },
+        ("PUT", ["itens", _], Some(id)) => match validar(corpo) {
+            Ok(novo) => match estado.itens.iter_mut().find(|i| i.id == id) {
+                Some(item) => {
+                    *item = novo.com_id(id);
+                    json(200, &serde_json::to_string(item).unwrap())
+                }
+                None => json(404, r#"{"erro": "Item não encontrado"}"#),
+            },
+            Err(resp) => resp,
+        },
+        ("DELETE", ["itens", _], Some(id)) => {
+            let antes = estado.itens.len();
+            estado.itens.retain(|i| i.id != id);
+            if estado.itens.len() < antes {
+                vazio(204)
+            } else {
+                json(404, r#"{"erro": "Item não encontrado"}"#)
+            }
+        }
+        _ => json(404, r#"{"erro": "Rota não encontrada"}"#),
+    }
+}
+
+fn validar(corpo: &str) -> Result<NovoItem, Result<Response, JsValue>> {
+    let novo: NovoItem = serde_json::from_str(corpo).map_err(|_| json(400, r#"{"erro": "JSON inválido"}"#))?;
+    if novo.nome.trim() == "duplicado" {
+        return Err(json(422, r#"{"erro": "Validação", "campos": {"nome": "Já existe um item com esse nome"}}"#));
+    }
+    Ok(novo)
+}
+
+fn listar(
+    itens: &[Item],
+    busca: &str,
+    modo: &str,
+    ordenar_por: &str,
+    ordem: &str,
+    pagina: i64,
+    por_pagina: i64,
+) -> ListarResponse {
+    let busca = busca.trim().to_lowercase();
+    let confere = |nome: &str| {
+        let nome = nome.to_lowercase();
+        match modo {
+            "comeca_com" => nome.starts_with(&busca),
+            "exato" => nome == busca,
+            "regex" => js_sys::RegExp::new(&busca, "i").test(&nome),
+            _ => nome.contains(&busca),
+        }
+    };
+    let mut filtrados: Vec<Item> = itens
+        .iter()
+        .filter(|i| {
+            busca.is_empty()
+                || i.id.to_string() == busca
+                || i.sku.as_deref().is_some_and(|sku| sku.to_lowercase() == busca)
+                || confere(&i.nome)
+        })
+        .cloned()
+        .collect();
+
+    filtrados.sort_by(|a, b| match ordenar_por {
+        "nome" => a.nome.cmp(&b.nome),
+        "preco" => a.preco.cmp(&b.preco),
+        _ => a.id.cmp(&b.id),
+    });
+    if ordem == "desc" {
+        filtrados.reverse();
+    }
+
+    let total = filtrados.len() as i64;
+    let total_paginas = (total + por_pagina - 1) / por_pagina;
+    let itens = filtrados
+        .into_iter()
+        .skip(((pagina - 1) * por_pagina) as usize)
+        .take(por_pagina as usize)
+        .collect();
+
+    ListarResponse {
+        itens,
+        total,
+        pagina,
+        por_pagina,
+        total_paginas,
+    }
+}
+
+fn json(status: u16, corpo: &str) -> Result<Response, JsValue> {
+    let headers = Headers::new()?;
+    headers.set("content-type", "application/json")?;
+    let init = ResponseInit::new();
+    init.set_status(status);
+    init.set_headers(&headers);
+    Response::new_with_opt_str_and_init(Some(corpo), &init)
+}
+
+fn vazio(status: u16) -> Result<Response, JsValue> {
+    let init = ResponseInit::new();
+    init.set_status(status);
+    Response::new_with_opt_str_and_init(None, &init)
+}